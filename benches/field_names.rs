@@ -0,0 +1,83 @@
+//! Counts heap allocations made while building field-bearing queries, comparing
+//! `&'static str` field names (borrowed) against owned `String` ones.
+//!
+//! Run with `cargo bench --bench field_names`
+
+use elasticsearch_dsl::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+
+fn measure<F, T>(name: &str, f: F)
+where
+    F: Fn() -> T,
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        drop(std::hint::black_box(f()));
+    }
+
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!(
+        "{:<32} {:>6.2} allocations/iter {:>10.2?}/iter",
+        name,
+        allocations as f64 / ITERATIONS as f64,
+        elapsed / ITERATIONS as u32,
+    );
+}
+
+fn main() {
+    measure("term (static field)", || Query::term("user.id", 42));
+    measure("term (owned field)", || {
+        Query::term(String::from("user.id"), 42)
+    });
+
+    measure("range (static field)", || {
+        Query::range("created_at").gte(10).lt(20)
+    });
+    measure("range (owned field)", || {
+        Query::range(String::from("created_at")).gte(10).lt(20)
+    });
+
+    measure("exists (static field)", || Query::exists("user.id"));
+    measure("exists (owned field)", || {
+        Query::exists(String::from("user.id"))
+    });
+
+    measure("bool of terms (static fields)", || {
+        Query::bool()
+            .filter(Query::term("user.id", 42))
+            .filter(Query::term("status", 2))
+            .filter(Query::exists("title"))
+    });
+    measure("bool of terms (owned fields)", || {
+        Query::bool()
+            .filter(Query::term(String::from("user.id"), 42))
+            .filter(Query::term(String::from("status"), 2))
+            .filter(Query::exists(String::from("title")))
+    });
+}
@@ -0,0 +1,8 @@
+//! Performs multiple indexing or delete operations in a single API call.
+//! This reduces overhead and can greatly increase indexing speed.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html>
+
+mod request;
+
+pub use self::request::*;
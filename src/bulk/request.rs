@@ -0,0 +1,408 @@
+use crate::util::*;
+use serde_json::Value;
+
+/// Performs multiple indexing or delete operations in a single API call.
+///
+/// The body of the bulk API is newline delimited JSON, use
+/// [`serialize_ndjson`](BulkRequest::serialize_ndjson) to build it:
+/// ```
+/// # use elasticsearch_dsl::bulk::*;
+/// # use serde_json::json;
+/// let body = BulkRequest::new()
+///     .operation(BulkOperation::index("test", json!({ "field1": "value1" })).id("1"))
+///     .operation(BulkOperation::delete("test", "2"))
+///     .serialize_ndjson();
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-bulk.html>
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BulkRequest {
+    /// Operations performed in order
+    pub operations: Vec<BulkOperation>,
+}
+
+/// A single operation of a [`BulkRequest`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkOperation {
+    /// Indexes the specified document. If the document exists, replaces the
+    /// document and increments the version.
+    Index {
+        /// Name of the index or data stream
+        _index: String,
+
+        /// Document ID, generated by Elasticsearch when omitted
+        _id: Option<String>,
+
+        /// Custom value used to route the operation to a specific shard
+        _routing: Option<String>,
+
+        /// Document source
+        source: Value,
+    },
+
+    /// Indexes the specified document if it does not already exist.
+    Create {
+        /// Name of the index or data stream
+        _index: String,
+
+        /// Document ID, generated by Elasticsearch when omitted
+        _id: Option<String>,
+
+        /// Custom value used to route the operation to a specific shard
+        _routing: Option<String>,
+
+        /// Document source
+        source: Value,
+    },
+
+    /// Performs a partial document update.
+    Update {
+        /// Name of the index
+        _index: String,
+
+        /// ID of the document to update
+        _id: String,
+
+        /// Custom value used to route the operation to a specific shard
+        _routing: Option<String>,
+
+        /// Partial document merged into the existing one
+        doc: Value,
+
+        /// Uses the contents of `doc` as the upsert value when the document
+        /// does not exist
+        doc_as_upsert: Option<bool>,
+
+        /// How many times the update should be retried in the case of a
+        /// version conflict
+        retry_on_conflict: Option<u32>,
+    },
+
+    /// Removes the specified document from the index.
+    Delete {
+        /// Name of the index
+        _index: String,
+
+        /// ID of the document to remove
+        _id: String,
+
+        /// Custom value used to route the operation to a specific shard
+        _routing: Option<String>,
+    },
+}
+
+#[derive(Serialize)]
+struct Metadata<'a> {
+    _index: &'a str,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _id: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    retry_on_conflict: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct UpdateSource<'a> {
+    doc: &'a Value,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    doc_as_upsert: Option<bool>,
+}
+
+impl BulkRequest {
+    /// Creates an instance of [`BulkRequest`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an operation to the request
+    pub fn operation<T>(mut self, operation: T) -> Self
+    where
+        T: Into<BulkOperation>,
+    {
+        self.operations.push(operation.into());
+        self
+    }
+
+    /// Adds several operations to the request
+    pub fn operations<I>(mut self, operations: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<BulkOperation>,
+    {
+        self.operations
+            .extend(operations.into_iter().map(Into::into));
+        self
+    }
+
+    /// Serializes the request into the newline delimited JSON body expected
+    /// by the bulk API, the action and metadata followed by the source for
+    /// every operation except `delete`. The body ends with a newline.
+    pub fn serialize_ndjson(&self) -> String {
+        let mut body = String::new();
+
+        for operation in &self.operations {
+            for line in operation.lines() {
+                body.push_str(&line);
+                body.push('\n');
+            }
+        }
+
+        body
+    }
+}
+
+impl BulkOperation {
+    /// Creates an `index` operation
+    pub fn index<T>(index: T, source: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::Index {
+            _index: index.to_string(),
+            _id: None,
+            _routing: None,
+            source,
+        }
+    }
+
+    /// Creates a `create` operation
+    pub fn create<T>(index: T, source: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::Create {
+            _index: index.to_string(),
+            _id: None,
+            _routing: None,
+            source,
+        }
+    }
+
+    /// Creates an `update` operation
+    pub fn update<T, U>(index: T, id: U, doc: Value) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        Self::Update {
+            _index: index.to_string(),
+            _id: id.to_string(),
+            _routing: None,
+            doc,
+            doc_as_upsert: None,
+            retry_on_conflict: None,
+        }
+    }
+
+    /// Creates a `delete` operation
+    pub fn delete<T, U>(index: T, id: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        Self::Delete {
+            _index: index.to_string(),
+            _id: id.to_string(),
+            _routing: None,
+        }
+    }
+
+    /// Sets the document ID of the operation
+    pub fn id<T>(mut self, id: T) -> Self
+    where
+        T: ToString,
+    {
+        match &mut self {
+            Self::Index { _id, .. } | Self::Create { _id, .. } => *_id = Some(id.to_string()),
+            Self::Update { _id, .. } | Self::Delete { _id, .. } => *_id = id.to_string(),
+        }
+        self
+    }
+
+    /// Custom value used to route the operation to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        match &mut self {
+            Self::Index { _routing, .. }
+            | Self::Create { _routing, .. }
+            | Self::Update { _routing, .. }
+            | Self::Delete { _routing, .. } => *_routing = Some(routing.to_string()),
+        }
+        self
+    }
+
+    /// Uses the contents of `doc` as the upsert value, has no effect on
+    /// operations other than `update`
+    pub fn doc_as_upsert(mut self, doc_as_upsert: bool) -> Self {
+        if let Self::Update {
+            doc_as_upsert: value,
+            ..
+        } = &mut self
+        {
+            *value = Some(doc_as_upsert);
+        }
+        self
+    }
+
+    /// How many times the update should be retried in the case of a version
+    /// conflict, has no effect on operations other than `update`
+    pub fn retry_on_conflict(mut self, retry_on_conflict: u32) -> Self {
+        if let Self::Update {
+            retry_on_conflict: value,
+            ..
+        } = &mut self
+        {
+            *value = Some(retry_on_conflict);
+        }
+        self
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let (action, metadata, source) = match self {
+            Self::Index {
+                _index,
+                _id,
+                _routing,
+                source,
+            } => (
+                "index",
+                Metadata::new(_index, _id.as_deref(), _routing.as_deref()),
+                Some(source.to_string()),
+            ),
+            Self::Create {
+                _index,
+                _id,
+                _routing,
+                source,
+            } => (
+                "create",
+                Metadata::new(_index, _id.as_deref(), _routing.as_deref()),
+                Some(source.to_string()),
+            ),
+            Self::Update {
+                _index,
+                _id,
+                _routing,
+                doc,
+                doc_as_upsert,
+                retry_on_conflict,
+            } => (
+                "update",
+                Metadata {
+                    retry_on_conflict: *retry_on_conflict,
+                    ..Metadata::new(_index, Some(_id), _routing.as_deref())
+                },
+                Some(
+                    serde_json::to_string(&UpdateSource {
+                        doc,
+                        doc_as_upsert: *doc_as_upsert,
+                    })
+                    .unwrap(),
+                ),
+            ),
+            Self::Delete {
+                _index,
+                _id,
+                _routing,
+            } => (
+                "delete",
+                Metadata::new(_index, Some(_id), _routing.as_deref()),
+                None,
+            ),
+        };
+
+        std::iter::once(serde_json::to_string(&KeyValuePair::new(action, metadata)).unwrap())
+            .chain(source)
+            .collect()
+    }
+}
+
+impl<'a> Metadata<'a> {
+    fn new(_index: &'a str, _id: Option<&'a str>, routing: Option<&'a str>) -> Self {
+        Self {
+            _index,
+            _id,
+            routing,
+            retry_on_conflict: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_ndjson() {
+        let subject = BulkRequest::new()
+            .operation(BulkOperation::index("test", json!({ "field1": "value1" })).id("1"))
+            .operation(BulkOperation::delete("test", "2"))
+            .operation(BulkOperation::create("test", json!({ "field1": "value3" })).id("3"))
+            .operation(
+                BulkOperation::update("test", "1", json!({ "field2": "value2" }))
+                    .doc_as_upsert(true)
+                    .retry_on_conflict(3),
+            );
+
+        assert_eq!(
+            subject.serialize_ndjson(),
+            concat!(
+                r#"{"index":{"_index":"test","_id":"1"}}"#,
+                "\n",
+                r#"{"field1":"value1"}"#,
+                "\n",
+                r#"{"delete":{"_index":"test","_id":"2"}}"#,
+                "\n",
+                r#"{"create":{"_index":"test","_id":"3"}}"#,
+                "\n",
+                r#"{"field1":"value3"}"#,
+                "\n",
+                r#"{"update":{"_index":"test","_id":"1","retry_on_conflict":3}}"#,
+                "\n",
+                r#"{"doc":{"field2":"value2"},"doc_as_upsert":true}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn serializes_routing_and_generated_ids() {
+        let subject = BulkRequest::new().operations([
+            BulkOperation::index("test", json!({ "a": 1 })),
+            BulkOperation::delete("test", "2").routing("user1"),
+            BulkOperation::Update {
+                _index: "test".to_string(),
+                _id: "3".to_string(),
+                _routing: Some("user2".to_string()),
+                doc: json!({ "b": 2 }),
+                doc_as_upsert: None,
+                retry_on_conflict: None,
+            },
+        ]);
+
+        assert_eq!(
+            subject.serialize_ndjson(),
+            concat!(
+                r#"{"index":{"_index":"test"}}"#,
+                "\n",
+                r#"{"a":1}"#,
+                "\n",
+                r#"{"delete":{"_index":"test","_id":"2","routing":"user1"}}"#,
+                "\n",
+                r#"{"update":{"_index":"test","_id":"3","routing":"user2"}}"#,
+                "\n",
+                r#"{"doc":{"b":2}}"#,
+                "\n",
+            )
+        );
+
+        assert_eq!(BulkRequest::new().serialize_ndjson(), "");
+    }
+}
@@ -0,0 +1,9 @@
+//! Deletes documents that match the specified query.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html>
+
+mod params;
+mod request;
+
+pub use self::params::*;
+pub use self::request::*;
@@ -0,0 +1,120 @@
+use crate::search::*;
+use crate::util::*;
+use crate::ConflictsPolicy;
+
+/// Deletes documents that match the specified query.
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// DeleteByQuery::new(Query::range("@timestamp").lt("now-30d"))
+///     .conflicts(ConflictsPolicy::Proceed)
+///     .max_docs(10000);
+/// ```
+///
+/// URL parameters are set with [`DeleteByQueryParams`].
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DeleteByQuery {
+    query: Query,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    conflicts: Option<ConflictsPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slice: Option<Slice>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+}
+
+impl DeleteByQuery {
+    /// Creates an instance of [`DeleteByQuery`]
+    ///
+    /// - `query` - documents matching the query are deleted, use
+    /// [`Query::match_all`] to delete every document
+    pub fn new<Q>(query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        Self {
+            query: query.into(),
+            conflicts: None,
+            slice: None,
+            max_docs: None,
+        }
+    }
+
+    /// What to do if delete by query hits version conflicts
+    pub fn conflicts(mut self, conflicts: ConflictsPolicy) -> Self {
+        self.conflicts = Some(conflicts);
+        self
+    }
+
+    /// Manually slices the request, run one request per slice to
+    /// parallelize the deletion
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-delete-by-query.html#docs-delete-by-query-manual-slice>
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Maximum number of documents to process, defaults to all documents
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+}
+
+impl Validate for DeleteByQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("query", &self.query);
+        errors.nested("slice", &self.slice);
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            DeleteByQuery::new(Query::match_all()),
+            json!({ "query": { "match_all": {} } }),
+        );
+
+        assert_serialize(
+            DeleteByQuery::new(
+                Query::bool()
+                    .filter(Query::term("user.id", "kimchy"))
+                    .filter(Query::range("@timestamp").lt("now-30d"))
+                    .must_not(Query::exists("legal_hold")),
+            )
+            .conflicts(ConflictsPolicy::Proceed)
+            .slice(Slice::new(1, 4))
+            .max_docs(1000),
+            json!({
+                "query": {
+                    "bool": {
+                        "filter": [
+                            { "term": { "user.id": { "value": "kimchy" } } },
+                            { "range": { "@timestamp": { "lt": "now-30d" } } }
+                        ],
+                        "must_not": [
+                            { "exists": { "field": "legal_hold" } }
+                        ]
+                    }
+                },
+                "conflicts": "proceed",
+                "slice": { "id": 1, "max": 4 },
+                "max_docs": 1000
+            }),
+        );
+    }
+}
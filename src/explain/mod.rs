@@ -0,0 +1,12 @@
+//! Returns information about why a specific document matches, or doesn’t
+//! match, a query.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html>
+
+mod params;
+mod request;
+mod response;
+
+pub use self::params::*;
+pub use self::request::*;
+pub use self::response::*;
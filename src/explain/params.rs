@@ -0,0 +1,100 @@
+use crate::util::*;
+use serde::Serializer;
+
+/// URL parameters of an [`ExplainRequest`](crate::ExplainRequest),
+/// serializes into a flat structure that can be passed to the query string
+/// of any HTTP client
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html#search-explain-api-query-params>
+#[derive(Debug, Default, Clone, Serialize, PartialEq, Eq)]
+pub struct ExplainParams {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _source: Option<bool>,
+
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        serialize_with = "serialize_comma_separated"
+    )]
+    stored_fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preference: Option<String>,
+}
+
+impl ExplainParams {
+    /// Creates an instance of [`ExplainParams`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the `_source` of the document is returned
+    pub fn source(mut self, source: bool) -> Self {
+        self._source = Some(source);
+        self
+    }
+
+    /// Stored fields of the document to return
+    pub fn stored_fields<I>(mut self, stored_fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.stored_fields = stored_fields
+            .into_iter()
+            .map(|field| field.to_string())
+            .collect();
+        self
+    }
+
+    /// Custom value used to route the request to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /// Nodes and shards used for the request, e.g. `_local`
+    pub fn preference<T>(mut self, preference: T) -> Self
+    where
+        T: ToString,
+    {
+        self.preference = Some(preference.to_string());
+        self
+    }
+}
+
+fn serialize_comma_separated<S>(values: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(ExplainParams::new(), json!({}));
+
+        assert_serialize(
+            ExplainParams::new()
+                .source(false)
+                .stored_fields(["user", "message"])
+                .routing("user1")
+                .preference("_local"),
+            json!({
+                "_source": false,
+                "stored_fields": "user,message",
+                "routing": "user1",
+                "preference": "_local"
+            }),
+        );
+    }
+}
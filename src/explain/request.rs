@@ -0,0 +1,56 @@
+use crate::search::*;
+
+/// Computes a score explanation for a query and a specific document.
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// ExplainRequest::new(Query::r#match("message", "elasticsearch"));
+/// ```
+///
+/// URL parameters are set with [`ExplainParams`].
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExplainRequest {
+    query: Query,
+}
+
+impl ExplainRequest {
+    /// Creates an instance of [`ExplainRequest`]
+    ///
+    /// - `query` - query to explain the document score for
+    pub fn new<Q>(query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        Self {
+            query: query.into(),
+        }
+    }
+}
+
+impl Validate for ExplainRequest {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("query", &self.query);
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            ExplainRequest::new(Query::r#match("message", "elasticsearch")),
+            json!({
+                "query": { "match": { "message": { "query": "elasticsearch" } } }
+            }),
+        );
+    }
+}
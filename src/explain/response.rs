@@ -0,0 +1,80 @@
+use crate::{util::ShouldSkip, Explanation};
+
+/// Explain response
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-explain.html#search-explain-api-example>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExplainResponse {
+    /// Document index
+    #[serde(rename = "_index")]
+    pub index: String,
+
+    /// Document ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Whether the document matches the query
+    pub matched: bool,
+
+    /// Score explanation, missing when the document does not exist
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub explanation: Option<Explanation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = json!({
+          "_index": "my-index-000001",
+          "_id": "0",
+          "matched": true,
+          "explanation": {
+            "value": 1.6943598,
+            "description": "weight(message:elasticsearch in 0) [PerFieldSimilarity], result of:",
+            "details": [
+              {
+                "value": 1.6943598,
+                "description": "score(freq=1.0), computed as boost * idf * tf from:",
+                "details": [
+                  {
+                    "value": 2.2,
+                    "description": "boost",
+                    "details": []
+                  },
+                  {
+                    "value": 1.3862944,
+                    "description": "idf, computed as log(1 + (N - n + 0.5) / (n + 0.5)) from:",
+                    "details": []
+                  }
+                ]
+              }
+            ]
+          }
+        });
+
+        let subject: ExplainResponse = serde_json::from_value(json).unwrap();
+        let explanation = subject.explanation.unwrap();
+
+        assert_eq!(subject.index, "my-index-000001");
+        assert_eq!(subject.id, "0");
+        assert!(subject.matched);
+        assert_eq!(explanation.value, 1.6943598);
+        assert_eq!(explanation.details[0].details[0].description, "boost");
+    }
+
+    #[test]
+    fn deserializes_unmatched_document() {
+        let subject: ExplainResponse = serde_json::from_value(json!({
+          "_index": "my-index-000001",
+          "_id": "1",
+          "matched": false
+        }))
+        .unwrap();
+
+        assert!(!subject.matched);
+        assert_eq!(subject.explanation, None);
+    }
+}
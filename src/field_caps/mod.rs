@@ -0,0 +1,11 @@
+//! Allows you to retrieve the capabilities of fields among multiple indices.
+//! For data streams, the API returns field capabilities among the stream’s
+//! backing indices.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-field-caps.html>
+
+mod request;
+mod response;
+
+pub use self::request::*;
+pub use self::response::*;
@@ -0,0 +1,107 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// Retrieves the capabilities of fields among multiple indices.
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// FieldCapabilitiesRequest::new(["rating", "title"])
+///     .index_filter(Query::range("@timestamp").gte("2018"));
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-field-caps.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldCapabilitiesRequest {
+    fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    runtime_mappings: Map<String, RuntimeMapping>,
+}
+
+impl FieldCapabilitiesRequest {
+    /// Creates an instance of [`FieldCapabilitiesRequest`]
+    ///
+    /// - `fields` - fields to retrieve capabilities for, wildcard (`*`)
+    /// expressions are supported
+    pub fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        Self {
+            fields: fields.into_iter().map(|field| field.to_string()).collect(),
+            index_filter: None,
+            runtime_mappings: Map::new(),
+        }
+    }
+
+    /// Skips indices that do not match the query, e.g. with no documents in
+    /// a time range. Skippable queries leave the filter unset.
+    pub fn index_filter<Q>(mut self, index_filter: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.index_filter = Some(index_filter.into()).filter(ShouldSkip::should_keep);
+        self
+    }
+
+    /// Defines a runtime field whose capabilities are returned alongside the
+    /// mapped fields
+    pub fn runtime_mapping<S>(mut self, name: S, mapping: RuntimeMapping) -> Self
+    where
+        S: ToString,
+    {
+        let _ = self.runtime_mappings.insert(name.to_string(), mapping);
+        self
+    }
+}
+
+impl Validate for FieldCapabilitiesRequest {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("index_filter", &self.index_filter);
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            FieldCapabilitiesRequest::new(["*"]),
+            json!({ "fields": ["*"] }),
+        );
+
+        assert_serialize(
+            FieldCapabilitiesRequest::new(["rating", "title", "day_of_week"])
+                .index_filter(Query::range("@timestamp").gte("2018"))
+                .runtime_mapping(
+                    "day_of_week",
+                    RuntimeMapping::keyword(
+                        "emit(doc['@timestamp'].value.dayOfWeekEnum.toString())",
+                    ),
+                ),
+            json!({
+                "fields": ["rating", "title", "day_of_week"],
+                "index_filter": { "range": { "@timestamp": { "gte": "2018" } } },
+                "runtime_mappings": {
+                    "day_of_week": {
+                        "type": "keyword",
+                        "script": {
+                            "source": "emit(doc['@timestamp'].value.dayOfWeekEnum.toString())"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{util::ShouldSkip, Map};
+
+/// Field capabilities response
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-field-caps.html#search-field-caps-api-response-body>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldCapabilitiesResponse {
+    /// Indices the capabilities were retrieved from
+    #[serde(default)]
+    pub indices: Vec<String>,
+
+    /// Capabilities of each field, keyed by field name and then by field
+    /// type, a field mapped with different types across indices has several
+    /// entries
+    #[serde(default)]
+    pub fields: Map<String, Map<String, FieldCapability>>,
+}
+
+/// Capabilities of a field for a single field type
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldCapability {
+    /// Field type
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Whether the field is a metadata field, e.g. `_id`
+    #[serde(default)]
+    pub metadata_field: bool,
+
+    /// Whether the field is indexed for search on all indices
+    pub searchable: bool,
+
+    /// Whether the field can be aggregated on all indices
+    pub aggregatable: bool,
+
+    /// Indices with this field type, [None] when all indices share the type
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub indices: Option<Vec<String>>,
+
+    /// Indices where the field is not searchable, [None] when the field is
+    /// searchable or not searchable on all indices
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub non_searchable_indices: Option<Vec<String>>,
+
+    /// Indices where the field is not aggregatable, [None] when the field is
+    /// aggregatable or not aggregatable on all indices
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub non_aggregatable_indices: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = json!({
+          "indices": ["index1", "index2", "index3", "index4", "index5"],
+          "fields": {
+            "rating": {
+              "long": {
+                "metadata_field": false,
+                "searchable": true,
+                "aggregatable": false,
+                "indices": ["index1", "index2"],
+                "non_aggregatable_indices": ["index1"],
+                "type": "long"
+              },
+              "keyword": {
+                "metadata_field": false,
+                "searchable": false,
+                "aggregatable": true,
+                "indices": ["index3", "index4"],
+                "non_searchable_indices": ["index4"],
+                "type": "keyword"
+              }
+            },
+            "title": {
+              "text": {
+                "metadata_field": false,
+                "searchable": true,
+                "aggregatable": false,
+                "type": "text"
+              }
+            }
+          }
+        });
+
+        let subject: FieldCapabilitiesResponse = serde_json::from_value(json).unwrap();
+        let rating = &subject.fields["rating"];
+        let title = &subject.fields["title"]["text"];
+
+        assert_eq!(subject.indices.len(), 5);
+        assert_eq!(rating["long"].ty, "long");
+        assert_eq!(
+            rating["long"].non_aggregatable_indices,
+            Some(vec!["index1".to_string()])
+        );
+        assert_eq!(
+            rating["keyword"].non_searchable_indices,
+            Some(vec!["index4".to_string()])
+        );
+        assert!(title.searchable);
+        assert!(!title.aggregatable);
+        assert_eq!(title.indices, None);
+    }
+}
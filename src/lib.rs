@@ -124,8 +124,22 @@ pub(crate) use self::types::*;
 
 // Public modules
 pub mod analyze;
+pub mod bulk;
+pub mod delete_by_query;
+pub mod explain;
+pub mod field_caps;
+pub mod msearch;
 pub mod search;
+pub mod search_template;
+pub mod update_by_query;
 
 // Public re-exports
 pub use self::analyze::*;
+pub use self::bulk::*;
+pub use self::delete_by_query::*;
+pub use self::explain::*;
+pub use self::field_caps::*;
+pub use self::msearch::*;
 pub use self::search::*;
+pub use self::search_template::*;
+pub use self::update_by_query::*;
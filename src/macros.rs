@@ -46,7 +46,9 @@ macro_rules! add_aggregate {
             A: Into<Aggregation>,
         {
             let a = aggregation.into();
-            let _ = self.aggs.entry(aggregation_name.into()).or_insert(a);
+            if $crate::util::ShouldSkip::should_keep(&a) {
+                let _ = self.aggs.entry(aggregation_name.into()).or_insert(a);
+            }
             self
         }
     };
@@ -197,3 +199,148 @@ macro_rules! serialize_keyed {
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_with_root {
+    ($root:tt : $inner:ty) => {
+        impl<'de> $crate::serde::Deserialize<'de> for $inner {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::serde::de::Deserializer<'de>,
+            {
+                struct Wrapper($inner);
+
+                impl<'de> $crate::serde::Deserialize<'de> for Wrapper {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: $crate::serde::de::Deserializer<'de>,
+                    {
+                        <$inner>::deserialize(deserializer).map(Wrapper)
+                    }
+                }
+
+                #[derive(Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Root {
+                    #[serde(rename = $root)]
+                    root: Wrapper,
+                }
+
+                Root::deserialize(deserializer).map(|root| root.root.0)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_with_root_keyed {
+    ($root:tt : $inner:ty) => {
+        impl<'de> $crate::serde::Deserialize<'de> for $inner {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::serde::de::Deserializer<'de>,
+            {
+                struct Wrapper($inner);
+
+                impl<'de> $crate::serde::Deserialize<'de> for Wrapper {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: $crate::serde::de::Deserializer<'de>,
+                    {
+                        <$inner>::deserialize(deserializer).map(Wrapper)
+                    }
+                }
+
+                #[derive(Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Root {
+                    #[serde(rename = $root)]
+                    root: KeyValuePair<String, Wrapper>,
+                }
+
+                let root = Root::deserialize(deserializer)?.root;
+                let mut inner = root.value.0;
+                inner.field = root.key.into();
+
+                Ok(inner)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_with_root_key_value_pair {
+    ($root:tt : $inner:ty, $key:ident, $value:ident) => {
+        impl<'de> $crate::serde::Deserialize<'de> for $inner {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::serde::de::Deserializer<'de>,
+            {
+                use $crate::serde::de::Error;
+
+                #[derive(Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct Root {
+                    #[serde(rename = $root)]
+                    root: ::serde_json::Map<String, ::serde_json::Value>,
+                }
+
+                // The value is never serialized under its own name, so a key
+                // matching it can only be the dynamic one
+                let fields = $crate::util::struct_fields(|fields| <$inner>::deserialize(fields));
+                let is_key = |key: &str| key == stringify!($value) || !fields.contains(&key);
+
+                let mut map = Root::deserialize(deserializer)?.root;
+                let key = map
+                    .keys()
+                    .find(|key| is_key(key))
+                    .cloned()
+                    .ok_or_else(|| D::Error::custom(concat!("missing `", $root, "` field")))?;
+
+                if let Some(value) = map.remove(&key) {
+                    let _ = map.insert(stringify!($value).to_string(), value);
+                }
+
+                let mut inner = <$inner>::deserialize(::serde_json::Value::Object(map))
+                    .map_err(D::Error::custom)?;
+                inner.$key = key.into();
+
+                Ok(inner)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! deserialize_keyed {
+    ($inner:ty : $field:ident) => {
+        impl<'de> $crate::serde::Deserialize<'de> for $inner {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::serde::de::Deserializer<'de>,
+            {
+                struct Wrapper($inner);
+
+                impl<'de> $crate::serde::Deserialize<'de> for Wrapper {
+                    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                    where
+                        D: $crate::serde::de::Deserializer<'de>,
+                    {
+                        <$inner>::deserialize(deserializer).map(Wrapper)
+                    }
+                }
+
+                let pair =
+                    $crate::util::KeyValuePair::<String, Wrapper>::deserialize(deserializer)?;
+                let mut inner = pair.value.0;
+                inner.$field = pair.key.into();
+
+                Ok(inner)
+            }
+        }
+    };
+}
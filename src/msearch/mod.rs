@@ -0,0 +1,9 @@
+//! Executes several searches with a single API request.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html>
+
+mod request;
+mod response;
+
+pub use self::request::*;
+pub use self::response::*;
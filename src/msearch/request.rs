@@ -0,0 +1,196 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Executes several searches with a single API request.
+///
+/// The body of the multi search API is newline delimited JSON, use
+/// [`serialize_ndjson`](MSearchRequest::serialize_ndjson) to build it:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// let body = MSearchRequest::new()
+///     .search(MSearchHeader::new().index(["my-index"]), Search::new().query(Query::match_all()))
+///     .search(MSearchHeader::new(), Search::new().size(0))
+///     .serialize_ndjson();
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html>
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MSearchRequest {
+    /// Searches executed in order
+    pub requests: Vec<MSearchEntry>,
+}
+
+/// A single search of a [`MSearchRequest`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MSearchEntry {
+    /// Search parameters, targets the indices of the request URL unless
+    /// [`index`](MSearchHeader::index) is set
+    pub header: MSearchHeader,
+
+    /// Search body
+    pub body: Search,
+}
+
+/// Parameters of a single search of a [`MSearchRequest`]
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html#search-multi-search-api-request-body>
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct MSearchHeader {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    index: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    search_type: Option<SearchType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    preference: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    request_cache: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ccs_minimize_roundtrips: Option<bool>,
+}
+
+/// How distributed term frequencies are calculated for relevance scoring
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-search.html#search-search-api-query-params>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    /// Calculates term frequencies locally on each shard, this is the default
+    QueryThenFetch,
+
+    /// Calculates term frequencies using the information gathered from all
+    /// shards, which is more accurate but slower
+    DfsQueryThenFetch,
+}
+
+impl MSearchRequest {
+    /// Creates an instance of [`MSearchRequest`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a search to the request
+    pub fn search(mut self, header: MSearchHeader, body: Search) -> Self {
+        self.requests.push(MSearchEntry { header, body });
+        self
+    }
+
+    /// Serializes the request into the newline delimited JSON body expected
+    /// by the multi search API, a header line followed by a body line for
+    /// every search. The body ends with a newline.
+    pub fn serialize_ndjson(&self) -> String {
+        let mut body = String::new();
+
+        for request in &self.requests {
+            body.push_str(&serde_json::to_string(&request.header).unwrap());
+            body.push('\n');
+            body.push_str(&serde_json::to_string(&request.body).unwrap());
+            body.push('\n');
+        }
+
+        body
+    }
+}
+
+impl MSearchHeader {
+    /// Creates an instance of [`MSearchHeader`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Data streams, indices and aliases to search
+    pub fn index<I>(mut self, index: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.index = Some(index.into_iter().map(|index| index.to_string()).collect());
+        self
+    }
+
+    /// How distributed term frequencies are calculated for relevance scoring
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type);
+        self
+    }
+
+    /// Nodes and shards used for the search, e.g. `_local`
+    pub fn preference<T>(mut self, preference: T) -> Self
+    where
+        T: ToString,
+    {
+        self.preference = Some(preference.to_string());
+        self
+    }
+
+    /// Custom value used to route the search to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /// Whether the request cache can be used for this search
+    pub fn request_cache(mut self, request_cache: bool) -> Self {
+        self.request_cache = Some(request_cache);
+        self
+    }
+
+    /// Whether network round-trips between the coordinating node and the
+    /// remote clusters are minimized for cross-cluster searches
+    pub fn ccs_minimize_roundtrips(mut self, ccs_minimize_roundtrips: bool) -> Self {
+        self.ccs_minimize_roundtrips = Some(ccs_minimize_roundtrips);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_ndjson() {
+        let subject = MSearchRequest::new()
+            .search(
+                MSearchHeader::new().index(["my-index-000001"]),
+                Search::new().query(Query::match_all()),
+            )
+            .search(
+                MSearchHeader::new()
+                    .index(["my-index-000002", "my-index-000003"])
+                    .search_type(SearchType::DfsQueryThenFetch)
+                    .preference("_local")
+                    .routing("user1")
+                    .request_cache(true)
+                    .ccs_minimize_roundtrips(false),
+                Search::new().size(0),
+            )
+            .search(MSearchHeader::new(), Search::new());
+
+        assert_eq!(
+            subject.serialize_ndjson(),
+            concat!(
+                r#"{"index":["my-index-000001"]}"#,
+                "\n",
+                r#"{"query":{"match_all":{}}}"#,
+                "\n",
+                r#"{"index":["my-index-000002","my-index-000003"],"search_type":"dfs_query_then_fetch","preference":"_local","routing":"user1","request_cache":true,"ccs_minimize_roundtrips":false}"#,
+                "\n",
+                r#"{"size":0}"#,
+                "\n",
+                "{}\n",
+                "{}\n",
+            )
+        );
+
+        assert_eq!(MSearchRequest::new().serialize_ndjson(), "");
+    }
+}
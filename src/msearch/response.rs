@@ -0,0 +1,202 @@
+use crate::{ErrorResponse, SearchResponse, Source};
+use serde::de::{DeserializeOwned, Deserializer, Error, IgnoredAny};
+use serde::Deserialize;
+use serde_json::value::RawValue;
+
+/// Multi search response, with one response per search in the order of the
+/// request
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multi-search.html#search-multi-search-api-response-body>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(bound(
+    serialize = "T: serde::Serialize + 'static",
+    deserialize = "T: DeserializeOwned"
+))]
+pub struct MSearchResponse<T = Source> {
+    /// The time that it took Elasticsearch to process the searches
+    #[serde(default)]
+    pub took: u32,
+
+    /// Response of each search
+    pub responses: Vec<MSearchResponseItem<T>>,
+}
+
+/// Response of a single search of a [`MSearchResponse`], searches fail
+/// independently of each other
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged, bound(serialize = "T: serde::Serialize + 'static"))]
+pub enum MSearchResponseItem<T = Source> {
+    /// Successful search
+    Success(Box<SearchResponse<T>>),
+
+    /// Failed search
+    Error(ErrorResponse),
+}
+
+impl<T> MSearchResponseItem<T> {
+    /// Returns `true` if the search succeeded
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
+
+    /// Converts the item into a [`Result`]
+    pub fn into_result(self) -> Result<SearchResponse<T>, ErrorResponse> {
+        match self {
+            Self::Success(response) => Ok(*response),
+            Self::Error(error) => Err(error),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for MSearchResponseItem<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Untagged enums buffer their content, which breaks the raw `Source`
+        // of hits <https://github.com/serde-rs/json/issues/779>
+        #[derive(Deserialize)]
+        struct Probe {
+            error: Option<IgnoredAny>,
+        }
+
+        let raw = Box::<RawValue>::deserialize(deserializer)?;
+        let probe: Probe = serde_json::from_str(raw.get()).map_err(D::Error::custom)?;
+
+        match probe.error {
+            Some(_) => serde_json::from_str(raw.get()).map(Self::Error),
+            None => {
+                serde_json::from_str(raw.get()).map(|response| Self::Success(Box::new(response)))
+            }
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = json!({
+          "took": 4,
+          "responses": [
+            {
+              "took": 2,
+              "timed_out": false,
+              "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+              "hits": {
+                "total": { "value": 1, "relation": "eq" },
+                "max_score": 1.0,
+                "hits": [
+                  {
+                    "_index": "my-index-000001",
+                    "_id": "1",
+                    "_score": 1.0,
+                    "_source": { "title": "first" }
+                  }
+                ]
+              },
+              "status": 200
+            },
+            {
+              "took": 1,
+              "timed_out": false,
+              "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+              "hits": {
+                "total": { "value": 0, "relation": "eq" },
+                "max_score": null,
+                "hits": []
+              },
+              "status": 200
+            }
+          ]
+        });
+
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        struct Document {
+            title: String,
+        }
+
+        let subject: MSearchResponse<Document> = serde_json::from_value(json).unwrap();
+        let responses = subject
+            .responses
+            .into_iter()
+            .map(MSearchResponseItem::into_result)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(subject.took, 4);
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].hits.hits[0].source.title, "first");
+        assert!(responses[1].hits.hits.is_empty());
+    }
+
+    #[test]
+    fn deserializes_mixed_responses() {
+        let json = r#"{
+          "took": 3,
+          "responses": [
+            {
+              "took": 2,
+              "timed_out": false,
+              "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+              "hits": {
+                "total": { "value": 1, "relation": "eq" },
+                "max_score": 1.0,
+                "hits": [
+                  {
+                    "_index": "my-index-000001",
+                    "_id": "1",
+                    "_score": 1.0,
+                    "_source": { "title": "first" }
+                  }
+                ]
+              },
+              "status": 200
+            },
+            {
+              "error": {
+                "root_cause": [
+                  {
+                    "type": "index_not_found_exception",
+                    "reason": "no such index [missing-index]",
+                    "index_uuid": "_na_",
+                    "index": "missing-index"
+                  }
+                ],
+                "type": "index_not_found_exception",
+                "reason": "no such index [missing-index]",
+                "index_uuid": "_na_",
+                "index": "missing-index"
+              },
+              "status": 404
+            }
+          ]
+        }"#;
+
+        let subject: MSearchResponse = serde_json::from_str(json).unwrap();
+
+        assert!(subject.responses[0].is_success());
+        assert!(!subject.responses[1].is_success());
+
+        let mut responses = subject
+            .responses
+            .into_iter()
+            .map(MSearchResponseItem::into_result);
+
+        let success = responses.next().unwrap().unwrap();
+        let error = responses.next().unwrap().unwrap_err();
+
+        assert_eq!(
+            success.hits.hits[0].source.to_string(),
+            r#"{ "title": "first" }"#
+        );
+        assert_eq!(error.status, 404);
+        assert_eq!(error.error.index.as_deref(), Some("missing-index"));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A bucket aggregation returning a form of adjacency matrix. The request provides a collection of
+/// named filter expressions, similar to the `filters` aggregation request. Each bucket in the
+/// response represents a non-empty cell in the matrix of intersecting filters.
+///
+/// Given filters named `A`, `B` and `C` the response would return buckets with the following
+/// names: `A`, `B`, `C`, `A&B`, `A&C` and `B&C`, intersections are named after the filters
+/// sorted by their names, regardless of the order they were added.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-adjacency-matrix-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AdjacencyMatrixAggregation {
+    adjacency_matrix: AdjacencyMatrixAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AdjacencyMatrixAggregationInner {
+    #[serde(serialize_with = "serialize_key_value_pairs")]
+    filters: Vec<KeyValuePair<String, Query>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    separator: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AdjacencyMatrixAggregation`]
+    ///
+    /// - `filters` - named filters
+    pub fn adjacency_matrix<I, K, Q>(filters: I) -> AdjacencyMatrixAggregation
+    where
+        I: IntoIterator<Item = (K, Q)>,
+        K: ToString,
+        Q: Into<Query>,
+    {
+        AdjacencyMatrixAggregation {
+            adjacency_matrix: AdjacencyMatrixAggregationInner {
+                filters: filters
+                    .into_iter()
+                    .map(|(name, query)| KeyValuePair::new(name.to_string(), query.into()))
+                    .collect(),
+                separator: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl AdjacencyMatrixAggregation {
+    /// Adds a named filter
+    pub fn filter<T, Q>(mut self, name: T, query: Q) -> Self
+    where
+        T: ToString,
+        Q: Into<Query>,
+    {
+        self.adjacency_matrix
+            .filters
+            .push(KeyValuePair::new(name.to_string(), query.into()));
+        self
+    }
+
+    /// The separator used to join the names of intersecting filters,
+    /// defaults to `&`
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: ToString,
+    {
+        self.adjacency_matrix.separator = Some(separator.to_string());
+        self
+    }
+
+    /// Names of the buckets that can be returned for this aggregation, the
+    /// individual filters followed by every pair of intersecting filters,
+    /// sorted by filter name the same way Elasticsearch names them
+    pub fn bucket_names(&self) -> Vec<String> {
+        let separator = self.adjacency_matrix.separator.as_deref().unwrap_or("&");
+
+        let mut keys: Vec<&str> = self
+            .adjacency_matrix
+            .filters
+            .iter()
+            .map(|filter| filter.key.as_str())
+            .collect();
+        keys.sort_unstable();
+
+        let mut names: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+
+        for (index, first) in keys.iter().enumerate() {
+            for second in &keys[index + 1..] {
+                names.push(format!("{first}{separator}{second}"));
+            }
+        }
+
+        names
+    }
+
+    add_aggregate!();
+}
+
+impl Validate for AdjacencyMatrixAggregation {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if let Some(separator) = &self.adjacency_matrix.separator {
+            if separator.is_empty() {
+                errors.push("adjacency_matrix.separator", "separator cannot be empty");
+            }
+        }
+
+        for filter in &self.adjacency_matrix.filters {
+            errors.nested(
+                &format!("adjacency_matrix.filters.{}", filter.key),
+                &filter.value,
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
+impl ShouldSkip for AdjacencyMatrixAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::adjacency_matrix([
+                ("grpA", Query::terms("accounts", ["hillary", "sidney"])),
+                ("grpB", Query::terms("accounts", ["donald", "mitt"])),
+                ("grpC", Query::terms("accounts", ["mitt"])),
+            ])
+            .separator("&")
+            .aggregate("top_tags", Aggregation::terms("tags")),
+            json!({
+                "adjacency_matrix": {
+                    "filters": {
+                        "grpA": { "terms": { "accounts": ["hillary", "sidney"] } },
+                        "grpB": { "terms": { "accounts": ["donald", "mitt"] } },
+                        "grpC": { "terms": { "accounts": ["mitt"] } }
+                    },
+                    "separator": "&"
+                },
+                "aggs": {
+                    "top_tags": { "terms": { "field": "tags" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn preserves_filters_order() {
+        let aggregation = Aggregation::adjacency_matrix([("b", Query::term("tag", "b"))])
+            .filter("a", Query::term("tag", "a"));
+
+        assert_eq!(
+            serde_json::to_string(&aggregation).unwrap(),
+            r#"{"adjacency_matrix":{"filters":{"b":{"term":{"tag":{"value":"b"}}},"a":{"term":{"tag":{"value":"a"}}}}}}"#
+        );
+    }
+
+    #[test]
+    fn bucket_names() {
+        let aggregation = Aggregation::adjacency_matrix([
+            ("grpA", Query::terms("accounts", ["hillary", "sidney"])),
+            ("grpB", Query::terms("accounts", ["donald", "mitt"])),
+            ("grpC", Query::terms("accounts", ["mitt"])),
+        ]);
+
+        assert_eq!(
+            aggregation.bucket_names(),
+            [
+                "grpA",
+                "grpB",
+                "grpC",
+                "grpA&grpB",
+                "grpA&grpC",
+                "grpB&grpC"
+            ]
+        );
+
+        assert_eq!(
+            aggregation.separator("|").bucket_names(),
+            [
+                "grpA",
+                "grpB",
+                "grpC",
+                "grpA|grpB",
+                "grpA|grpC",
+                "grpB|grpC"
+            ]
+        );
+    }
+
+    #[test]
+    fn bucket_names_are_sorted() {
+        let aggregation = Aggregation::adjacency_matrix([
+            ("c", Query::term("tag", "c")),
+            ("b", Query::term("tag", "b")),
+        ])
+        .filter("a", Query::term("tag", "a"));
+
+        assert_eq!(
+            aggregation.bucket_names(),
+            ["a", "b", "c", "a&b", "a&c", "b&c"]
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let aggregation = Aggregation::adjacency_matrix([("grpA", Query::term("tag", "a"))]);
+
+        assert_eq!(aggregation.clone().separator("&").validate(), Ok(()));
+
+        assert_eq!(
+            aggregation.separator("").validate(),
+            Err(vec![ValidationError::new(
+                "adjacency_matrix.separator",
+                "separator cannot be empty"
+            )])
+        );
+    }
+}
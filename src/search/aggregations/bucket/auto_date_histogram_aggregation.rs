@@ -0,0 +1,163 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation similar to the [date histogram](DateHistogramAggregation) except
+/// instead of providing an interval to use as the width of each bucket, a target number of buckets
+/// is provided and the interval is automatically chosen to best achieve that target.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-autodatehistogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AutoDateHistogramAggregation {
+    auto_date_histogram: AutoDateHistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AutoDateHistogramAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    buckets: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    minimum_interval: Option<CalendarInterval>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AutoDateHistogramAggregation`]
+    ///
+    /// - `field` - date field to group by
+    pub fn auto_date_histogram<T>(field: T) -> AutoDateHistogramAggregation
+    where
+        T: ToString,
+    {
+        AutoDateHistogramAggregation {
+            auto_date_histogram: AutoDateHistogramAggregationInner {
+                field: field.to_string(),
+                buckets: None,
+                format: None,
+                time_zone: None,
+                minimum_interval: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl AutoDateHistogramAggregation {
+    /// The target number of buckets, the number of buckets returned will
+    /// always be less than or equal to this target.
+    ///
+    /// Defaults to `10`
+    pub fn buckets(mut self, buckets: u64) -> Self {
+        self.auto_date_histogram.buckets = Some(buckets);
+        self
+    }
+
+    /// [Date format](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+    /// of the bucket keys returned as `key_as_string`
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.auto_date_histogram.format = Some(format.to_string());
+        self
+    }
+
+    /// Time zone used for bucketing and rounding, either an ISO 8601 UTC
+    /// offset (e.g. `-01:00`) or an IANA time zone ID (e.g. `America/Los_Angeles`).
+    ///
+    /// Defaults to `UTC`
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.auto_date_histogram.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// The smallest rounding interval that will be used, making collection
+    /// more efficient as smaller intervals are not considered.
+    ///
+    /// [`Week`](CalendarInterval::Week) and [`Quarter`](CalendarInterval::Quarter)
+    /// are not accepted by Elasticsearch
+    pub fn minimum_interval(mut self, minimum_interval: CalendarInterval) -> Self {
+        self.auto_date_histogram.minimum_interval = Some(minimum_interval);
+        self
+    }
+
+    /// Defines how documents that are missing a value should be treated,
+    /// by default they are ignored.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.auto_date_histogram.missing = Term::new(missing);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for AutoDateHistogramAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::auto_date_histogram("date"),
+            json!({ "auto_date_histogram": { "field": "date" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::auto_date_histogram("date")
+                .buckets(10)
+                .format("yyyy-MM-dd")
+                .time_zone("-01:00")
+                .minimum_interval(CalendarInterval::Minute)
+                .missing("2000/01/01")
+                .aggregate("total", Aggregation::sum("price")),
+            json!({
+                "auto_date_histogram": {
+                    "field": "date",
+                    "buckets": 10,
+                    "format": "yyyy-MM-dd",
+                    "time_zone": "-01:00",
+                    "minimum_interval": "minute",
+                    "missing": "2000/01/01"
+                },
+                "aggs": {
+                    "total": { "sum": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_exactly() {
+        let aggregation = Aggregation::auto_date_histogram("date")
+            .buckets(5)
+            .format("yyyy-MM-dd")
+            .time_zone("-01:00");
+
+        assert_eq!(
+            serde_json::to_string(&aggregation).unwrap(),
+            r#"{"auto_date_histogram":{"field":"date","buckets":5,"format":"yyyy-MM-dd","time_zone":"-01:00"}}"#
+        );
+    }
+}
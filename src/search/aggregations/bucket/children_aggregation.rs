@@ -0,0 +1,77 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A special single bucket aggregation that selects child documents that have the specified type,
+/// as defined in a [`join` field](https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-children-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ChildrenAggregation {
+    children: ChildrenAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ChildrenAggregationInner {
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ChildrenAggregation`]
+    ///
+    /// - `ty` - the child type that the buckets in the parent space should be mapped to
+    pub fn children<T>(ty: T) -> ChildrenAggregation
+    where
+        T: ToString,
+    {
+        ChildrenAggregation {
+            children: ChildrenAggregationInner { ty: ty.to_string() },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl ChildrenAggregation {
+    add_aggregate!();
+}
+
+impl ShouldSkip for ChildrenAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::children("answer"),
+            json!({ "children": { "type": "answer" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("tags.keyword").aggregate(
+                "to-answers",
+                Aggregation::children("answer").aggregate(
+                    "top-names",
+                    Aggregation::terms("owner.display_name.keyword"),
+                ),
+            ),
+            json!({
+                "terms": { "field": "tags.keyword" },
+                "aggs": {
+                    "to-answers": {
+                        "children": { "type": "answer" },
+                        "aggs": {
+                            "top-names": {
+                                "terms": { "field": "owner.display_name.keyword" }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+use serde_json::Value;
+
+/// A multi-bucket aggregation that creates composite buckets from different sources. Unlike the
+/// other multi-bucket aggregations, the composite aggregation can be used to paginate **all**
+/// buckets from a multi-level aggregation efficiently, by passing the `after_key` of the response
+/// back to [`after`](Self::after).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-composite-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CompositeAggregation {
+    composite: CompositeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct CompositeAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    sources: Vec<CompositeSource>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    after: Map<String, Value>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`CompositeAggregation`], sources are added
+    /// with [`source`](CompositeAggregation::source)
+    pub fn composite() -> CompositeAggregation {
+        CompositeAggregation {
+            composite: CompositeAggregationInner {
+                size: None,
+                sources: Vec::new(),
+                after: Map::new(),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl CompositeAggregation {
+    /// The number of composite buckets that should be returned.
+    ///
+    /// Defaults to `10`
+    pub fn size(mut self, size: u64) -> Self {
+        self.composite.size = Some(size);
+        self
+    }
+
+    /// Adds a source, the order of the sources determines the order of the
+    /// keys in the composite buckets
+    pub fn source<T>(mut self, source: T) -> Self
+    where
+        T: Into<CompositeSource>,
+    {
+        self.composite.sources.push(source.into());
+        self
+    }
+
+    /// Adds several sources, the order of the sources determines the order
+    /// of the keys in the composite buckets
+    pub fn sources<I>(mut self, sources: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<CompositeSource>,
+    {
+        self.composite
+            .sources
+            .extend(sources.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns the buckets following the given composite key, i.e. the
+    /// `after_key` of the previous page, see
+    /// [`SearchResponse::after_key`](crate::SearchResponse::after_key)
+    pub fn after<I, K, V>(mut self, after: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: Into<Value>,
+    {
+        self.composite.after = after
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.into()))
+            .collect();
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for CompositeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::composite().source(CompositeSource::terms("product", "product")),
+            json!({
+                "composite": {
+                    "sources": [
+                        { "product": { "terms": { "field": "product" } } }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::composite()
+                .size(2)
+                .source(CompositeSource::date_histogram(
+                    "date",
+                    "timestamp",
+                    CalendarInterval::Day,
+                ))
+                .source(CompositeSource::terms("product", "product").order(SortOrder::Asc))
+                .after([
+                    ("date", json!(1494288000000i64)),
+                    ("product", json!("mad max")),
+                ])
+                .aggregate("the_avg", Aggregation::avg("price")),
+            json!({
+                "composite": {
+                    "size": 2,
+                    "sources": [
+                        {
+                            "date": {
+                                "date_histogram": {
+                                    "field": "timestamp",
+                                    "calendar_interval": "day"
+                                }
+                            }
+                        },
+                        {
+                            "product": {
+                                "terms": { "field": "product", "order": "asc" }
+                            }
+                        }
+                    ],
+                    "after": { "date": 1494288000000i64, "product": "mad max" }
+                },
+                "aggs": {
+                    "the_avg": { "avg": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_sources_in_order() {
+        let aggregation = Aggregation::composite()
+            .sources([
+                CompositeSource::terms("shop", "shop"),
+                CompositeSource::terms("brand", "brand"),
+            ])
+            .size(1);
+
+        assert_eq!(
+            serde_json::to_string(&aggregation).unwrap(),
+            r#"{"composite":{"size":1,"sources":[{"shop":{"terms":{"field":"shop"}}},{"brand":{"terms":{"field":"brand"}}}]}}"#
+        );
+    }
+}
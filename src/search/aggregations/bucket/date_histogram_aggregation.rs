@@ -0,0 +1,261 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation similar to the histogram except it can only be used with date or
+/// date range values. Intervals are either calendar-aware, e.g. a month may have 28 to 31 days,
+/// or fixed, e.g. always `30d`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-datehistogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DateHistogramAggregation {
+    date_histogram: DateHistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct DateHistogramAggregationInner {
+    field: String,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    interval: Option<DateHistogramInterval>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    offset: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: TermsOrderCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    extended_bounds: Option<HistogramBounds<DateBound>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hard_bounds: Option<HistogramBounds<DateBound>>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`DateHistogramAggregation`]
+    ///
+    /// - `field` - date field to group by
+    pub fn date_histogram<T>(field: T) -> DateHistogramAggregation
+    where
+        T: ToString,
+    {
+        DateHistogramAggregation {
+            date_histogram: DateHistogramAggregationInner {
+                field: field.to_string(),
+                interval: None,
+                format: None,
+                time_zone: None,
+                offset: None,
+                min_doc_count: None,
+                missing: None,
+                keyed: None,
+                order: Default::default(),
+                extended_bounds: None,
+                hard_bounds: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl DateHistogramAggregation {
+    /// Calendar-aware interval, which understands that daylight savings
+    /// changes the length of specific days and months have different amounts
+    /// of days.
+    ///
+    /// Replaces the [`fixed_interval`](Self::fixed_interval) if one was set.
+    pub fn calendar_interval(mut self, calendar_interval: CalendarInterval) -> Self {
+        self.date_histogram.interval =
+            Some(DateHistogramInterval::CalendarInterval(calendar_interval));
+        self
+    }
+
+    /// Fixed interval, which is always a multiple of SI units and does not
+    /// change based on calendaring context, e.g. `90m` or `30d`.
+    ///
+    /// Replaces the [`calendar_interval`](Self::calendar_interval) if one was set.
+    pub fn fixed_interval<T>(mut self, fixed_interval: T) -> Self
+    where
+        T: Into<Time>,
+    {
+        self.date_histogram.interval =
+            Some(DateHistogramInterval::FixedInterval(fixed_interval.into()));
+        self
+    }
+
+    /// [Date format](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+    /// of the bucket keys returned as `key_as_string`, also used to parse
+    /// the bounds
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.format = Some(format.to_string());
+        self
+    }
+
+    /// Time zone used for bucketing and rounding, either an ISO 8601 UTC
+    /// offset (e.g. `-01:00`) or an IANA time zone ID (e.g. `America/Los_Angeles`).
+    ///
+    /// Defaults to `UTC`
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Changes the start value of each bucket by the given positive (`+`) or
+    /// negative (`-`) duration, e.g. `+6h` to start days at 6am
+    pub fn offset<T>(mut self, offset: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.offset = Some(offset.to_string());
+        self
+    }
+
+    /// Only returns buckets that have at least this many documents, use `0`
+    /// to return empty buckets as well
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.date_histogram.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Defines how documents that are missing a value should be treated,
+    /// by default they are ignored.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.date_histogram.missing = Term::new(missing);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their formatted key rather
+    /// than as an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.date_histogram.keyed = Some(keyed);
+        self
+    }
+
+    /// The order of the buckets, by default they are ordered by their key
+    /// ascending
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: Into<TermsOrderCollection>,
+    {
+        self.date_histogram.order = order.into();
+        self
+    }
+
+    /// Forces the histogram to start and end building buckets on the given
+    /// dates, only taking effect when `min_doc_count` is `0`
+    pub fn extended_bounds(mut self, extended_bounds: HistogramBounds<DateBound>) -> Self {
+        self.date_histogram.extended_bounds = Some(extended_bounds);
+        self
+    }
+
+    /// Limits the range of buckets in the histogram, buckets outside of the
+    /// bounds are not returned even if documents fall into them
+    pub fn hard_bounds(mut self, hard_bounds: HistogramBounds<DateBound>) -> Self {
+        self.date_histogram.hard_bounds = Some(hard_bounds);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for DateHistogramAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("sold_date"),
+            json!({ "date_histogram": { "field": "sold_date" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("sold_date")
+                .calendar_interval(CalendarInterval::Month)
+                .format("yyyy-MM-dd")
+                .time_zone("-01:00")
+                .offset("+6h")
+                .min_doc_count(0)
+                .missing("2000/01/01")
+                .keyed(true)
+                .order(TermsOrder::key_descending())
+                .extended_bounds(HistogramBounds::new("2020-01-01", "2020-12-31"))
+                .hard_bounds(HistogramBounds::at_least(1577836800000))
+                .aggregate("total", Aggregation::sum("price")),
+            json!({
+                "date_histogram": {
+                    "field": "sold_date",
+                    "calendar_interval": "month",
+                    "format": "yyyy-MM-dd",
+                    "time_zone": "-01:00",
+                    "offset": "+6h",
+                    "min_doc_count": 0,
+                    "missing": "2000/01/01",
+                    "keyed": true,
+                    "order": [{ "_key": "desc" }],
+                    "extended_bounds": { "min": "2020-01-01", "max": "2020-12-31" },
+                    "hard_bounds": { "min": 1577836800000i64 }
+                },
+                "aggs": {
+                    "total": { "sum": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn intervals_are_mutually_exclusive() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("sold_date")
+                .calendar_interval(CalendarInterval::Month)
+                .fixed_interval(Time::Days(30)),
+            json!({
+                "date_histogram": {
+                    "field": "sold_date",
+                    "fixed_interval": "30d"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("sold_date")
+                .fixed_interval("90m")
+                .calendar_interval("1q".parse().unwrap()),
+            json!({
+                "date_histogram": {
+                    "field": "sold_date",
+                    "calendar_interval": "quarter"
+                }
+            }),
+        );
+    }
+}
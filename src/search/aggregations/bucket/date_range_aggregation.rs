@@ -0,0 +1,173 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A range aggregation that is dedicated for date values. The main difference between this
+/// aggregation and the normal [range](RangeAggregation) aggregation is that the `from` and `to`
+/// values can be expressed in
+/// [Date Math](https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#date-math)
+/// expressions.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-daterange-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DateRangeAggregation {
+    date_range: DateRangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct DateRangeAggregationInner {
+    field: String,
+
+    ranges: Vec<AggregationDateRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`DateRangeAggregation`]
+    ///
+    /// - `field` - date field to group by
+    pub fn date_range<T>(field: T) -> DateRangeAggregation
+    where
+        T: ToString,
+    {
+        DateRangeAggregation {
+            date_range: DateRangeAggregationInner {
+                field: field.to_string(),
+                ranges: Vec::new(),
+                format: None,
+                time_zone: None,
+                keyed: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl DateRangeAggregation {
+    /// Adds a range, buckets are returned in the order the ranges were added
+    pub fn range(mut self, range: AggregationDateRange) -> Self {
+        self.date_range.ranges.push(range);
+        self
+    }
+
+    /// Adds several ranges, buckets are returned in the order the ranges
+    /// were added
+    pub fn ranges<I>(mut self, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = AggregationDateRange>,
+    {
+        self.date_range.ranges.extend(ranges);
+        self
+    }
+
+    /// [Date format](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+    /// of the `from_as_string` and `to_as_string` values in the response, also
+    /// used to parse the range limits
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_range.format = Some(format.to_string());
+        self
+    }
+
+    /// Time zone used to convert the range limits to UTC, either an ISO 8601
+    /// UTC offset (e.g. `-01:00`) or an IANA time zone ID (e.g. `America/Los_Angeles`)
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_range.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their key rather than as an
+    /// array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.date_range.keyed = Some(keyed);
+        self
+    }
+
+    /// Defines how documents that are missing a value should be treated,
+    /// by default they are ignored.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.date_range.missing = Term::new(missing);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for DateRangeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::date_range("date").format("MM-yyyy").ranges([
+                AggregationDateRange::new().to("now-10M/M"),
+                AggregationDateRange::new().from("now-10M/M"),
+            ]),
+            json!({
+                "date_range": {
+                    "field": "date",
+                    "format": "MM-yyyy",
+                    "ranges": [
+                        { "to": "now-10M/M" },
+                        { "from": "now-10M/M" }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::date_range("date")
+                .range(AggregationDateRange::new().key("older").to("2016/02/01"))
+                .range(
+                    AggregationDateRange::new()
+                        .key("newer")
+                        .from(1454284800000)
+                        .to("now/d"),
+                )
+                .time_zone("CET")
+                .keyed(true)
+                .missing("1976/11/30")
+                .aggregate("max_price", Aggregation::max("price")),
+            json!({
+                "date_range": {
+                    "field": "date",
+                    "ranges": [
+                        { "key": "older", "to": "2016/02/01" },
+                        { "key": "newer", "from": 1454284800000i64, "to": "now/d" }
+                    ],
+                    "time_zone": "CET",
+                    "keyed": true,
+                    "missing": "1976/11/30"
+                },
+                "aggs": {
+                    "max_price": { "max": { "field": "price" } }
+                }
+            }),
+        );
+    }
+}
@@ -14,23 +14,13 @@ pub struct DiversifiedSamplerAggregation {
     aggs: Aggregations,
 }
 
-/// `execution_hint` field values.
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Copy)]
-#[serde(rename_all = "snake_case")]
-pub enum ExecutionHint {
-    /// Hold field values directly
-    Map,
-
-    /// Hold hashes of the field values - with potential for hash collisions
-    BytesHash,
-
-    /// Hold ordinals of the field as determined by the Lucene index
-    GlobalOrdinals,
-}
-
 #[derive(Debug, Clone, Serialize, PartialEq)]
 struct DiversifiedSamplerAggregationInner {
-    field: String,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     shard_size: Option<u64>,
@@ -39,18 +29,56 @@ struct DiversifiedSamplerAggregationInner {
     max_docs_per_value: Option<u64>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    execution_hint: Option<ExecutionHint>,
+    execution_hint: Option<DiversifiedExecutionHint>,
 }
 
 impl Aggregation {
     /// Creates an instance of [`DiversifiedSamplerAggregation`]
+    ///
+    /// - `field` - field providing the values used for de-duplication
     pub fn diversified_sampler<T>(field: T) -> DiversifiedSamplerAggregation
     where
         T: ToString,
     {
-        DiversifiedSamplerAggregation {
+        DiversifiedSamplerAggregation::new(field)
+    }
+
+    /// Creates an instance of [`DiversifiedSamplerAggregation`]
+    ///
+    /// - `script` - script providing the values used for de-duplication
+    pub fn diversified_sampler_script(script: Script) -> DiversifiedSamplerAggregation {
+        DiversifiedSamplerAggregation::script(script)
+    }
+}
+
+impl DiversifiedSamplerAggregation {
+    /// Creates an instance of [`DiversifiedSamplerAggregation`]
+    ///
+    /// - `field` - field providing the values used for de-duplication
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
             diversified_sampler: DiversifiedSamplerAggregationInner {
-                field: field.to_string(),
+                field: field.to_string().into(),
+                script: None,
+                shard_size: None,
+                max_docs_per_value: None,
+                execution_hint: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    /// Creates an instance of [`DiversifiedSamplerAggregation`]
+    ///
+    /// - `script` - script providing the values used for de-duplication
+    pub fn script(script: Script) -> Self {
+        Self {
+            diversified_sampler: DiversifiedSamplerAggregationInner {
+                field: None,
+                script: script.into(),
                 shard_size: None,
                 max_docs_per_value: None,
                 execution_hint: None,
@@ -58,9 +86,7 @@ impl Aggregation {
             aggs: Aggregations::new(),
         }
     }
-}
 
-impl DiversifiedSamplerAggregation {
     /// The `shard_size` parameter limits how many top-scoring documents are
     /// collected in the sample processed on each shard. The default value is 100.
     pub fn shard_size(mut self, shard_size: u64) -> Self {
@@ -81,7 +107,7 @@ impl DiversifiedSamplerAggregation {
     /// - hold field values directly (`map`)
     /// - hold ordinals of the field as determined by the Lucene index (`global_ordinals`)
     /// - hold hashes of the field values - with potential for hash collisions (`bytes_hash`)
-    pub fn execution_hint(mut self, execution_hint: ExecutionHint) -> Self {
+    pub fn execution_hint(mut self, execution_hint: DiversifiedExecutionHint) -> Self {
         self.diversified_sampler.execution_hint = Some(execution_hint);
         self
     }
@@ -89,12 +115,25 @@ impl DiversifiedSamplerAggregation {
     add_aggregate!();
 }
 
+impl ShouldSkip for DiversifiedSamplerAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn serialization() {
+        assert_serialize_aggregation(
+            DiversifiedSamplerAggregation::new("author")
+                .execution_hint(DiversifiedExecutionHint::BytesHash),
+            json!({
+                "diversified_sampler": {
+                    "field": "author",
+                    "execution_hint": "bytes_hash"
+                }
+            }),
+        );
+
         assert_serialize_aggregation(
             Aggregation::diversified_sampler("catalog_id").shard_size(50),
             json!({
@@ -109,7 +148,7 @@ mod tests {
             Aggregation::diversified_sampler("catalog_id")
                 .shard_size(50)
                 .max_docs_per_value(2)
-                .execution_hint(ExecutionHint::GlobalOrdinals)
+                .execution_hint(DiversifiedExecutionHint::GlobalOrdinals)
                 .aggregate("catalog", Aggregation::terms("catalog_id"))
                 .aggregate("brand", Aggregation::terms("brand_id")),
             json!({
@@ -134,4 +173,50 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_significant_terms_sample() {
+        assert_serialize_aggregation(
+            Aggregation::diversified_sampler("author")
+                .shard_size(200)
+                .max_docs_per_value(3)
+                .execution_hint(DiversifiedExecutionHint::Map)
+                .aggregate("keywords", Aggregation::significant_terms("tags")),
+            json!({
+                "diversified_sampler": {
+                    "field": "author",
+                    "shard_size": 200,
+                    "max_docs_per_value": 3,
+                    "execution_hint": "map"
+                },
+                "aggs": {
+                    "keywords": {
+                        "significant_terms": { "field": "tags" }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::diversified_sampler_script(
+                Script::source("doc['tags'].hashCode()").lang("painless"),
+            )
+            .shard_size(200)
+            .aggregate("keywords", Aggregation::significant_terms("tags")),
+            json!({
+                "diversified_sampler": {
+                    "script": {
+                        "source": "doc['tags'].hashCode()",
+                        "lang": "painless"
+                    },
+                    "shard_size": 200
+                },
+                "aggs": {
+                    "keywords": {
+                        "significant_terms": { "field": "tags" }
+                    }
+                }
+            }),
+        );
+    }
 }
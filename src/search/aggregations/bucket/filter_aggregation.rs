@@ -31,6 +31,8 @@ impl FilterAggregation {
     add_aggregate!();
 }
 
+impl ShouldSkip for FilterAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,199 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation where each bucket contains the documents that match a query.
+///
+/// Buckets are either named, in which case they keep the order they were added in:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let aggregation =
+/// Aggregation::filters()
+///     .filter("errors", Query::r#match("body", "error"))
+///     .filter("warnings", Query::r#match("body", "warning"));
+/// ```
+/// or anonymous and returned in the same order as the queries:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let aggregation =
+/// Aggregation::anonymous_filters([
+///     Query::r#match("body", "error"),
+///     Query::r#match("body", "warning"),
+/// ]);
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-filters-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FiltersAggregation {
+    filters: FiltersAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct FiltersAggregationInner {
+    filters: Filters,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    other_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    other_bucket_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+enum Filters {
+    #[serde(serialize_with = "serialize_key_value_pairs")]
+    Named(Vec<KeyValuePair<String, Query>>),
+
+    Anonymous(Vec<Query>),
+}
+
+impl Aggregation {
+    /// Creates an instance of [`FiltersAggregation`] with named filters,
+    /// added with [`filter`](FiltersAggregation::filter)
+    pub fn filters() -> FiltersAggregation {
+        FiltersAggregation {
+            filters: FiltersAggregationInner {
+                filters: Filters::Named(Vec::new()),
+                other_bucket: None,
+                other_bucket_key: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    /// Creates an instance of [`FiltersAggregation`] with anonymous filters,
+    /// buckets are returned as an array in the order of the queries
+    pub fn anonymous_filters<I>(queries: I) -> FiltersAggregation
+    where
+        I: IntoIterator,
+        I::Item: Into<Query>,
+    {
+        FiltersAggregation {
+            filters: FiltersAggregationInner {
+                filters: Filters::Anonymous(queries.into_iter().map(Into::into).collect()),
+                other_bucket: None,
+                other_bucket_key: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl FiltersAggregation {
+    /// Adds a filter
+    ///
+    /// Filters created with [`anonymous_filters`](Aggregation::anonymous_filters)
+    /// are identified by their position, so for those the name is ignored and
+    /// the query is appended after the existing ones
+    pub fn filter<T, Q>(mut self, name: T, query: Q) -> Self
+    where
+        T: ToString,
+        Q: Into<Query>,
+    {
+        match &mut self.filters.filters {
+            Filters::Named(filters) => {
+                filters.push(KeyValuePair::new(name.to_string(), query.into()))
+            }
+            Filters::Anonymous(filters) => filters.push(query.into()),
+        }
+        self
+    }
+
+    /// Adds a bucket with all documents that do not match any of the
+    /// filters, keyed `_other_` unless
+    /// [`other_bucket_key`](Self::other_bucket_key) is set
+    pub fn other_bucket(mut self, other_bucket: bool) -> Self {
+        self.filters.other_bucket = Some(other_bucket);
+        self
+    }
+
+    /// Key of the bucket with all documents that do not match any of the
+    /// filters, implies [`other_bucket`](Self::other_bucket)
+    pub fn other_bucket_key<T>(mut self, other_bucket_key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.filters.other_bucket_key = Some(other_bucket_key.to_string());
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for FiltersAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::filters()
+                .filter("errors", Query::r#match("body", "error"))
+                .filter("warnings", Query::r#match("body", "warning"))
+                .other_bucket_key("other_messages")
+                .aggregate("monthly", Aggregation::terms("month")),
+            json!({
+                "filters": {
+                    "filters": {
+                        "errors": { "match": { "body": { "query": "error" } } },
+                        "warnings": { "match": { "body": { "query": "warning" } } }
+                    },
+                    "other_bucket_key": "other_messages"
+                },
+                "aggs": {
+                    "monthly": { "terms": { "field": "month" } }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::anonymous_filters([
+                Query::r#match("body", "error"),
+                Query::r#match("body", "warning"),
+            ])
+            .other_bucket(true),
+            json!({
+                "filters": {
+                    "filters": [
+                        { "match": { "body": { "query": "error" } } },
+                        { "match": { "body": { "query": "warning" } } }
+                    ],
+                    "other_bucket": true
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn ignores_names_of_anonymous_filters() {
+        assert_serialize_aggregation(
+            Aggregation::anonymous_filters([Query::r#match("body", "error")])
+                .filter("warnings", Query::r#match("body", "warning")),
+            json!({
+                "filters": {
+                    "filters": [
+                        { "match": { "body": { "query": "error" } } },
+                        { "match": { "body": { "query": "warning" } } }
+                    ]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn preserves_named_filters_order() {
+        let aggregation = Aggregation::filters()
+            .filter("warnings", Query::term("level", "warning"))
+            .filter("errors", Query::term("level", "error"));
+
+        assert_eq!(
+            serde_json::to_string(&aggregation).unwrap(),
+            r#"{"filters":{"filters":{"warnings":{"term":{"level":{"value":"warning"}}},"errors":{"term":{"level":{"value":"error"}}}}}}"#
+        );
+    }
+}
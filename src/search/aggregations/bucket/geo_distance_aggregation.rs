@@ -0,0 +1,182 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation that works on `geo_point` fields and conceptually works very
+/// similar to the [range](crate::RangeAggregation) aggregation. The user can define a point of
+/// origin and a set of distance range buckets. The aggregation evaluates the distance of each
+/// document value from the origin point and determines the buckets it belongs to based on the
+/// ranges (a document belongs to a bucket if the distance between the document and the origin
+/// falls within the distance range of the bucket).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geodistance-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoDistanceAggregation {
+    geo_distance: GeoDistanceAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoDistanceAggregationInner {
+    field: String,
+
+    origin: GeoPoint,
+
+    ranges: Vec<DistanceRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unit: Option<DistanceUnit>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    distance_type: Option<GeoDistanceType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoDistanceAggregation`]
+    ///
+    /// - `field` - `geo_point` field to compute the distances of
+    /// - `origin` - point the distances are computed from
+    pub fn geo_distance<T, U>(field: T, origin: U) -> GeoDistanceAggregation
+    where
+        T: ToString,
+        U: Into<GeoPoint>,
+    {
+        GeoDistanceAggregation {
+            geo_distance: GeoDistanceAggregationInner {
+                field: field.to_string(),
+                origin: origin.into(),
+                ranges: Vec::new(),
+                unit: None,
+                distance_type: None,
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GeoDistanceAggregation {
+    /// Adds a range, buckets are returned in the order the ranges were added
+    pub fn range(mut self, range: DistanceRange) -> Self {
+        self.geo_distance.ranges.push(range);
+        self
+    }
+
+    /// Adds several ranges, buckets are returned in the order the ranges
+    /// were added
+    pub fn ranges<I>(mut self, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = DistanceRange>,
+    {
+        self.geo_distance.ranges.extend(ranges);
+        self
+    }
+
+    /// The unit of the distance ranges, defaults to meters
+    pub fn unit(mut self, unit: DistanceUnit) -> Self {
+        self.geo_distance.unit = Some(unit);
+        self
+    }
+
+    /// How the distance is calculated, defaults to [`arc`](GeoDistanceType::Arc)
+    pub fn distance_type(mut self, distance_type: GeoDistanceType) -> Self {
+        self.geo_distance.distance_type = Some(distance_type);
+        self
+    }
+
+    /// Setting the `keyed` flag to `true` will associate a unique string key
+    /// with each bucket and return the ranges as a hash rather than an array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.geo_distance.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for GeoDistanceAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geo_distance("location", GeoPoint::new(52.3760, 4.894))
+                .range(DistanceRange::new().to(100000))
+                .range(DistanceRange::new().from(100000).to(300000))
+                .range(DistanceRange::new().from(300000)),
+            json!({
+                "geo_distance": {
+                    "field": "location",
+                    "origin": { "lat": 52.3760, "lon": 4.894 },
+                    "ranges": [
+                        { "to": 100000 },
+                        { "from": 100000, "to": 300000 },
+                        { "from": 300000 }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::geo_distance("pin.location", GeoPoint::new(52.3760, 4.894))
+                .range(DistanceRange::new().to(100.0))
+                .range(DistanceRange::new().from(100.0).to(300.0))
+                .unit(DistanceUnit::Kilometers)
+                .distance_type(GeoDistanceType::Plane)
+                .aggregate("avg_price", Aggregation::avg("price")),
+            json!({
+                "geo_distance": {
+                    "field": "pin.location",
+                    "origin": { "lat": 52.3760, "lon": 4.894 },
+                    "ranges": [
+                        { "to": 100.0 },
+                        { "from": 100.0, "to": 300.0 }
+                    ],
+                    "unit": "km",
+                    "distance_type": "plane"
+                },
+                "aggs": {
+                    "avg_price": { "avg": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization_keyed() {
+        assert_serialize_aggregation(
+            Aggregation::geo_distance(
+                "location",
+                GeoPoint::Text {
+                    lat: 52.376,
+                    lon: 4.894,
+                },
+            )
+            .ranges([
+                DistanceRange::new().key("close").to(100000),
+                DistanceRange::new().key("nearby").from(100000).to(300000),
+                DistanceRange::new().key("far").from(300000),
+            ])
+            .keyed(true),
+            json!({
+                "geo_distance": {
+                    "field": "location",
+                    "origin": "52.376,4.894",
+                    "ranges": [
+                        { "key": "close", "to": 100000 },
+                        { "key": "nearby", "from": 100000, "to": 300000 },
+                        { "key": "far", "from": 300000 }
+                    ],
+                    "keyed": true
+                }
+            }),
+        );
+    }
+}
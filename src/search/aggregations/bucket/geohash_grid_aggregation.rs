@@ -0,0 +1,123 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation that groups [`geo_point`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+/// and [`geo_shape`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
+/// values into buckets that represent a grid. Each cell is labeled using a
+/// [geohash](https://en.wikipedia.org/wiki/Geohash) which is of user-definable precision.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geohashgrid-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeohashGridAggregation {
+    geohash_grid: GeohashGridAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeohashGridAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    bounds: Option<GeoBoundingBox>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeohashGridAggregation`]
+    ///
+    /// - `field` - field containing indexed geo values
+    pub fn geohash_grid<T>(field: T) -> GeohashGridAggregation
+    where
+        T: ToString,
+    {
+        GeohashGridAggregation {
+            geohash_grid: GeohashGridAggregationInner {
+                field: field.to_string(),
+                precision: None,
+                bounds: None,
+                size: None,
+                shard_size: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GeohashGridAggregation {
+    /// The string length of the geohashes used to define cells/buckets in the results, between
+    /// `1` and `12`, defaults to `5`
+    pub fn precision(mut self, precision: u8) -> Self {
+        debug_assert!(
+            (1..=12).contains(&precision),
+            "precision must be between 1 and 12"
+        );
+        self.geohash_grid.precision = Some(precision);
+        self
+    }
+
+    /// The bounding box used to filter the values of the field, only cells
+    /// intersecting it are returned
+    pub fn bounds<T>(mut self, bounds: T) -> Self
+    where
+        T: Into<GeoBoundingBox>,
+    {
+        self.geohash_grid.bounds = Some(bounds.into());
+        self
+    }
+
+    /// The maximum number of buckets to return, defaults to `10000`
+    pub fn size(mut self, size: u64) -> Self {
+        self.geohash_grid.size = Some(size);
+        self
+    }
+
+    /// To allow for more accurate counting of the top cells returned in the
+    /// final result the aggregation defaults to returning
+    /// `max(10, (size x number-of-shards))` buckets from each shard
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.geohash_grid.shard_size = Some(shard_size);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for GeohashGridAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geohash_grid("location"),
+            json!({ "geohash_grid": { "field": "location" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::geohash_grid("location")
+                .precision(3)
+                .size(100)
+                .shard_size(200),
+            json!({
+                "geohash_grid": {
+                    "field": "location",
+                    "precision": 3,
+                    "size": 100,
+                    "shard_size": 200
+                }
+            }),
+        );
+    }
+}
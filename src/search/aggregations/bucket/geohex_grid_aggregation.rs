@@ -0,0 +1,113 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation that groups [`geo_point`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+/// values into buckets that represent a grid. Each cell corresponds to a
+/// [H3 cell index](https://h3geo.org/docs/core-library/h3Indexing#h3-cell-indexp) and is
+/// labeled using the H3Index representation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geohexgrid-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeohexGridAggregation {
+    geohex_grid: GeohexGridAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeohexGridAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    bounds: Option<GeoBoundingBox>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeohexGridAggregation`]
+    ///
+    /// - `field` - field containing indexed geo values
+    pub fn geohex_grid<T>(field: T) -> GeohexGridAggregation
+    where
+        T: ToString,
+    {
+        GeohexGridAggregation {
+            geohex_grid: GeohexGridAggregationInner {
+                field: field.to_string(),
+                precision: None,
+                bounds: None,
+                size: None,
+                shard_size: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GeohexGridAggregation {
+    /// The H3 resolution of the key used to define cells/buckets in the results, between `0`
+    /// and `15`, defaults to `6`
+    pub fn precision(mut self, precision: u8) -> Self {
+        debug_assert!(
+            (0..=15).contains(&precision),
+            "precision must be between 0 and 15"
+        );
+        self.geohex_grid.precision = Some(precision);
+        self
+    }
+
+    /// The bounding box used to filter the values of the field, only cells
+    /// intersecting it are returned
+    pub fn bounds<T>(mut self, bounds: T) -> Self
+    where
+        T: Into<GeoBoundingBox>,
+    {
+        self.geohex_grid.bounds = Some(bounds.into());
+        self
+    }
+
+    /// The maximum number of buckets to return, defaults to `10000`
+    pub fn size(mut self, size: u64) -> Self {
+        self.geohex_grid.size = Some(size);
+        self
+    }
+
+    /// To allow for more accurate counting of the top cells returned in the
+    /// final result the aggregation defaults to returning
+    /// `max(10, (size x number-of-shards))` buckets from each shard
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.geohex_grid.shard_size = Some(shard_size);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for GeohexGridAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geohex_grid("location").precision(4),
+            json!({
+                "geohex_grid": {
+                    "field": "location",
+                    "precision": 4
+                }
+            }),
+        );
+    }
+}
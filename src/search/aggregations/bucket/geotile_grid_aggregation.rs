@@ -0,0 +1,127 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation that groups [`geo_point`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+/// and [`geo_shape`](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
+/// values into buckets that represent a grid. Each cell corresponds to a
+/// [map tile](https://en.wikipedia.org/wiki/Tiled_web_map) as used by many online map sites,
+/// labeled using a `{zoom}/{x}/{y}` format.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-geotilegrid-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeotileGridAggregation {
+    geotile_grid: GeotileGridAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeotileGridAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    bounds: Option<GeoBoundingBox>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeotileGridAggregation`]
+    ///
+    /// - `field` - field containing indexed geo values
+    pub fn geotile_grid<T>(field: T) -> GeotileGridAggregation
+    where
+        T: ToString,
+    {
+        GeotileGridAggregation {
+            geotile_grid: GeotileGridAggregationInner {
+                field: field.to_string(),
+                precision: None,
+                bounds: None,
+                size: None,
+                shard_size: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl GeotileGridAggregation {
+    /// The zoom of the key used to define cells/buckets in the results, between `0` and `29`,
+    /// defaults to `7`
+    pub fn precision(mut self, precision: u8) -> Self {
+        debug_assert!(
+            (0..=29).contains(&precision),
+            "precision must be between 0 and 29"
+        );
+        self.geotile_grid.precision = Some(precision);
+        self
+    }
+
+    /// The bounding box used to filter the values of the field, only cells
+    /// intersecting it are returned
+    pub fn bounds<T>(mut self, bounds: T) -> Self
+    where
+        T: Into<GeoBoundingBox>,
+    {
+        self.geotile_grid.bounds = Some(bounds.into());
+        self
+    }
+
+    /// The maximum number of buckets to return, defaults to `10000`
+    pub fn size(mut self, size: u64) -> Self {
+        self.geotile_grid.size = Some(size);
+        self
+    }
+
+    /// To allow for more accurate counting of the top cells returned in the
+    /// final result the aggregation defaults to returning
+    /// `max(10, (size x number-of-shards))` buckets from each shard
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.geotile_grid.shard_size = Some(shard_size);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for GeotileGridAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geotile_grid("location")
+                .precision(8)
+                .bounds(GeoBoundingBox::main_diagonal(
+                    GeoLocation::new(52.4, 4.9),
+                    GeoLocation::new(52.3, 5.0),
+                ))
+                .aggregate("centroid", Aggregation::geo_centroid("location")),
+            json!({
+                "geotile_grid": {
+                    "field": "location",
+                    "precision": 8,
+                    "bounds": {
+                        "top_left": [4.9, 52.4],
+                        "bottom_right": [5.0, 52.3]
+                    }
+                },
+                "aggs": {
+                    "centroid": { "geo_centroid": { "field": "location" } }
+                }
+            }),
+        );
+    }
+}
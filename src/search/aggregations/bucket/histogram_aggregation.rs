@@ -0,0 +1,200 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket values source based aggregation that can be applied on numeric values or
+/// numeric range values extracted from the documents. It dynamically builds fixed size
+/// (a.k.a. interval) buckets over the values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-histogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HistogramAggregation {
+    histogram: HistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct HistogramAggregationInner {
+    field: String,
+
+    interval: f64,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    offset: Option<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: TermsOrderCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    extended_bounds: Option<HistogramBounds<f64>>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    hard_bounds: Option<HistogramBounds<f64>>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`HistogramAggregation`]
+    ///
+    /// - `field` - numeric field to group by
+    /// - `interval` - width of each bucket, must be a positive decimal
+    pub fn histogram<T, U>(field: T, interval: U) -> HistogramAggregation
+    where
+        T: ToString,
+        U: num_traits::AsPrimitive<f64>,
+    {
+        HistogramAggregation {
+            histogram: HistogramAggregationInner {
+                field: field.to_string(),
+                interval: interval.as_(),
+                offset: None,
+                min_doc_count: None,
+                missing: None,
+                keyed: None,
+                order: Default::default(),
+                extended_bounds: None,
+                hard_bounds: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl HistogramAggregation {
+    /// Shifts the bucket boundaries by the given value, which must be in the
+    /// range `[0, interval)`.
+    ///
+    /// Defaults to `0`, buckets then start at multiples of the interval
+    pub fn offset<T>(mut self, offset: T) -> Self
+    where
+        T: num_traits::AsPrimitive<f64>,
+    {
+        self.histogram.offset = Some(offset.as_());
+        self
+    }
+
+    /// Only returns buckets that have at least this many documents, use `0`
+    /// to return empty buckets as well
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.histogram.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Defines how documents that are missing a value should be treated,
+    /// by default they are ignored.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: num_traits::AsPrimitive<f64>,
+    {
+        self.histogram.missing = Some(missing.as_());
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their key rather than as an
+    /// array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.histogram.keyed = Some(keyed);
+        self
+    }
+
+    /// The order of the buckets, by default they are ordered by their key
+    /// ascending
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: Into<TermsOrderCollection>,
+    {
+        self.histogram.order = order.into();
+        self
+    }
+
+    /// Forces the histogram to start and end building buckets on the given
+    /// values, only taking effect when `min_doc_count` is `0`
+    pub fn extended_bounds(mut self, extended_bounds: HistogramBounds<f64>) -> Self {
+        self.histogram.extended_bounds = Some(extended_bounds);
+        self
+    }
+
+    /// Limits the range of buckets in the histogram, buckets outside of the
+    /// bounds are not returned even if documents fall into them
+    pub fn hard_bounds(mut self, hard_bounds: HistogramBounds<f64>) -> Self {
+        self.histogram.hard_bounds = Some(hard_bounds);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for HistogramAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 50.0),
+            json!({ "histogram": { "field": "price", "interval": 50.0 } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 50)
+                .offset(10)
+                .min_doc_count(0)
+                .missing(0)
+                .keyed(true)
+                .order(TermsOrder::count_descending())
+                .extended_bounds(HistogramBounds::new(0.0, 500.0))
+                .aggregate("total", Aggregation::sum("price")),
+            json!({
+                "histogram": {
+                    "field": "price",
+                    "interval": 50.0,
+                    "offset": 10.0,
+                    "min_doc_count": 0,
+                    "missing": 0.0,
+                    "keyed": true,
+                    "order": [{ "_count": "desc" }],
+                    "extended_bounds": { "min": 0.0, "max": 500.0 }
+                },
+                "aggs": {
+                    "total": { "sum": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_hard_bounds() {
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 10.0).hard_bounds(HistogramBounds::new(100.0, 200.0)),
+            json!({
+                "histogram": {
+                    "field": "price",
+                    "interval": 10.0,
+                    "hard_bounds": { "min": 100.0, "max": 200.0 }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::histogram("price", 10.0).hard_bounds(HistogramBounds::at_most(200)),
+            json!({
+                "histogram": {
+                    "field": "price",
+                    "interval": 10.0,
+                    "hard_bounds": { "max": 200.0 }
+                }
+            }),
+        );
+    }
+}
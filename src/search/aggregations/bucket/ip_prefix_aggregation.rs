@@ -0,0 +1,170 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A bucket aggregation that groups documents based on the network or sub-network of an IP
+/// address. An IP address consists of two groups of bits: the most significant bits which
+/// represent the network prefix, and the least significant bits which represent the host.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-ipprefix-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct IpPrefixAggregation {
+    ip_prefix: IpPrefixAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct IpPrefixAggregationInner {
+    field: String,
+
+    prefix_length: u8,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    is_ipv6: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    append_prefix_length: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`IpPrefixAggregation`]
+    ///
+    /// - `field` - IP field to group by
+    /// - `prefix_length` - length of the network prefix, at most `32` for
+    /// IPv4 addresses and `128` for IPv6 addresses
+    pub fn ip_prefix<T>(field: T, prefix_length: u8) -> IpPrefixAggregation
+    where
+        T: ToString,
+    {
+        IpPrefixAggregation {
+            ip_prefix: IpPrefixAggregationInner {
+                field: field.to_string(),
+                prefix_length,
+                is_ipv6: None,
+                append_prefix_length: None,
+                keyed: None,
+                min_doc_count: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl IpPrefixAggregation {
+    /// Defines whether the prefix applies to IPv6 addresses, defaults to
+    /// `false`
+    pub fn is_ipv6(mut self, is_ipv6: bool) -> Self {
+        self.ip_prefix.is_ipv6 = Some(is_ipv6);
+        self
+    }
+
+    /// Defines whether the prefix length is appended to IP address keys in
+    /// the response, defaults to `false`
+    pub fn append_prefix_length(mut self, append_prefix_length: bool) -> Self {
+        self.ip_prefix.append_prefix_length = Some(append_prefix_length);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their key rather than as an
+    /// array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.ip_prefix.keyed = Some(keyed);
+        self
+    }
+
+    /// Minimum number of documents for a bucket to be included in the
+    /// response, defaults to `1`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.ip_prefix.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl Validate for IpPrefixAggregation {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        let max = if self.ip_prefix.is_ipv6 == Some(true) {
+            128
+        } else {
+            32
+        };
+
+        if self.ip_prefix.prefix_length > max {
+            errors.push(
+                "ip_prefix.prefix_length",
+                format!("prefix length cannot be greater than {max}"),
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
+impl ShouldSkip for IpPrefixAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::ip_prefix("ipv4", 24),
+            json!({
+                "ip_prefix": {
+                    "field": "ipv4",
+                    "prefix_length": 24
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::ip_prefix("ipv6", 64)
+                .is_ipv6(true)
+                .append_prefix_length(true)
+                .keyed(true)
+                .min_doc_count(3)
+                .aggregate("hosts", Aggregation::terms("host")),
+            json!({
+                "ip_prefix": {
+                    "field": "ipv6",
+                    "prefix_length": 64,
+                    "is_ipv6": true,
+                    "append_prefix_length": true,
+                    "keyed": true,
+                    "min_doc_count": 3
+                },
+                "aggs": {
+                    "hosts": { "terms": { "field": "host" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Aggregation::ip_prefix("ipv4", 32).validate(), Ok(()));
+        assert_eq!(
+            Aggregation::ip_prefix("ipv6", 128).is_ipv6(true).validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Aggregation::ip_prefix("ipv4", 64).validate(),
+            Err(vec![ValidationError::new(
+                "ip_prefix.prefix_length",
+                "prefix length cannot be greater than 32"
+            )])
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Just like the dedicated [date](DateRangeAggregation) range aggregation, there is also a
+/// dedicated range aggregation for IP typed fields.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-iprange-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct IpRangeAggregation {
+    ip_range: IpRangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct IpRangeAggregationInner {
+    field: String,
+
+    ranges: Vec<IpRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`IpRangeAggregation`]
+    ///
+    /// - `field` - IP field to group by
+    pub fn ip_range<T>(field: T) -> IpRangeAggregation
+    where
+        T: ToString,
+    {
+        IpRangeAggregation {
+            ip_range: IpRangeAggregationInner {
+                field: field.to_string(),
+                ranges: Vec::new(),
+                keyed: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl IpRangeAggregation {
+    /// Adds a range, buckets are returned in the order the ranges were added
+    pub fn range(mut self, range: IpRange) -> Self {
+        self.ip_range.ranges.push(range);
+        self
+    }
+
+    /// Adds several ranges, buckets are returned in the order the ranges
+    /// were added
+    pub fn ranges<I>(mut self, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = IpRange>,
+    {
+        self.ip_range.ranges.extend(ranges);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their key rather than as an
+    /// array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.ip_range.keyed = Some(keyed);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl Validate for IpRangeAggregation {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (index, range) in self.ip_range.ranges.iter().enumerate() {
+            errors.nested(&format!("ip_range.ranges[{index}]"), range);
+        }
+
+        errors.into_result()
+    }
+}
+
+impl ShouldSkip for IpRangeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::ip_range("ip")
+                .range(IpRange::new().to("10.0.0.5"))
+                .range(IpRange::new().from("10.0.0.5")),
+            json!({
+                "ip_range": {
+                    "field": "ip",
+                    "ranges": [
+                        { "to": "10.0.0.5" },
+                        { "from": "10.0.0.5" }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::ip_range("ip")
+                .range(IpRange::new().to("10.0.0.5"))
+                .range(IpRange::mask("10.0.0.127/25")),
+            json!({
+                "ip_range": {
+                    "field": "ip",
+                    "ranges": [
+                        { "to": "10.0.0.5" },
+                        { "mask": "10.0.0.127/25" }
+                    ]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_keyed() {
+        assert_serialize_aggregation(
+            Aggregation::ip_range("ip")
+                .ranges([
+                    IpRange::mask("10.0.0.0/25").key("infrastructure"),
+                    IpRange::new()
+                        .from("10.0.0.128")
+                        .to("10.0.1.0")
+                        .key("users"),
+                ])
+                .keyed(true)
+                .aggregate("hosts", Aggregation::terms("host")),
+            json!({
+                "ip_range": {
+                    "field": "ip",
+                    "ranges": [
+                        { "key": "infrastructure", "mask": "10.0.0.0/25" },
+                        { "key": "users", "from": "10.0.0.128", "to": "10.0.1.0" }
+                    ],
+                    "keyed": true
+                },
+                "aggs": {
+                    "hosts": { "terms": { "field": "host" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Aggregation::ip_range("ip")
+                .range(IpRange::mask("10.0.0.0/25"))
+                .range(IpRange::mask("2001:db8::/32"))
+                .range(IpRange::new().from("10.0.0.128").to("::1"))
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Aggregation::ip_range("ip")
+                .range(IpRange::mask("10.0.0.0/33"))
+                .range(IpRange::mask("10.0.0.0"))
+                .range(IpRange::new().from("10.0.0").to("10.0.0.256"))
+                .validate(),
+            Err(vec![
+                ValidationError::new(
+                    "ip_range.ranges[0].mask",
+                    "`10.0.0.0/33` is not a valid CIDR mask"
+                ),
+                ValidationError::new(
+                    "ip_range.ranges[1].mask",
+                    "`10.0.0.0` is not a valid CIDR mask"
+                ),
+                ValidationError::new(
+                    "ip_range.ranges[2].from",
+                    "`10.0.0` is not a valid IP address"
+                ),
+                ValidationError::new(
+                    "ip_range.ranges[2].to",
+                    "`10.0.0.256` is not a valid IP address"
+                ),
+            ])
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A field data based single bucket aggregation, that creates a bucket of all documents in the
+/// current document set context that are missing a field value (effectively, missing a field or
+/// having the configured NULL value set). This aggregator will often be used in conjunction with
+/// other field data bucket aggregators (such as ranges) to return information for all the
+/// documents that could not be placed in any of the other buckets due to missing field data values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-missing-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MissingAggregation {
+    missing: MissingAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MissingAggregationInner {
+    field: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MissingAggregation`]
+    ///
+    /// - `field` - field to look for missing values in
+    pub fn missing<T>(field: T) -> MissingAggregation
+    where
+        T: ToString,
+    {
+        MissingAggregation::new(field)
+    }
+}
+
+impl MissingAggregation {
+    /// Creates an instance of [`MissingAggregation`]
+    ///
+    /// - `field` - field to look for missing values in
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            missing: MissingAggregationInner {
+                field: field.to_string(),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for MissingAggregation {
+    fn should_skip(&self) -> bool {
+        self.missing.field.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::missing("price"),
+            json!({ "missing": { "field": "price" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::missing("price").aggregate("brands", Aggregation::terms("brand")),
+            json!({
+                "missing": { "field": "price" },
+                "aggs": {
+                    "brands": { "terms": { "field": "brand" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn skips_aggregation_without_field() {
+        assert_serialize(
+            Search::new().aggregate("m", Aggregation::missing("")),
+            json!({}),
+        );
+
+        assert_serialize(
+            Aggregation::terms("brand").aggregate("m", Aggregation::missing("")),
+            json!({ "terms": { "field": "brand" } }),
+        );
+    }
+}
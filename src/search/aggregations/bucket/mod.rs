@@ -19,12 +19,62 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket.html>
 
+mod adjacency_matrix_aggregation;
+mod auto_date_histogram_aggregation;
+mod children_aggregation;
+mod composite_aggregation;
+mod date_histogram_aggregation;
+mod date_range_aggregation;
 mod diversified_sampler_aggregation;
 mod filter_aggregation;
+mod filters_aggregation;
+mod geo_distance_aggregation;
+mod geohash_grid_aggregation;
+mod geohex_grid_aggregation;
+mod geotile_grid_aggregation;
+mod global_aggregation;
+mod histogram_aggregation;
+mod ip_prefix_aggregation;
+mod ip_range_aggregation;
+mod missing_aggregation;
+mod multi_terms_aggregation;
+mod nested_aggregation;
+mod parent_aggregation;
+mod range_aggregation;
+mod rare_terms_aggregation;
+mod reverse_nested_aggregation;
 mod sampler_aggregation;
+mod significant_terms_aggregation;
+mod significant_text_aggregation;
 mod terms_aggregation;
+mod variable_width_histogram_aggregation;
 
+pub use self::adjacency_matrix_aggregation::*;
+pub use self::auto_date_histogram_aggregation::*;
+pub use self::children_aggregation::*;
+pub use self::composite_aggregation::*;
+pub use self::date_histogram_aggregation::*;
+pub use self::date_range_aggregation::*;
 pub use self::diversified_sampler_aggregation::*;
 pub use self::filter_aggregation::*;
+pub use self::filters_aggregation::*;
+pub use self::geo_distance_aggregation::*;
+pub use self::geohash_grid_aggregation::*;
+pub use self::geohex_grid_aggregation::*;
+pub use self::geotile_grid_aggregation::*;
+pub use self::global_aggregation::*;
+pub use self::histogram_aggregation::*;
+pub use self::ip_prefix_aggregation::*;
+pub use self::ip_range_aggregation::*;
+pub use self::missing_aggregation::*;
+pub use self::multi_terms_aggregation::*;
+pub use self::nested_aggregation::*;
+pub use self::parent_aggregation::*;
+pub use self::range_aggregation::*;
+pub use self::rare_terms_aggregation::*;
+pub use self::reverse_nested_aggregation::*;
 pub use self::sampler_aggregation::*;
+pub use self::significant_terms_aggregation::*;
+pub use self::significant_text_aggregation::*;
 pub use self::terms_aggregation::*;
+pub use self::variable_width_histogram_aggregation::*;
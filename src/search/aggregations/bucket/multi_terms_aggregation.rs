@@ -0,0 +1,186 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket value source based aggregation where buckets are dynamically built - one per
+/// unique set of values, i.e. a terms aggregation over a combination of fields.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-multi-terms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MultiTermsAggregation {
+    multi_terms: MultiTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MultiTermsAggregationInner {
+    terms: Vec<MultiTermLookup>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    show_term_doc_count_error: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: TermsOrderCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collect_mode: Option<CollectMode>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MultiTermsAggregation`]
+    ///
+    /// - `terms` - term sources to group by
+    pub fn multi_terms<T>(terms: T) -> MultiTermsAggregation
+    where
+        T: IntoIterator,
+        T::Item: Into<MultiTermLookup>,
+    {
+        MultiTermsAggregation {
+            multi_terms: MultiTermsAggregationInner {
+                terms: terms.into_iter().map(Into::into).collect(),
+                size: None,
+                shard_size: None,
+                show_term_doc_count_error: None,
+                order: Default::default(),
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                collect_mode: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl MultiTermsAggregation {
+    /// The number of term buckets should be returned out of the overall terms list.
+    ///
+    /// Defaults to `10`
+    pub fn size(mut self, size: u64) -> Self {
+        self.multi_terms.size = Some(size);
+        self
+    }
+
+    /// The number of term buckets each shard returns to the coordinating
+    /// node, higher values improve accuracy at the cost of performance.
+    ///
+    /// Defaults to `size * 1.5 + 10`
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.multi_terms.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Shows an error value for each term returned by the aggregation which represents the worst
+    /// case error in the document count
+    pub fn show_term_doc_count_error(mut self, show_term_doc_count_error: bool) -> Self {
+        self.multi_terms.show_term_doc_count_error = Some(show_term_doc_count_error);
+        self
+    }
+
+    /// The order of the buckets, by default the buckets are ordered by their
+    /// `doc_count` descending
+    pub fn order<T>(mut self, order: T) -> Self
+    where
+        T: Into<TermsOrderCollection>,
+    {
+        self.multi_terms.order = order.into();
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits
+    ///
+    /// Default value is `1`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.multi_terms.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Only returns terms from shards that match more than a configured
+    /// number of hits
+    ///
+    /// Default value is `0`
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.multi_terms.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    /// Whether buckets and sub-aggregations are computed in a single pass
+    /// or the top level buckets are pruned first
+    pub fn collect_mode(mut self, collect_mode: CollectMode) -> Self {
+        self.multi_terms.collect_mode = Some(collect_mode);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for MultiTermsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::multi_terms(["genre", "product"]),
+            json!({
+                "multi_terms": {
+                    "terms": [
+                        { "field": "genre" },
+                        { "field": "product" }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::multi_terms([
+                MultiTermLookup::new("genre"),
+                MultiTermLookup::new("product").missing("Product Z"),
+            ])
+            .size(5)
+            .shard_size(25)
+            .min_doc_count(2)
+            .shard_min_doc_count(1)
+            .show_term_doc_count_error(true)
+            .collect_mode(CollectMode::BreadthFirst)
+            .order(TermsOrder::descending("total_quantity"))
+            .aggregate("total_quantity", Aggregation::sum("quantity")),
+            json!({
+                "multi_terms": {
+                    "terms": [
+                        { "field": "genre" },
+                        { "field": "product", "missing": "Product Z" }
+                    ],
+                    "size": 5,
+                    "shard_size": 25,
+                    "min_doc_count": 2,
+                    "shard_min_doc_count": 1,
+                    "show_term_doc_count_error": true,
+                    "collect_mode": "breadth_first",
+                    "order": [
+                        { "total_quantity": "desc" }
+                    ]
+                },
+                "aggs": {
+                    "total_quantity": {
+                        "sum": { "field": "quantity" }
+                    }
+                }
+            }),
+        );
+    }
+}
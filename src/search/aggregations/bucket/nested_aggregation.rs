@@ -0,0 +1,65 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A special single bucket aggregation that enables aggregating nested documents.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-nested-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NestedAggregation {
+    nested: NestedAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct NestedAggregationInner {
+    path: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`NestedAggregation`]
+    ///
+    /// - `path` - path of the nested documents within the top level documents
+    pub fn nested<T>(path: T) -> NestedAggregation
+    where
+        T: ToString,
+    {
+        NestedAggregation {
+            nested: NestedAggregationInner {
+                path: path.to_string(),
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl NestedAggregation {
+    add_aggregate!();
+}
+
+impl ShouldSkip for NestedAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::nested("resellers"),
+            json!({ "nested": { "path": "resellers" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::nested("resellers")
+                .aggregate("min_price", Aggregation::min("resellers.price")),
+            json!({
+                "nested": { "path": "resellers" },
+                "aggs": {
+                    "min_price": { "min": { "field": "resellers.price" } }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A special single bucket aggregation that selects parent documents that have the specified type,
+/// as defined in a [`join` field](https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-parent-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ParentAggregation {
+    parent: ParentAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ParentAggregationInner {
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ParentAggregation`]
+    ///
+    /// - `ty` - the child type that should be selected, the buckets contain
+    /// the parents of the documents of this type
+    pub fn parent<T>(ty: T) -> ParentAggregation
+    where
+        T: ToString,
+    {
+        ParentAggregation {
+            parent: ParentAggregationInner { ty: ty.to_string() },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl ParentAggregation {
+    add_aggregate!();
+}
+
+impl ShouldSkip for ParentAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::parent("question"),
+            json!({ "parent": { "type": "question" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("owner.display_name.keyword").aggregate(
+                "to-questions",
+                Aggregation::parent("answer")
+                    .aggregate("top-names", Aggregation::terms("tags.keyword")),
+            ),
+            json!({
+                "terms": { "field": "owner.display_name.keyword" },
+                "aggs": {
+                    "to-questions": {
+                        "parent": { "type": "answer" },
+                        "aggs": {
+                            "top-names": {
+                                "terms": { "field": "tags.keyword" }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
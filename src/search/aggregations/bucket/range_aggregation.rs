@@ -0,0 +1,171 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket value source based aggregation that enables the user to define a set of
+/// ranges - each representing a bucket. During the aggregation process, the values extracted from
+/// each document will be checked against each bucket range and "bucket" the relevant/matching
+/// document.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-range-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RangeAggregation {
+    range: RangeAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct RangeAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    ranges: Vec<AggregationRange>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`RangeAggregation`]
+    ///
+    /// - `field` - numeric field to group by
+    pub fn range<T>(field: T) -> RangeAggregation
+    where
+        T: ToString,
+    {
+        RangeAggregation {
+            range: RangeAggregationInner {
+                field: Some(field.to_string()),
+                script: None,
+                ranges: Vec::new(),
+                keyed: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+
+    /// Creates an instance of [`RangeAggregation`]
+    ///
+    /// - `script` - script producing the values to group by
+    pub fn range_script(script: Script) -> RangeAggregation {
+        RangeAggregation {
+            range: RangeAggregationInner {
+                field: None,
+                script: Some(script),
+                ranges: Vec::new(),
+                keyed: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl RangeAggregation {
+    /// Adds a range, buckets are returned in the order the ranges were added
+    pub fn range(mut self, range: AggregationRange) -> Self {
+        self.range.ranges.push(range);
+        self
+    }
+
+    /// Adds several ranges, buckets are returned in the order the ranges
+    /// were added
+    pub fn ranges<I>(mut self, ranges: I) -> Self
+    where
+        I: IntoIterator<Item = AggregationRange>,
+    {
+        self.range.ranges.extend(ranges);
+        self
+    }
+
+    /// Returns the buckets as a hash keyed by their key rather than as an
+    /// array
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.range.keyed = Some(keyed);
+        self
+    }
+
+    /// Defines how documents that are missing a value should be treated,
+    /// by default they are ignored.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.range.missing = Some(missing.into());
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for RangeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::range("price").ranges([
+                AggregationRange::new().to(100.0),
+                AggregationRange::new().from(100.0).to(200.0),
+                AggregationRange::new().from(200.0),
+            ]),
+            json!({
+                "range": {
+                    "field": "price",
+                    "ranges": [
+                        { "to": 100.0 },
+                        { "from": 100.0, "to": 200.0 },
+                        { "from": 200.0 }
+                    ]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::range("price")
+                .range(AggregationRange::new().key("cheap").to(100))
+                .range(AggregationRange::new().key("mid").from(100).to(200))
+                .range(AggregationRange::new().key("expensive").from(200))
+                .keyed(true)
+                .missing(0)
+                .aggregate("price_stats", Aggregation::avg("price")),
+            json!({
+                "range": {
+                    "field": "price",
+                    "ranges": [
+                        { "key": "cheap", "to": 100 },
+                        { "key": "mid", "from": 100, "to": 200 },
+                        { "key": "expensive", "from": 200 }
+                    ],
+                    "keyed": true,
+                    "missing": 0
+                },
+                "aggs": {
+                    "price_stats": { "avg": { "field": "price" } }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::range_script(Script::source("doc['price'].value * 1.2"))
+                .range(AggregationRange::new().to(100)),
+            json!({
+                "range": {
+                    "script": { "source": "doc['price'].value * 1.2" },
+                    "ranges": [{ "to": 100 }]
+                }
+            }),
+        );
+    }
+}
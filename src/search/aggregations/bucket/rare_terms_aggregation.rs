@@ -0,0 +1,165 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket value source based aggregation which finds "rare" terms — terms that are at the
+/// long-tail of the distribution and are not frequent.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-rare-terms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RareTermsAggregation {
+    rare_terms: RareTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct RareTermsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`RareTermsAggregation`]
+    ///
+    /// - `field` - field to group by
+    pub fn rare_terms<T>(field: T) -> RareTermsAggregation
+    where
+        T: ToString,
+    {
+        RareTermsAggregation {
+            rare_terms: RareTermsAggregationInner {
+                field: field.to_string(),
+                max_doc_count: None,
+                precision: None,
+                include: None,
+                exclude: None,
+                missing: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl RareTermsAggregation {
+    /// The maximum number of documents a term should appear in.
+    ///
+    /// Defaults to `1`, the maximum allowed value is `100`
+    pub fn max_doc_count(mut self, max_doc_count: u64) -> Self {
+        self.rare_terms.max_doc_count = Some(max_doc_count);
+        self
+    }
+
+    /// The precision of the internal CuckooFilters. Smaller precision leads to better
+    /// approximation, but higher memory usage.
+    ///
+    /// Defaults to `0.001`, cannot be smaller than `0.00001`
+    pub fn precision(mut self, precision: f64) -> Self {
+        self.rare_terms.precision = Some(precision);
+        self
+    }
+
+    /// Only creates buckets for values matching a regular expression or one
+    /// of the exact values
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.rare_terms.include = Some(include.into());
+        self
+    }
+
+    /// Doesn't create buckets for values matching a regular expression or
+    /// one of the exact values
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.rare_terms.exclude = Some(exclude.into());
+        self
+    }
+
+    /// The value to use for documents missing the field, such documents
+    /// are ignored by default
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.rare_terms.missing = Term::new(missing);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for RareTermsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre"),
+            json!({ "rare_terms": { "field": "genre" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre").max_doc_count(2),
+            json!({ "rare_terms": { "field": "genre", "max_doc_count": 2 } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre")
+                .max_doc_count(2)
+                .precision(0.001)
+                .include("swi*")
+                .exclude("electro*")
+                .missing("n/a")
+                .aggregate("avg_plays", Aggregation::avg("plays")),
+            json!({
+                "rare_terms": {
+                    "field": "genre",
+                    "max_doc_count": 2,
+                    "precision": 0.001,
+                    "include": "swi*",
+                    "exclude": "electro*",
+                    "missing": "n/a"
+                },
+                "aggs": {
+                    "avg_plays": {
+                        "avg": { "field": "plays" }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::rare_terms("genre")
+                .include(["swing", "rock"])
+                .exclude(["jazz"]),
+            json!({
+                "rare_terms": {
+                    "field": "genre",
+                    "include": ["swing", "rock"],
+                    "exclude": ["jazz"]
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A special single bucket aggregation that enables aggregating on parent docs from nested
+/// documents. Effectively this aggregation can break out of the nested block structure and link
+/// to other nested structures or the root document, which allows nesting other aggregations that
+/// aren't part of the nested object in a nested aggregation.
+///
+/// The `reverse_nested` aggregation must be defined inside a
+/// [`nested`](crate::NestedAggregation) aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-reverse-nested-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReverseNestedAggregation {
+    reverse_nested: ReverseNestedAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ReverseNestedAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    path: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ReverseNestedAggregation`], which joins
+    /// back to the root document unless a [path](ReverseNestedAggregation::path)
+    /// is given
+    pub fn reverse_nested() -> ReverseNestedAggregation {
+        ReverseNestedAggregation {
+            reverse_nested: ReverseNestedAggregationInner { path: None },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl ReverseNestedAggregation {
+    /// Defines to what nested object field should be joined back
+    pub fn path<T>(mut self, path: T) -> Self
+    where
+        T: ToString,
+    {
+        self.reverse_nested.path = Some(path.to_string());
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for ReverseNestedAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::reverse_nested(),
+            json!({ "reverse_nested": {} }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::reverse_nested().path("resellers"),
+            json!({ "reverse_nested": { "path": "resellers" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::nested("resellers").aggregate(
+                "top_resellers",
+                Aggregation::terms("resellers.name").aggregate(
+                    "resellers_to_product",
+                    Aggregation::reverse_nested()
+                        .aggregate("top_categories", Aggregation::terms("category")),
+                ),
+            ),
+            json!({
+                "nested": { "path": "resellers" },
+                "aggs": {
+                    "top_resellers": {
+                        "terms": { "field": "resellers.name" },
+                        "aggs": {
+                            "resellers_to_product": {
+                                "reverse_nested": {},
+                                "aggs": {
+                                    "top_categories": {
+                                        "terms": { "field": "category" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
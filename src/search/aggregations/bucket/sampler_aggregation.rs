@@ -4,7 +4,7 @@ use crate::util::*;
 /// A filtering aggregation used to limit any sub aggregations' processing to a sample of the top-scoring documents.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-sampler-aggregation.html>
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
 pub struct SamplerAggregation {
     sampler: SamplerAggregationInner,
 
@@ -12,7 +12,7 @@ pub struct SamplerAggregation {
     aggs: Aggregations,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
 struct SamplerAggregationInner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     shard_size: Option<u64>,
@@ -21,14 +21,16 @@ struct SamplerAggregationInner {
 impl Aggregation {
     /// Creates an instance of [`SamplerAggregation`]
     pub fn sampler() -> SamplerAggregation {
-        SamplerAggregation {
-            sampler: SamplerAggregationInner { shard_size: None },
-            aggs: Aggregations::new(),
-        }
+        SamplerAggregation::new()
     }
 }
 
 impl SamplerAggregation {
+    /// Creates an instance of [`SamplerAggregation`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// The shard_size parameter limits how many top-scoring documents are
     /// collected in the sample processed on each shard. The default value is 100.
     pub fn shard_size(mut self, shard_size: u64) -> Self {
@@ -39,6 +41,8 @@ impl SamplerAggregation {
     add_aggregate!();
 }
 
+impl ShouldSkip for SamplerAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,6 +51,8 @@ mod tests {
     fn serialization() {
         assert_serialize_aggregation(Aggregation::sampler(), json!({ "sampler": {} }));
 
+        assert_serialize_aggregation(SamplerAggregation::new(), json!({ "sampler": {} }));
+
         assert_serialize_aggregation(
             Aggregation::sampler().shard_size(100),
             json!({ "sampler": { "shard_size": 100 } }),
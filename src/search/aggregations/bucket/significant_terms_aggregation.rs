@@ -0,0 +1,208 @@
+use crate::search::*;
+use crate::util::*;
+
+/// An aggregation that returns interesting or unusual occurrences of terms in a set, i.e. terms
+/// that have undergone a significant change in popularity measured between a foreground and
+/// background set.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significantterms-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SignificantTermsAggregation {
+    significant_terms: SignificantTermsAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SignificantTermsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    background_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_hint: Option<TermsExecutionHint>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    significance_heuristic: Option<SignificanceHeuristic>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SignificantTermsAggregation`]
+    ///
+    /// - `field` - field to find significant terms in
+    pub fn significant_terms<T>(field: T) -> SignificantTermsAggregation
+    where
+        T: ToString,
+    {
+        SignificantTermsAggregation {
+            significant_terms: SignificantTermsAggregationInner {
+                field: field.to_string(),
+                size: None,
+                shard_size: None,
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                background_filter: None,
+                execution_hint: None,
+                include: None,
+                exclude: None,
+                significance_heuristic: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl SignificantTermsAggregation {
+    /// The number of term buckets should be returned out of the overall terms list.
+    ///
+    /// Defaults to `10`
+    pub fn size(mut self, size: u64) -> Self {
+        self.significant_terms.size = Some(size);
+        self
+    }
+
+    /// The number of candidate terms produced by each shard, higher values
+    /// improve accuracy at the cost of performance
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.significant_terms.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits
+    ///
+    /// Default value is `3`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.significant_terms.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Only returns terms from shards that match more than a configured
+    /// number of hits
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.significant_terms.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    /// Narrows the background set, which defaults to all the documents in the
+    /// index, to the documents matching a query
+    ///
+    /// Skippable queries, e.g. empty `terms` query, leave the filter unset.
+    pub fn background_filter<Q>(mut self, background_filter: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.significant_terms.background_filter =
+            Some(background_filter.into()).filter(ShouldSkip::should_keep);
+        self
+    }
+
+    /// Mechanism by which the aggregation is executed, Elasticsearch ignores
+    /// the hint when it isn't applicable
+    pub fn execution_hint(mut self, execution_hint: TermsExecutionHint) -> Self {
+        self.significant_terms.execution_hint = Some(execution_hint);
+        self
+    }
+
+    /// Only creates buckets for values matching a regular expression, one of
+    /// the exact values or a partition of the terms
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.significant_terms.include = Some(include.into());
+        self
+    }
+
+    /// Doesn't create buckets for values matching a regular expression or
+    /// one of the exact values
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.significant_terms.exclude = Some(exclude.into());
+        self
+    }
+
+    /// Heuristic used to score terms, defaults to
+    /// [JLH](SignificanceHeuristic::Jlh)
+    pub fn significance_heuristic(mut self, significance_heuristic: SignificanceHeuristic) -> Self {
+        self.significant_terms.significance_heuristic = Some(significance_heuristic);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for SignificantTermsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::significant_terms("crime_type"),
+            json!({ "significant_terms": { "field": "crime_type" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::significant_terms("tag")
+                .size(5)
+                .shard_size(50)
+                .min_doc_count(10)
+                .shard_min_doc_count(2)
+                .background_filter(Query::term("text", "spain"))
+                .execution_hint(TermsExecutionHint::Map)
+                .include(".*sport.*")
+                .exclude("water_.*")
+                .significance_heuristic(SignificanceHeuristic::ChiSquare {
+                    include_negatives: Some(true),
+                    background_is_superset: Some(false),
+                })
+                .aggregate("top_hits", Aggregation::top_hits()),
+            json!({
+                "significant_terms": {
+                    "field": "tag",
+                    "size": 5,
+                    "shard_size": 50,
+                    "min_doc_count": 10,
+                    "shard_min_doc_count": 2,
+                    "background_filter": {
+                        "term": { "text": { "value": "spain" } }
+                    },
+                    "execution_hint": "map",
+                    "include": ".*sport.*",
+                    "exclude": "water_.*",
+                    "chi_square": {
+                        "include_negatives": true,
+                        "background_is_superset": false
+                    }
+                },
+                "aggs": {
+                    "top_hits": { "top_hits": {} }
+                }
+            }),
+        );
+    }
+}
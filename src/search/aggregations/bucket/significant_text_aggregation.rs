@@ -0,0 +1,237 @@
+use crate::search::*;
+use crate::util::*;
+
+/// An aggregation that returns interesting or unusual occurrences of free-text terms in a set.
+/// Unlike [`significant_terms`](SignificantTermsAggregation) it works on `text` fields by
+/// re-analyzing the source of the top matching documents on the fly.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significanttext-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SignificantTextAggregation {
+    significant_text: SignificantTextAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SignificantTextAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    background_filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter_duplicate_text: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    source_fields: Vec<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+
+    #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
+    significance_heuristic: Option<SignificanceHeuristic>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SignificantTextAggregation`]
+    ///
+    /// - `field` - `text` field to find significant terms in
+    pub fn significant_text<T>(field: T) -> SignificantTextAggregation
+    where
+        T: ToString,
+    {
+        SignificantTextAggregation {
+            significant_text: SignificantTextAggregationInner {
+                field: field.to_string(),
+                size: None,
+                shard_size: None,
+                min_doc_count: None,
+                shard_min_doc_count: None,
+                background_filter: None,
+                filter_duplicate_text: None,
+                source_fields: Vec::new(),
+                include: None,
+                exclude: None,
+                significance_heuristic: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl SignificantTextAggregation {
+    /// The number of term buckets should be returned out of the overall terms list.
+    ///
+    /// Defaults to `10`
+    pub fn size(mut self, size: u64) -> Self {
+        self.significant_text.size = Some(size);
+        self
+    }
+
+    /// The number of candidate terms produced by each shard, higher values
+    /// improve accuracy at the cost of performance
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.significant_text.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Only returns terms that match more than a configured number of hits
+    ///
+    /// Default value is `3`
+    pub fn min_doc_count(mut self, min_doc_count: u64) -> Self {
+        self.significant_text.min_doc_count = Some(min_doc_count);
+        self
+    }
+
+    /// Only returns terms from shards that match more than a configured
+    /// number of hits
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.significant_text.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    /// Narrows the background set, which defaults to all the documents in the
+    /// index, to the documents matching a query
+    ///
+    /// Skippable queries, e.g. empty `terms` query, leave the filter unset.
+    pub fn background_filter<Q>(mut self, background_filter: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.significant_text.background_filter =
+            Some(background_filter.into()).filter(ShouldSkip::should_keep);
+        self
+    }
+
+    /// Filters out duplicate sections of text, e.g. boilerplate or copies of
+    /// the same content, which may otherwise skew the statistics
+    pub fn filter_duplicate_text(mut self, filter_duplicate_text: bool) -> Self {
+        self.significant_text.filter_duplicate_text = Some(filter_duplicate_text);
+        self
+    }
+
+    /// Analyzes the values of the given fields from `_source` instead of
+    /// the aggregated field, e.g. when the field name differs from the
+    /// source key because of `copy_to`
+    pub fn source_fields<I>(mut self, source_fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.significant_text.source_fields =
+            source_fields.into_iter().map(|x| x.to_string()).collect();
+        self
+    }
+
+    /// Only creates buckets for values matching a regular expression, one of
+    /// the exact values or a partition of the terms
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.significant_text.include = Some(include.into());
+        self
+    }
+
+    /// Doesn't create buckets for values matching a regular expression or
+    /// one of the exact values
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.significant_text.exclude = Some(exclude.into());
+        self
+    }
+
+    /// Heuristic used to score terms, defaults to
+    /// [JLH](SignificanceHeuristic::Jlh)
+    pub fn significance_heuristic(mut self, significance_heuristic: SignificanceHeuristic) -> Self {
+        self.significant_text.significance_heuristic = Some(significance_heuristic);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for SignificantTextAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::significant_text("content"),
+            json!({ "significant_text": { "field": "content" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::significant_text("content")
+                .size(3)
+                .shard_size(30)
+                .min_doc_count(5)
+                .shard_min_doc_count(1)
+                .background_filter(Query::term("content", "elasticsearch"))
+                .filter_duplicate_text(true)
+                .source_fields(["content", "title"])
+                .include(["kibana", "logstash"])
+                .exclude(["beats"])
+                .significance_heuristic(SignificanceHeuristic::script(Script::source(
+                    "params._subset_freq",
+                )))
+                .aggregate(
+                    "keywords",
+                    Aggregation::significant_terms("tags").significance_heuristic(
+                        SignificanceHeuristic::Gnd {
+                            background_is_superset: Some(false),
+                        },
+                    ),
+                ),
+            json!({
+                "significant_text": {
+                    "field": "content",
+                    "size": 3,
+                    "shard_size": 30,
+                    "min_doc_count": 5,
+                    "shard_min_doc_count": 1,
+                    "background_filter": {
+                        "term": { "content": { "value": "elasticsearch" } }
+                    },
+                    "filter_duplicate_text": true,
+                    "source_fields": ["content", "title"],
+                    "include": ["kibana", "logstash"],
+                    "exclude": ["beats"],
+                    "script_heuristic": {
+                        "script": { "source": "params._subset_freq" }
+                    }
+                },
+                "aggs": {
+                    "keywords": {
+                        "significant_terms": {
+                            "field": "tags",
+                            "gnd": { "background_is_superset": false }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -31,6 +31,24 @@ struct TermsAggregationInner {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_min_doc_count: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    include: Option<TermsInclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    exclude: Option<TermsExclude>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_hint: Option<TermsExecutionHint>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collect_mode: Option<CollectMode>,
 }
 
 impl Aggregation {
@@ -49,6 +67,12 @@ impl Aggregation {
                 order: Default::default(),
                 min_doc_count: None,
                 missing: None,
+                shard_size: None,
+                shard_min_doc_count: None,
+                include: None,
+                exclude: None,
+                execution_hint: None,
+                collect_mode: None,
             },
             aggs: Aggregations::new(),
         }
@@ -115,9 +139,65 @@ impl TermsAggregation {
         self
     }
 
+    /// The number of term buckets each shard returns to the coordinating
+    /// node, higher values improve accuracy at the cost of performance.
+    ///
+    /// Defaults to `size * 1.5 + 10`
+    pub fn shard_size(mut self, shard_size: u64) -> Self {
+        self.terms.shard_size = Some(shard_size);
+        self
+    }
+
+    /// Only returns terms from shards that match more than a configured
+    /// number of hits, which filters out rare terms before they are sent to
+    /// the coordinating node.
+    ///
+    /// Default value is `0`
+    pub fn shard_min_doc_count(mut self, shard_min_doc_count: u64) -> Self {
+        self.terms.shard_min_doc_count = Some(shard_min_doc_count);
+        self
+    }
+
+    /// Only creates buckets for values matching a regular expression, one of
+    /// the exact values or a partition of the terms
+    pub fn include<T>(mut self, include: T) -> Self
+    where
+        T: Into<TermsInclude>,
+    {
+        self.terms.include = Some(include.into());
+        self
+    }
+
+    /// Doesn't create buckets for values matching a regular expression or
+    /// one of the exact values, takes precedence over
+    /// [include](Self::include)
+    pub fn exclude<T>(mut self, exclude: T) -> Self
+    where
+        T: Into<TermsExclude>,
+    {
+        self.terms.exclude = Some(exclude.into());
+        self
+    }
+
+    /// Mechanism by which the aggregation is executed, Elasticsearch ignores
+    /// the hint when it isn't applicable
+    pub fn execution_hint(mut self, execution_hint: TermsExecutionHint) -> Self {
+        self.terms.execution_hint = Some(execution_hint);
+        self
+    }
+
+    /// Whether buckets and sub-aggregations are computed in a single pass
+    /// or the top level buckets are pruned first
+    pub fn collect_mode(mut self, collect_mode: CollectMode) -> Self {
+        self.terms.collect_mode = Some(collect_mode);
+        self
+    }
+
     add_aggregate!();
 }
 
+impl ShouldSkip for TermsAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,5 +259,56 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("genre")
+                .order([
+                    TermsOrder::descending("rock>playback_stats.avg"),
+                    TermsOrder::count_descending(),
+                ])
+                .include(["rock", "jazz"])
+                .exclude("water_.*")
+                .shard_size(20)
+                .shard_min_doc_count(2)
+                .execution_hint(TermsExecutionHint::Map)
+                .collect_mode(CollectMode::BreadthFirst)
+                .aggregate("playback_stats", Aggregation::avg("play_count")),
+            json!({
+                "terms": {
+                    "field": "genre",
+                    "order": [
+                        { "rock>playback_stats.avg": "desc" },
+                        { "_count": "desc" }
+                    ],
+                    "include": ["rock", "jazz"],
+                    "exclude": "water_.*",
+                    "shard_size": 20,
+                    "shard_min_doc_count": 2,
+                    "execution_hint": "map",
+                    "collect_mode": "breadth_first"
+                },
+                "aggs": {
+                    "playback_stats": {
+                        "avg": { "field": "play_count" }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("account_id")
+                .include(TermsInclude::partition(0, 20))
+                .size(10000),
+            json!({
+                "terms": {
+                    "field": "account_id",
+                    "include": {
+                        "partition": 0,
+                        "num_partitions": 20
+                    },
+                    "size": 10000
+                }
+            }),
+        );
     }
 }
@@ -0,0 +1,140 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A multi-bucket aggregation similar to the [histogram](HistogramAggregation), however the width
+/// of each bucket is not specified. Rather, a target number of buckets is provided and bucket
+/// intervals are dynamically determined based on the document distribution.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-variablewidthhistogram-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct VariableWidthHistogramAggregation {
+    variable_width_histogram: VariableWidthHistogramAggregationInner,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    aggs: Aggregations,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct VariableWidthHistogramAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    buckets: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    initial_buffer: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shard_size: Option<u32>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`VariableWidthHistogramAggregation`]
+    ///
+    /// - `field` - numeric field to group by
+    pub fn variable_width_histogram<T>(field: T) -> VariableWidthHistogramAggregation
+    where
+        T: ToString,
+    {
+        VariableWidthHistogramAggregation {
+            variable_width_histogram: VariableWidthHistogramAggregationInner {
+                field: field.to_string(),
+                buckets: None,
+                initial_buffer: None,
+                shard_size: None,
+            },
+            aggs: Aggregations::new(),
+        }
+    }
+}
+
+impl VariableWidthHistogramAggregation {
+    /// The target number of buckets.
+    ///
+    /// Defaults to `10`
+    pub fn buckets(mut self, buckets: u32) -> Self {
+        self.variable_width_histogram.buckets = Some(buckets);
+        self
+    }
+
+    /// The number of individual documents that will be stored in memory on a
+    /// shard before the initial bucketing algorithm is run.
+    ///
+    /// Defaults to `min(10 * shard_size, 50000)`
+    pub fn initial_buffer(mut self, initial_buffer: u32) -> Self {
+        self.variable_width_histogram.initial_buffer = Some(initial_buffer);
+        self
+    }
+
+    /// The number of buckets that the coordinating node will request from
+    /// each shard.
+    ///
+    /// Defaults to `buckets * 50`
+    pub fn shard_size(mut self, shard_size: u32) -> Self {
+        self.variable_width_histogram.shard_size = Some(shard_size);
+        self
+    }
+
+    add_aggregate!();
+}
+
+impl ShouldSkip for VariableWidthHistogramAggregation {
+    fn should_skip(&self) -> bool {
+        self.variable_width_histogram.field.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::variable_width_histogram("price"),
+            json!({ "variable_width_histogram": { "field": "price" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::variable_width_histogram("price").buckets(2),
+            json!({
+                "variable_width_histogram": {
+                    "field": "price",
+                    "buckets": 2
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::variable_width_histogram("price")
+                .buckets(5)
+                .initial_buffer(500)
+                .shard_size(250)
+                .aggregate("avg_price", Aggregation::avg("price")),
+            json!({
+                "variable_width_histogram": {
+                    "field": "price",
+                    "buckets": 5,
+                    "initial_buffer": 500,
+                    "shard_size": 250
+                },
+                "aggs": {
+                    "avg_price": { "avg": { "field": "price" } }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn skips_aggregation_without_field() {
+        assert_serialize(
+            Search::new().aggregate("m", Aggregation::variable_width_histogram("")),
+            json!({}),
+        );
+
+        assert_serialize(
+            Aggregation::terms("brand").aggregate("m", Aggregation::variable_width_histogram("")),
+            json!({ "terms": { "field": "brand" } }),
+        );
+    }
+}
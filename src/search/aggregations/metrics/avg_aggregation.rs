@@ -3,7 +3,7 @@ use crate::util::*;
 
 /// A `single-value` metrics aggregation that computes the average of numeric values that are extracted
 /// from the aggregated documents. These values can be extracted either from specific numeric fields
-/// in the documents.
+/// in the documents, or be generated by a provided script.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-avg-aggregation.html>
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -13,7 +13,11 @@ pub struct AvgAggregation {
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 struct AvgAggregationInner {
-    field: String,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Number>,
@@ -29,7 +33,21 @@ impl Aggregation {
     {
         AvgAggregation {
             avg: AvgAggregationInner {
-                field: field.to_string(),
+                field: field.to_string().into(),
+                script: None,
+                missing: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`AvgAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn avg_script(script: Script) -> AvgAggregation {
+        AvgAggregation {
+            avg: AvgAggregationInner {
+                script: script.into(),
+                field: None,
                 missing: None,
             },
         }
@@ -48,6 +66,8 @@ impl AvgAggregation {
     }
 }
 
+impl ShouldSkip for AvgAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,5 +88,19 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::avg_script(
+                Script::source("doc.grade.value * params.correction").param("correction", 1.2),
+            ),
+            json!({
+                "avg": {
+                    "script": {
+                        "source": "doc.grade.value * params.correction",
+                        "params": { "correction": 1.2 }
+                    }
+                }
+            }),
+        );
     }
 }
@@ -1,5 +1,5 @@
 use crate::util::*;
-use crate::{Aggregation, Number};
+use crate::{Aggregation, Number, Script, TDigestExecutionHint};
 
 /// A `boxplot` metrics aggregation that computes boxplot of numeric values extracted from the
 /// aggregated documents. These values can be generated from specific numeric or [histogram fields](https://www.elastic.co/guide/en/elasticsearch/reference/current/histogram.html)
@@ -22,10 +22,15 @@ pub struct BoxplotAggregation {
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 struct BoxplotAggregationInner {
-    field: String,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     compression: Option<Number>,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_hint: Option<TDigestExecutionHint>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Number>,
 }
 
@@ -39,8 +44,25 @@ impl Aggregation {
     {
         BoxplotAggregation {
             boxplot: BoxplotAggregationInner {
-                field: field.to_string(),
+                field: field.to_string().into(),
+                script: None,
+                compression: None,
+                execution_hint: None,
+                missing: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`BoxplotAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn boxplot_script(script: Script) -> BoxplotAggregation {
+        BoxplotAggregation {
+            boxplot: BoxplotAggregationInner {
+                script: script.into(),
+                field: None,
                 compression: None,
+                execution_hint: None,
                 missing: None,
             },
         }
@@ -70,6 +92,12 @@ impl BoxplotAggregation {
         self
     }
 
+    /// The TDigest implementation, trading speed for accuracy
+    pub fn execution_hint(mut self, execution_hint: TDigestExecutionHint) -> Self {
+        self.boxplot.execution_hint = Some(execution_hint);
+        self
+    }
+
     /// The `missing` parameter defines how documents that are missing a value should be treated.
     /// By default they will be ignored but it is also possible to treat them as if they had a value.
     pub fn missing<T>(mut self, missing: T) -> Self
@@ -81,6 +109,8 @@ impl BoxplotAggregation {
     }
 }
 
+impl ShouldSkip for BoxplotAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,5 +134,20 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::boxplot_script(Script::source("doc['load_time'].value / 1000.0"))
+                .compression(200)
+                .execution_hint(TDigestExecutionHint::HighAccuracy),
+            json!({
+                "boxplot": {
+                    "script": {
+                        "source": "doc['load_time'].value / 1000.0"
+                    },
+                    "compression": 200,
+                    "execution_hint": "high_accuracy"
+                }
+            }),
+        );
     }
 }
@@ -11,13 +11,20 @@ pub struct CardinalityAggregation {
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 struct CardinalityAggregationInner {
-    field: String,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     precision_threshold: Option<u16>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    execution_hint: Option<CardinalityExecutionHint>,
 }
 
 impl Aggregation {
@@ -30,9 +37,26 @@ impl Aggregation {
     {
         CardinalityAggregation {
             cardinality: CardinalityAggregationInner {
-                field: field.to_string(),
+                field: field.to_string().into(),
+                script: None,
+                precision_threshold: None,
+                missing: None,
+                execution_hint: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`CardinalityAggregation`]
+    ///
+    /// - `script` - script generating the values to count
+    pub fn cardinality_script(script: Script) -> CardinalityAggregation {
+        CardinalityAggregation {
+            cardinality: CardinalityAggregationInner {
+                script: script.into(),
+                field: None,
                 precision_threshold: None,
                 missing: None,
+                execution_hint: None,
             },
         }
     }
@@ -57,8 +81,17 @@ impl CardinalityAggregation {
         self.cardinality.missing = Some(missing.to_string());
         self
     }
+
+    /// Mechanism by which the cardinality is calculated, Elasticsearch picks
+    /// the fastest implementation by default
+    pub fn execution_hint(mut self, execution_hint: CardinalityExecutionHint) -> Self {
+        self.cardinality.execution_hint = Some(execution_hint);
+        self
+    }
 }
 
+impl ShouldSkip for CardinalityAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,5 +115,36 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::cardinality_script(
+                Script::source("doc['type'].value + ' ' + doc['promoted'].value").lang("painless"),
+            )
+            .execution_hint(CardinalityExecutionHint::Direct),
+            json!({
+                "cardinality": {
+                    "script": {
+                        "source": "doc['type'].value + ' ' + doc['promoted'].value",
+                        "lang": "painless"
+                    },
+                    "execution_hint": "direct"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("category").aggregate(
+                "type_count",
+                Aggregation::cardinality("type").precision_threshold(100),
+            ),
+            json!({
+                "terms": { "field": "category" },
+                "aggs": {
+                    "type_count": {
+                        "cardinality": { "field": "type", "precision_threshold": 100 }
+                    }
+                }
+            }),
+        );
     }
 }
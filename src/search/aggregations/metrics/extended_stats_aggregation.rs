@@ -0,0 +1,118 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that computes stats over numeric values extracted from the
+/// aggregated documents.
+///
+/// The `extended_stats` aggregations is an extended version of the
+/// [`stats`](crate::StatsAggregation) aggregation, where additional metrics are added such as
+/// `sum_of_squares`, `variance`, `std_deviation` and `std_deviation_bounds`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-extendedstats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ExtendedStatsAggregation {
+    extended_stats: ExtendedStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ExtendedStatsAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sigma: Option<f64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ExtendedStatsAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn extended_stats<T>(field: T) -> ExtendedStatsAggregation
+    where
+        T: ToString,
+    {
+        ExtendedStatsAggregation {
+            extended_stats: ExtendedStatsAggregationInner {
+                field: field.to_string().into(),
+                script: None,
+                missing: None,
+                sigma: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`ExtendedStatsAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn extended_stats_script(script: Script) -> ExtendedStatsAggregation {
+        ExtendedStatsAggregation {
+            extended_stats: ExtendedStatsAggregationInner {
+                script: script.into(),
+                field: None,
+                missing: None,
+                sigma: None,
+            },
+        }
+    }
+}
+
+impl ExtendedStatsAggregation {
+    /// The `missing` parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.extended_stats.missing = Some(missing.into());
+        self
+    }
+
+    /// Number of standard deviations above and below the mean used to
+    /// calculate `std_deviation_bounds`, defaults to `2`
+    pub fn sigma<T>(mut self, sigma: T) -> Self
+    where
+        T: Into<f64>,
+    {
+        self.extended_stats.sigma = Some(sigma.into());
+        self
+    }
+}
+
+impl ShouldSkip for ExtendedStatsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::extended_stats("grade"),
+            json!({ "extended_stats": { "field": "grade" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::extended_stats("grade").sigma(3.0),
+            json!({ "extended_stats": { "field": "grade", "sigma": 3.0 } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::extended_stats_script(Script::source("doc['grade'].value"))
+                .missing(0)
+                .sigma(1.5),
+            json!({
+                "extended_stats": {
+                    "script": { "source": "doc['grade'].value" },
+                    "missing": 0,
+                    "sigma": 1.5
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A metric aggregation that computes the geographic bounding box containing all values for a
+/// [Geopoint](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html) or
+/// [Geoshape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html) field.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-geobounds-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoBoundsAggregation {
+    geo_bounds: GeoBoundsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoBoundsAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    wrap_longitude: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoBoundsAggregation`]
+    ///
+    /// - `field` - field to compute the bounding box of
+    pub fn geo_bounds<T>(field: T) -> GeoBoundsAggregation
+    where
+        T: ToString,
+    {
+        GeoBoundsAggregation {
+            geo_bounds: GeoBoundsAggregationInner {
+                field: field.to_string(),
+                wrap_longitude: None,
+            },
+        }
+    }
+}
+
+impl GeoBoundsAggregation {
+    /// An optional parameter which specifies whether the bounding box should be allowed to
+    /// overlap the international date line. The default value is `true`.
+    pub fn wrap_longitude(mut self, wrap_longitude: bool) -> Self {
+        self.geo_bounds.wrap_longitude = Some(wrap_longitude);
+        self
+    }
+}
+
+impl ShouldSkip for GeoBoundsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geo_bounds("location"),
+            json!({ "geo_bounds": { "field": "location" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::geo_bounds("pin.location").wrap_longitude(true),
+            json!({
+                "geo_bounds": {
+                    "field": "pin.location",
+                    "wrap_longitude": true
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A metric aggregation that computes the weighted
+/// [centroid](https://en.wikipedia.org/wiki/Centroid) from all coordinate values for geo fields.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-geocentroid-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GeoCentroidAggregation {
+    geo_centroid: GeoCentroidAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct GeoCentroidAggregationInner {
+    field: String,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`GeoCentroidAggregation`]
+    ///
+    /// - `field` - field to compute the centroid of
+    pub fn geo_centroid<T>(field: T) -> GeoCentroidAggregation
+    where
+        T: ToString,
+    {
+        GeoCentroidAggregation {
+            geo_centroid: GeoCentroidAggregationInner {
+                field: field.to_string(),
+            },
+        }
+    }
+}
+
+impl ShouldSkip for GeoCentroidAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::geo_centroid("centroid.location"),
+            json!({ "geo_centroid": { "field": "centroid.location" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::terms("city.keyword")
+                .aggregate("centroid", Aggregation::geo_centroid("location")),
+            json!({
+                "terms": { "field": "city.keyword" },
+                "aggs": {
+                    "centroid": {
+                        "geo_centroid": { "field": "location" }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// The `matrix_stats` aggregation is a numeric aggregation that computes the following statistics
+/// over a set of document fields:
+///
+/// - `count` - Number of per field samples included in the calculation.
+/// - `mean` - The average value for each field.
+/// - `variance` - Per field Measurement for how spread out the samples are from the mean.
+/// - `skewness` - Per field measurement quantifying the asymmetric distribution around the mean.
+/// - `kurtosis` - Per field measurement quantifying the shape of the distribution.
+/// - `covariance` - A matrix that quantitatively describes how changes in one field are associated
+/// with another.
+/// - `correlation` - The covariance matrix scaled to a range of -1 to 1, inclusive. Describes the
+/// relationship between field distributions.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-matrix-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MatrixStatsAggregation {
+    matrix_stats: MatrixStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MatrixStatsAggregationInner {
+    fields: Vec<String>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Map<String, Number>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mode: Option<MatrixStatsMode>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MatrixStatsAggregation`]
+    ///
+    /// - `fields` - numeric fields to compute the statistics over
+    pub fn matrix_stats<I>(fields: I) -> MatrixStatsAggregation
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        MatrixStatsAggregation {
+            matrix_stats: MatrixStatsAggregationInner {
+                fields: fields.into_iter().map(|x| x.to_string()).collect(),
+                missing: Default::default(),
+                mode: None,
+            },
+        }
+    }
+}
+
+impl MatrixStatsAggregation {
+    /// Defines how documents that are missing a value for `field` should be treated. By default
+    /// they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T, U>(mut self, field: T, missing: U) -> Self
+    where
+        T: ToString,
+        U: Into<Number>,
+    {
+        let _ = self
+            .matrix_stats
+            .missing
+            .insert(field.to_string(), missing.into());
+        self
+    }
+
+    /// Which value to use for array or multi-valued fields, defaults to
+    /// [`MatrixStatsMode::Avg`]
+    pub fn mode(mut self, mode: MatrixStatsMode) -> Self {
+        self.matrix_stats.mode = Some(mode);
+        self
+    }
+}
+
+impl ShouldSkip for MatrixStatsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::matrix_stats(["poverty", "income"]),
+            json!({ "matrix_stats": { "fields": ["poverty", "income"] } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::matrix_stats(["poverty", "income"])
+                .missing("income", 50000)
+                .mode(MatrixStatsMode::Min),
+            json!({
+                "matrix_stats": {
+                    "fields": ["poverty", "income"],
+                    "missing": { "income": 50000 },
+                    "mode": "min"
+                }
+            }),
+        );
+    }
+}
@@ -69,6 +69,8 @@ impl MaxAggregation {
     }
 }
 
+impl ShouldSkip for MaxAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,132 @@
+use crate::util::*;
+use crate::{Aggregation, Number, Script};
+
+/// This `single-value` aggregation approximates the
+/// [median absolute deviation](https://en.wikipedia.org/wiki/Median_absolute_deviation) of its
+/// search results.
+///
+/// Median absolute deviation is a measure of variability. It is a robust statistic, meaning that
+/// it is useful for describing data that may have outliers, or may not be normally distributed.
+/// For such data it can be more descriptive than standard deviation.
+///
+/// It is calculated as the median of each data point’s deviation from the median of the entire
+/// sample. That is, for a random variable `X`, the median absolute deviation is
+/// `median(|median(X) - Xi|)`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-median-absolute-deviation-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MedianAbsoluteDeviationAggregation {
+    median_absolute_deviation: MedianAbsoluteDeviationAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MedianAbsoluteDeviationAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    compression: Option<Number>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MedianAbsoluteDeviationAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn median_absolute_deviation<T>(field: T) -> MedianAbsoluteDeviationAggregation
+    where
+        T: ToString,
+    {
+        MedianAbsoluteDeviationAggregation {
+            median_absolute_deviation: MedianAbsoluteDeviationAggregationInner {
+                field: field.to_string().into(),
+                script: None,
+                compression: None,
+                missing: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`MedianAbsoluteDeviationAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn median_absolute_deviation_script(script: Script) -> MedianAbsoluteDeviationAggregation {
+        MedianAbsoluteDeviationAggregation {
+            median_absolute_deviation: MedianAbsoluteDeviationAggregationInner {
+                script: script.into(),
+                field: None,
+                compression: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl MedianAbsoluteDeviationAggregation {
+    /// The underlying percentile estimation is done with the TDigest algorithm, the `compression`
+    /// parameter trades memory for accuracy. The default compression value is 1000, using a
+    /// higher value produces more accurate results at the cost of more memory.
+    pub fn compression<T>(mut self, compression: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.median_absolute_deviation.compression = Some(compression.into());
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.median_absolute_deviation.missing = Some(missing.into());
+        self
+    }
+}
+
+impl ShouldSkip for MedianAbsoluteDeviationAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::median_absolute_deviation("rating"),
+            json!({ "median_absolute_deviation": { "field": "rating" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::median_absolute_deviation("rating")
+                .compression(100)
+                .missing(5),
+            json!({
+                "median_absolute_deviation": {
+                    "field": "rating",
+                    "compression": 100,
+                    "missing": 5
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::median_absolute_deviation_script(
+                Script::source("doc['rating'].value * params.scaleFactor").param("scaleFactor", 2),
+            ),
+            json!({
+                "median_absolute_deviation": {
+                    "script": {
+                        "source": "doc['rating'].value * params.scaleFactor",
+                        "params": {
+                            "scaleFactor": 2
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -69,6 +69,8 @@ impl MinAggregation {
     }
 }
 
+impl ShouldSkip for MinAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
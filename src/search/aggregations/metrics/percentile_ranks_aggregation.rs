@@ -0,0 +1,115 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentile ranks over numeric
+/// values extracted from the aggregated documents.
+///
+/// Percentile rank show the percentage of observed values which are below certain value. For
+/// example, if a value is greater than or equal to 95% of the observed values it is said to be at
+/// the 95th percentile rank.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-rank-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentileRanksAggregation {
+    percentile_ranks: PercentileRanksAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentileRanksAggregationInner {
+    field: String,
+
+    values: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    method: Option<PercentilesMethod>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentileRanksAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    /// - `values` - values to calculate the percentile ranks of
+    pub fn percentile_ranks<T, I>(field: T, values: I) -> PercentileRanksAggregation
+    where
+        T: ToString,
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        PercentileRanksAggregation {
+            percentile_ranks: PercentileRanksAggregationInner {
+                field: field.to_string(),
+                values: values.into_iter().map(Into::into).collect(),
+                keyed: None,
+                missing: None,
+                method: None,
+            },
+        }
+    }
+}
+
+impl PercentileRanksAggregation {
+    /// Returns the percentile ranks as an array of key value objects instead
+    /// of a map when set to `false`
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentile_ranks.keyed = Some(keyed);
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentile_ranks.missing = Some(missing.into());
+        self
+    }
+
+    /// Algorithm used to approximate the percentile ranks
+    pub fn method(mut self, method: PercentilesMethod) -> Self {
+        self.percentile_ranks.method = Some(method);
+        self
+    }
+}
+
+impl ShouldSkip for PercentileRanksAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::percentile_ranks("load_time", [500.0, 600.0]),
+            json!({
+                "percentile_ranks": {
+                    "field": "load_time",
+                    "values": [500.0, 600.0]
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentile_ranks("load_time", [500, 600])
+                .keyed(false)
+                .missing(10)
+                .method(PercentilesMethod::hdr(3)),
+            json!({
+                "percentile_ranks": {
+                    "field": "load_time",
+                    "values": [500.0, 600.0],
+                    "keyed": false,
+                    "missing": 10,
+                    "hdr": { "number_of_significant_value_digits": 3 }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that calculates one or more percentiles over numeric values
+/// extracted from the aggregated documents.
+///
+/// Percentiles show the point at which a certain percentage of observed values occur. For example,
+/// the 95th percentile is the value which is greater than 95% of the observed values.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentilesAggregation {
+    percentiles: PercentilesAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentilesAggregationInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    percents: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    method: Option<PercentilesMethod>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentilesAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn percentiles<T>(field: T) -> PercentilesAggregation
+    where
+        T: ToString,
+    {
+        PercentilesAggregation {
+            percentiles: PercentilesAggregationInner {
+                field: field.to_string(),
+                percents: Vec::new(),
+                keyed: None,
+                missing: None,
+                method: None,
+            },
+        }
+    }
+}
+
+impl PercentilesAggregation {
+    /// Percentiles to calculate, defaults to
+    /// `[ 1, 5, 25, 50, 75, 95, 99 ]`
+    pub fn percents<I>(mut self, percents: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        self.percentiles.percents = percents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the percentiles as an array of key value objects instead of
+    /// a map when set to `false`
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentiles.keyed = Some(keyed);
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.percentiles.missing = Some(missing.into());
+        self
+    }
+
+    /// Algorithm used to approximate the percentiles
+    pub fn method(mut self, method: PercentilesMethod) -> Self {
+        self.percentiles.method = Some(method);
+        self
+    }
+}
+
+impl ShouldSkip for PercentilesAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time"),
+            json!({ "percentiles": { "field": "load_time" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time")
+                .percents([95.0, 99.0, 99.9])
+                .keyed(false)
+                .missing(10)
+                .method(PercentilesMethod::tdigest(200)),
+            json!({
+                "percentiles": {
+                    "field": "load_time",
+                    "percents": [95.0, 99.0, 99.9],
+                    "keyed": false,
+                    "missing": 10,
+                    "tdigest": { "compression": 200 }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentiles("load_time")
+                .percents([95.0, 99.0, 99.9])
+                .method(PercentilesMethod::tdigest(200))
+                .method(PercentilesMethod::hdr(3)),
+            json!({
+                "percentiles": {
+                    "field": "load_time",
+                    "percents": [95.0, 99.0, 99.9],
+                    "hdr": { "number_of_significant_value_digits": 3 }
+                }
+            }),
+        );
+    }
+}
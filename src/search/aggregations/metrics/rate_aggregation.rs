@@ -71,6 +71,8 @@ impl RateAggregation {
     }
 }
 
+impl ShouldSkip for RateAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,4 +95,32 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serialization_within_date_histogram() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("date")
+                .calendar_interval(CalendarInterval::Month)
+                .aggregate(
+                    "avg_price",
+                    Aggregation::rate()
+                        .field("price")
+                        .unit(CalendarInterval::Day),
+                ),
+            json!({
+                "date_histogram": {
+                    "field": "date",
+                    "calendar_interval": "month"
+                },
+                "aggs": {
+                    "avg_price": {
+                        "rate": {
+                            "field": "price",
+                            "unit": "day"
+                        }
+                    }
+                }
+            }),
+        );
+    }
 }
@@ -0,0 +1,163 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A metric aggregation that executes using scripts to provide a metric output.
+///
+/// The aggregation is executed in four stages, each one driven by its own script:
+/// - `init_script` - executed prior to any collection of documents, allows the aggregation to
+/// set up any initial state
+/// - `map_script` - executed once per document collected, the only required script
+/// - `combine_script` - executed once on each shard after document collection is complete,
+/// allows the aggregation to consolidate the state returned from each shard
+/// - `reduce_script` - executed once on the coordinating node after all shards have returned
+/// their results, has access to a variable `states` which is an array of the results of the
+/// `combine_script` on each shard
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-scripted-metric-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ScriptedMetricAggregation {
+    scripted_metric: ScriptedMetricAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ScriptedMetricAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    init_script: Option<Script>,
+
+    map_script: Script,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    combine_script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    reduce_script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    params: Map<String, serde_json::Value>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ScriptedMetricAggregation`]
+    ///
+    /// - `map_script` - script executed once per document collected
+    pub fn scripted_metric(map_script: Script) -> ScriptedMetricAggregation {
+        ScriptedMetricAggregation {
+            scripted_metric: ScriptedMetricAggregationInner {
+                init_script: None,
+                map_script,
+                combine_script: None,
+                reduce_script: None,
+                params: Map::new(),
+            },
+        }
+    }
+}
+
+impl ScriptedMetricAggregation {
+    /// Script executed prior to any collection of documents, allows the
+    /// aggregation to set up any initial state
+    pub fn init_script(mut self, init_script: Script) -> Self {
+        self.scripted_metric.init_script = Some(init_script);
+        self
+    }
+
+    /// Script executed once on each shard after document collection is
+    /// complete, allows the aggregation to consolidate the state returned
+    /// from each shard
+    pub fn combine_script(mut self, combine_script: Script) -> Self {
+        self.scripted_metric.combine_script = Some(combine_script);
+        self
+    }
+
+    /// Script executed once on the coordinating node after all shards have
+    /// returned their results
+    pub fn reduce_script(mut self, reduce_script: Script) -> Self {
+        self.scripted_metric.reduce_script = Some(reduce_script);
+        self
+    }
+
+    /// Named parameter passed into the `init`, `map` and `combine` scripts,
+    /// setting the same name again replaces its value
+    pub fn param<T, S>(mut self, name: S, param: T) -> Self
+    where
+        S: ToString,
+        T: Into<serde_json::Value>,
+    {
+        let _ = self
+            .scripted_metric
+            .params
+            .insert(name.to_string(), param.into());
+        self
+    }
+}
+
+impl ShouldSkip for ScriptedMetricAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::scripted_metric(Script::source(
+                "state.transactions.add(doc.type.value == 'sale' ? doc.amount.value : -1 * doc.amount.value)",
+            ))
+            .init_script(Script::source("state.transactions = []"))
+            .combine_script(Script::source(
+                "double profit = 0; for (t in state.transactions) { profit += t } return profit",
+            ))
+            .reduce_script(Script::source(
+                "double profit = 0; for (a in states) { profit += a } return profit",
+            )),
+            json!({
+                "scripted_metric": {
+                    "init_script": {
+                        "source": "state.transactions = []"
+                    },
+                    "map_script": {
+                        "source": "state.transactions.add(doc.type.value == 'sale' ? doc.amount.value : -1 * doc.amount.value)"
+                    },
+                    "combine_script": {
+                        "source": "double profit = 0; for (t in state.transactions) { profit += t } return profit"
+                    },
+                    "reduce_script": {
+                        "source": "double profit = 0; for (a in states) { profit += a } return profit"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::scripted_metric(Script::id("my_map_script").param("field", "amount"))
+                .init_script(Script::source("state.transactions = []"))
+                .combine_script(Script::id("my_combine_script"))
+                .reduce_script(Script::id("my_reduce_script"))
+                .param("multiplier", 1)
+                .param("multiplier", 2),
+            json!({
+                "scripted_metric": {
+                    "init_script": {
+                        "source": "state.transactions = []"
+                    },
+                    "map_script": {
+                        "id": "my_map_script",
+                        "params": {
+                            "field": "amount"
+                        }
+                    },
+                    "combine_script": {
+                        "id": "my_combine_script"
+                    },
+                    "reduce_script": {
+                        "id": "my_reduce_script"
+                    },
+                    "params": {
+                        "multiplier": 2
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `multi-value` metrics aggregation that computes stats over numeric values extracted from the
+/// aggregated documents.
+///
+/// The stats that are returned consist of: `min`, `max`, `sum`, `count` and `avg`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StatsAggregation {
+    stats: StatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct StatsAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`StatsAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn stats<T>(field: T) -> StatsAggregation
+    where
+        T: ToString,
+    {
+        StatsAggregation {
+            stats: StatsAggregationInner {
+                field: field.to_string().into(),
+                script: None,
+                missing: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`StatsAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn stats_script(script: Script) -> StatsAggregation {
+        StatsAggregation {
+            stats: StatsAggregationInner {
+                script: script.into(),
+                field: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl StatsAggregation {
+    /// The `missing` parameter defines how documents that are missing a value should be treated. By
+    /// default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.stats.missing = Some(missing.into());
+        self
+    }
+}
+
+impl ShouldSkip for StatsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::stats("grade"),
+            json!({ "stats": { "field": "grade" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::stats("grade").missing(0),
+            json!({ "stats": { "field": "grade", "missing": 0 } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::stats_script(Script::source("doc['grade'].value").lang("painless")),
+            json!({
+                "stats": {
+                    "script": {
+                        "source": "doc['grade'].value",
+                        "lang": "painless"
+                    }
+                }
+            }),
+        );
+    }
+}
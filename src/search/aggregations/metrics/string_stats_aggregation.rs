@@ -0,0 +1,126 @@
+use crate::util::*;
+use crate::{Aggregation, Script};
+
+/// A `multi-value` metrics aggregation that computes statistics over string values extracted from
+/// the aggregated documents. These values can be retrieved either from specific `keyword` fields
+/// in the documents or can be generated by a provided script.
+///
+/// The string stats aggregation returns the following results:
+/// - `count` - The number of non-empty fields counted.
+/// - `min_length` - The length of the shortest term.
+/// - `max_length` - The length of the longest term.
+/// - `avg_length` - The average length computed over all terms.
+/// - `entropy` - The [Shannon Entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory))
+/// value computed over all terms collected by the aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-string-stats-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StringStatsAggregation {
+    string_stats: StringStatsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct StringStatsAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    show_distribution: Option<bool>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`StringStatsAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn string_stats<T>(field: T) -> StringStatsAggregation
+    where
+        T: ToString,
+    {
+        StringStatsAggregation {
+            string_stats: StringStatsAggregationInner {
+                field: field.to_string().into(),
+                script: None,
+                show_distribution: None,
+                missing: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`StringStatsAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn string_stats_script(script: Script) -> StringStatsAggregation {
+        StringStatsAggregation {
+            string_stats: StringStatsAggregationInner {
+                script: script.into(),
+                field: None,
+                show_distribution: None,
+                missing: None,
+            },
+        }
+    }
+}
+
+impl StringStatsAggregation {
+    /// The computation of the probability distribution for all terms can be enabled by setting
+    /// `show_distribution` to `true`. Defaults to `false`.
+    pub fn show_distribution(mut self, show_distribution: bool) -> Self {
+        self.string_stats.show_distribution = Some(show_distribution);
+        self
+    }
+
+    /// The `missing` parameter defines how documents that are missing a value should be treated.
+    /// By default they will be ignored but it is also possible to treat them as if they had a value.
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.string_stats.missing = Some(missing.to_string());
+        self
+    }
+}
+
+impl ShouldSkip for StringStatsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::string_stats("message.keyword"),
+            json!({ "string_stats": { "field": "message.keyword" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::string_stats("message.keyword")
+                .show_distribution(true)
+                .missing("[empty message]"),
+            json!({
+                "string_stats": {
+                    "field": "message.keyword",
+                    "show_distribution": true,
+                    "missing": "[empty message]"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::string_stats_script(
+                Script::source("doc['message.keyword'].value").lang("painless"),
+            ),
+            json!({
+                "string_stats": {
+                    "script": {
+                        "lang": "painless",
+                        "source": "doc['message.keyword'].value"
+                    }
+                }
+            }),
+        );
+    }
+}
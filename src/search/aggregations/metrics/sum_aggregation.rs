@@ -66,6 +66,8 @@ impl SumAggregation {
     }
 }
 
+impl ShouldSkip for SumAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use crate::{Map, Set};
 
 /// A `top_hits` metric aggregation keeps track of the most relevant document being aggregated.
 /// This aggregation is intended to be used as a sub aggregation,
@@ -33,6 +34,27 @@ struct TopHitsAggregationInner {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     sort: SortCollection,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    highlight: Option<Highlight>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    seq_no_primary_term: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    stored_fields: StoredFields,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    docvalue_fields: Set<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script_fields: Map<String, ScriptField>,
 }
 
 impl Aggregation {
@@ -44,6 +66,13 @@ impl Aggregation {
                 from: None,
                 size: None,
                 sort: Default::default(),
+                highlight: None,
+                explain: None,
+                version: None,
+                seq_no_primary_term: None,
+                stored_fields: Default::default(),
+                docvalue_fields: Default::default(),
+                script_fields: Default::default(),
             },
         }
     }
@@ -82,8 +111,74 @@ impl TopHitsAggregation {
         self.top_hits.sort.extend(sort);
         self
     }
+
+    /// Highlights the top matching hits
+    pub fn highlight<H>(mut self, highlight: H) -> Self
+    where
+        H: Into<Highlight>,
+    {
+        self.top_hits.highlight = Some(highlight.into());
+        self
+    }
+
+    /// If `true`, returns detailed information about score computation as
+    /// part of a hit
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.top_hits.explain = Some(explain);
+        self
+    }
+
+    /// If `true`, returns document version as part of a hit
+    pub fn version(mut self, version: bool) -> Self {
+        self.top_hits.version = Some(version);
+        self
+    }
+
+    /// If `true`, returns sequence number and primary term of the last
+    /// modification of each hit
+    pub fn seq_no_primary_term(mut self, seq_no_primary_term: bool) -> Self {
+        self.top_hits.seq_no_primary_term = Some(seq_no_primary_term);
+        self
+    }
+
+    /// A collection of stored fields, `_none_` (or [`StoredFields::None`])
+    /// disables the stored fields and metadata fields entirely
+    pub fn stored_fields<T>(mut self, stored_fields: T) -> Self
+    where
+        T: Into<StoredFields>,
+    {
+        self.top_hits.stored_fields = stored_fields.into();
+        self
+    }
+
+    /// A collection of docvalue fields
+    pub fn docvalue_fields<T>(mut self, docvalue_fields: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        self.top_hits
+            .docvalue_fields
+            .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
+        self
+    }
+
+    /// Add script fields to the top matching hits
+    pub fn script_fields<S, T>(mut self, name: S, script: T) -> Self
+    where
+        S: ToString,
+        T: Into<ScriptField>,
+    {
+        let _ = self
+            .top_hits
+            .script_fields
+            .insert(name.to_string(), script.into());
+        self
+    }
 }
 
+impl ShouldSkip for TopHitsAggregation {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,5 +204,71 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_aggregation(
+            Aggregation::top_hits()
+                .size(1)
+                .highlight(Highlight::new().field("title"))
+                .explain(true)
+                .version(true)
+                .seq_no_primary_term(true)
+                .stored_fields(["user"])
+                .docvalue_fields(["date"])
+                .script_fields("double_price", Script::source("doc['price'].value * 2")),
+            json!({
+                "top_hits": {
+                    "size": 1,
+                    "highlight": {
+                        "fields": [
+                            { "title": {} }
+                        ]
+                    },
+                    "explain": true,
+                    "version": true,
+                    "seq_no_primary_term": true,
+                    "stored_fields": ["user"],
+                    "docvalue_fields": ["date"],
+                    "script_fields": {
+                        "double_price": {
+                            "script": {
+                                "source": "doc['price'].value * 2"
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization_as_sub_aggregation() {
+        assert_serialize_aggregation(
+            Aggregation::terms("type").size(3).aggregate(
+                "top_sales_hits",
+                Aggregation::top_hits()
+                    .sort(FieldSort::new("date").order(SortOrder::Desc))
+                    .source(SourceFilter::includes(["date", "price"]))
+                    .size(1),
+            ),
+            json!({
+                "terms": {
+                    "field": "type",
+                    "size": 3
+                },
+                "aggs": {
+                    "top_sales_hits": {
+                        "top_hits": {
+                            "sort": [
+                                { "date": { "order": "desc" } }
+                            ],
+                            "_source": {
+                                "includes": ["date", "price"]
+                            },
+                            "size": 1
+                        }
+                    }
+                }
+            }),
+        );
     }
 }
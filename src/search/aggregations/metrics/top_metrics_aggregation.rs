@@ -0,0 +1,137 @@
+use crate::search::*;
+use crate::util::*;
+
+/// The `top_metrics` aggregation selects metrics from the document with the largest or smallest
+/// "sort" value.
+///
+/// `top_metrics` is fairly similar to [`top_hits`](crate::TopHitsAggregation) in spirit but
+/// because it is more limited it is able to do its job using less memory and is often faster.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-top-metrics.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TopMetricsAggregation {
+    top_metrics: TopMetricsAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct TopMetricsAggregationInner {
+    metrics: Vec<TopMetric>,
+
+    sort: Sort,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    size: Option<u64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`TopMetricsAggregation`]
+    ///
+    /// - `metrics` - fields to return the values of
+    /// - `sort` - sort used to select the top documents, a field sort,
+    /// `_score` or a geo distance sort
+    pub fn top_metrics<T, U>(metrics: T, sort: U) -> TopMetricsAggregation
+    where
+        T: IntoIterator,
+        T::Item: Into<TopMetric>,
+        U: Into<Sort>,
+    {
+        TopMetricsAggregation {
+            top_metrics: TopMetricsAggregationInner {
+                metrics: metrics.into_iter().map(Into::into).collect(),
+                sort: sort.into(),
+                size: None,
+            },
+        }
+    }
+}
+
+impl TopMetricsAggregation {
+    /// The number of top documents to return metrics for, defaults to `1`
+    pub fn size(mut self, size: u64) -> Self {
+        self.top_metrics.size = Some(size);
+        self
+    }
+}
+
+impl ShouldSkip for TopMetricsAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::top_metrics(["m"], FieldSort::descending("s")).size(3),
+            json!({
+                "top_metrics": {
+                    "metrics": [
+                        { "field": "m" }
+                    ],
+                    "sort": { "s": { "order": "desc" } },
+                    "size": 3
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::top_metrics(
+                [TopMetric::new("m"), TopMetric::new("i").missing(0)],
+                ScoreSort::new(),
+            ),
+            json!({
+                "top_metrics": {
+                    "metrics": [
+                        { "field": "m" },
+                        { "field": "i", "missing": 0 }
+                    ],
+                    "sort": "_score"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::top_metrics(
+                ["name"],
+                GeoDistanceSort::new("location", GeoLocation::new(35.7796, -78.6382)),
+            ),
+            json!({
+                "top_metrics": {
+                    "metrics": [
+                        { "field": "name" }
+                    ],
+                    "sort": {
+                        "_geo_distance": {
+                            "location": [ [-78.6382, 35.7796] ]
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization_as_sub_aggregation() {
+        assert_serialize_aggregation(
+            Aggregation::terms("interval").aggregate(
+                "tm",
+                Aggregation::top_metrics(["m"], FieldSort::descending("s")),
+            ),
+            json!({
+                "terms": {
+                    "field": "interval"
+                },
+                "aggs": {
+                    "tm": {
+                        "top_metrics": {
+                            "metrics": [
+                                { "field": "m" }
+                            ],
+                            "sort": { "s": { "order": "desc" } }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that counts the number of values that are extracted from
+/// the aggregated documents. These values can be extracted either from specific fields in the
+/// documents, or be generated by a provided script. Typically, this aggregator will be used in
+/// conjunction with other single-value aggregations.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-valuecount-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ValueCountAggregation {
+    value_count: ValueCountAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ValueCountAggregationInner {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ValueCountAggregation`]
+    ///
+    /// - `field` - field to aggregate
+    pub fn value_count<T>(field: T) -> ValueCountAggregation
+    where
+        T: ToString,
+    {
+        ValueCountAggregation {
+            value_count: ValueCountAggregationInner {
+                field: field.to_string().into(),
+                script: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`ValueCountAggregation`]
+    ///
+    /// - `script` - script to aggregate
+    pub fn value_count_script(script: Script) -> ValueCountAggregation {
+        ValueCountAggregation {
+            value_count: ValueCountAggregationInner {
+                script: script.into(),
+                field: None,
+            },
+        }
+    }
+}
+
+impl ShouldSkip for ValueCountAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::value_count("type"),
+            json!({ "value_count": { "field": "type" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::value_count_script(Script::source("doc['type'].value")),
+            json!({
+                "value_count": {
+                    "script": {
+                        "source": "doc['type'].value"
+                    }
+                }
+            }),
+        );
+    }
+}
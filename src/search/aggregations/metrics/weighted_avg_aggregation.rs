@@ -0,0 +1,125 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A `single-value` metrics aggregation that computes the weighted average of numeric values that
+/// are extracted from the aggregated documents. These values can be extracted either from specific
+/// numeric fields in the documents, or be generated by a provided script.
+///
+/// When calculating a regular average, each datapoint has an equal "weight", it contributes
+/// equally to the final value. Weighted averages, on the other hand, weight each datapoint
+/// differently:
+///
+/// ```text
+/// ∑(value * weight) / ∑(weight)
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-weight-avg-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WeightedAvgAggregation {
+    weighted_avg: WeightedAvgAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct WeightedAvgAggregationInner {
+    value: WeightedAvgSource,
+
+    weight: WeightedAvgSource,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    value_type: Option<ValueType>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`WeightedAvgAggregation`]
+    ///
+    /// - `value` - source of the values to average
+    /// - `weight` - source of the weight of each value
+    pub fn weighted_avg(
+        value: WeightedAvgSource,
+        weight: WeightedAvgSource,
+    ) -> WeightedAvgAggregation {
+        WeightedAvgAggregation {
+            weighted_avg: WeightedAvgAggregationInner {
+                value,
+                weight,
+                format: None,
+                value_type: None,
+            },
+        }
+    }
+}
+
+impl WeightedAvgAggregation {
+    /// The numeric response formatter
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.weighted_avg.format = Some(format.to_string());
+        self
+    }
+
+    /// A hint about the values for pure scripts or unmapped fields
+    pub fn value_type(mut self, value_type: ValueType) -> Self {
+        self.weighted_avg.value_type = Some(value_type);
+        self
+    }
+}
+
+impl ShouldSkip for WeightedAvgAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::weighted_avg(
+                WeightedAvgSource::field("grade"),
+                WeightedAvgSource::field("weight"),
+            ),
+            json!({
+                "weighted_avg": {
+                    "value": { "field": "grade" },
+                    "weight": { "field": "weight" }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::weighted_avg(
+                WeightedAvgSource::field("grade").missing(2),
+                WeightedAvgSource::field("weight").missing(3),
+            )
+            .format("0.00")
+            .value_type(ValueType::Double),
+            json!({
+                "weighted_avg": {
+                    "value": { "field": "grade", "missing": 2 },
+                    "weight": { "field": "weight", "missing": 3 },
+                    "format": "0.00",
+                    "value_type": "double"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::weighted_avg(
+                WeightedAvgSource::script(Script::source("doc.grade.value + 1")),
+                WeightedAvgSource::field("weight"),
+            ),
+            json!({
+                "weighted_avg": {
+                    "value": {
+                        "script": { "source": "doc.grade.value + 1" }
+                    },
+                    "weight": { "field": "weight" }
+                }
+            }),
+        );
+    }
+}
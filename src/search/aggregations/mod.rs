@@ -18,6 +18,7 @@ pub mod metrics;
 pub mod params;
 pub mod pipeline;
 
+use crate::util::ShouldSkip;
 use crate::Map;
 
 pub use self::bucket::*;
@@ -47,6 +48,16 @@ macro_rules! aggregation {
             }
         }
 
+        impl ShouldSkip for Aggregation {
+            fn should_skip(&self) -> bool {
+                match self {
+                    $(
+                        Self::$variant(a) => a.should_skip(),
+                    )+
+                }
+            }
+        }
+
         $(
             impl From<$query> for Aggregation {
                 fn from(q: $query) -> Self {
@@ -59,17 +70,74 @@ macro_rules! aggregation {
 
 aggregation!(
     Terms(TermsAggregation),
+    MultiTerms(MultiTermsAggregation),
+    RareTerms(RareTermsAggregation),
+    SignificantTerms(SignificantTermsAggregation),
+    SignificantText(SignificantTextAggregation),
+    DateHistogram(DateHistogramAggregation),
+    AutoDateHistogram(AutoDateHistogramAggregation),
+    Histogram(HistogramAggregation),
+    VariableWidthHistogram(VariableWidthHistogramAggregation),
+    Range(RangeAggregation),
+    DateRange(DateRangeAggregation),
+    IpRange(IpRangeAggregation),
+    IpPrefix(IpPrefixAggregation),
+    GeoDistance(GeoDistanceAggregation),
+    Composite(CompositeAggregation),
+    Nested(NestedAggregation),
+    ReverseNested(ReverseNestedAggregation),
+    Children(ChildrenAggregation),
+    Parent(ParentAggregation),
+    GeohashGrid(GeohashGridAggregation),
+    GeotileGrid(GeotileGridAggregation),
+    GeohexGrid(GeohexGridAggregation),
     TopHits(TopHitsAggregation),
+    TopMetrics(TopMetricsAggregation),
     Cardinality(CardinalityAggregation),
     Avg(AvgAggregation),
     Max(MaxAggregation),
     Min(MinAggregation),
     Sum(SumAggregation),
+    ValueCount(ValueCountAggregation),
+    WeightedAvg(WeightedAvgAggregation),
+    ScriptedMetric(ScriptedMetricAggregation),
+    Percentiles(PercentilesAggregation),
+    PercentileRanks(PercentileRanksAggregation),
+    Stats(StatsAggregation),
+    ExtendedStats(ExtendedStatsAggregation),
     Rate(RateAggregation),
     Sampler(SamplerAggregation),
     Filter(FilterAggregation),
+    Filters(FiltersAggregation),
+    Global(GlobalAggregation),
+    AdjacencyMatrix(AdjacencyMatrixAggregation),
+    Missing(MissingAggregation),
     DiversifiedSampler(DiversifiedSamplerAggregation),
-    Boxplot(BoxplotAggregation)
+    Boxplot(BoxplotAggregation),
+    MedianAbsoluteDeviation(MedianAbsoluteDeviationAggregation),
+    GeoBounds(GeoBoundsAggregation),
+    GeoCentroid(GeoCentroidAggregation),
+    StringStats(StringStatsAggregation),
+    MatrixStats(MatrixStatsAggregation),
+    Derivative(DerivativeAggregation),
+    CumulativeSum(CumulativeSumAggregation),
+    MovingAverage(MovingAverageAggregation),
+    MovingFunction(MovingFunctionAggregation),
+    SerialDiff(SerialDiffAggregation),
+    BucketScript(BucketScriptAggregation),
+    BucketSelector(BucketSelectorAggregation),
+    BucketSort(BucketSortAggregation),
+    Normalize(NormalizeAggregation),
+    BucketCountKsTest(BucketCountKsTestAggregation),
+    BucketCorrelation(BucketCorrelationAggregation),
+    Inference(InferenceAggregation),
+    AvgBucket(AvgBucketAggregation),
+    MaxBucket(MaxBucketAggregation),
+    MinBucket(MinBucketAggregation),
+    SumBucket(SumBucketAggregation),
+    StatsBucket(StatsBucketAggregation),
+    ExtendedStatsBucket(ExtendedStatsBucketAggregation),
+    PercentilesBucket(PercentilesBucketAggregation),
 );
 
 /// Type alias for a collection of aggregations
@@ -0,0 +1,250 @@
+use crate::search::{DateBound, Number, Validate, ValidationError, ValidationErrors};
+use crate::util::ShouldSkip;
+use std::net::IpAddr;
+
+/// A bucket of the [`range`](crate::RangeAggregation) aggregation, the `from`
+/// value is included and the `to` value is excluded from the range
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AggregationRange {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    to: Option<Number>,
+}
+
+impl AggregationRange {
+    /// Creates an instance of [`AggregationRange`] unbounded on both ends
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Custom key of the bucket, defaults to the `from-to` representation
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// Lower limit of the range, inclusive
+    pub fn from<T>(mut self, from: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Upper limit of the range, exclusive
+    pub fn to<T>(mut self, to: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.to = Some(to.into());
+        self
+    }
+}
+
+/// A bucket of the [`date_range`](crate::DateRangeAggregation) aggregation,
+/// the `from` value is included and the `to` value is excluded from the range
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct AggregationDateRange {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<DateBound>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    to: Option<DateBound>,
+}
+
+impl AggregationDateRange {
+    /// Creates an instance of [`AggregationDateRange`] unbounded on both ends
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Custom key of the bucket, defaults to the `from-to` representation
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// Lower limit of the range, inclusive, either a date or a
+    /// [date math](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-daterange-aggregation.html#date-format-pattern)
+    /// expression such as `now-10M/M`
+    pub fn from<T>(mut self, from: T) -> Self
+    where
+        T: Into<DateBound>,
+    {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Upper limit of the range, exclusive, either a date or a
+    /// [date math](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-daterange-aggregation.html#date-format-pattern)
+    /// expression such as `now-10M/M`
+    pub fn to<T>(mut self, to: T) -> Self
+    where
+        T: Into<DateBound>,
+    {
+        self.to = Some(to.into());
+        self
+    }
+}
+
+/// A bucket of the [`geo_distance`](crate::GeoDistanceAggregation)
+/// aggregation, the `from` distance is included and the `to` distance is
+/// excluded from the range
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct DistanceRange {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<Number>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    to: Option<Number>,
+}
+
+impl DistanceRange {
+    /// Creates an instance of [`DistanceRange`] unbounded on both ends
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Custom key of the bucket, defaults to the `from-to` representation
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// Lower limit of the range, inclusive, in the unit of the aggregation
+    pub fn from<T>(mut self, from: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Upper limit of the range, exclusive, in the unit of the aggregation
+    pub fn to<T>(mut self, to: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.to = Some(to.into());
+        self
+    }
+}
+
+/// A bucket of the [`ip_range`](crate::IpRangeAggregation) aggregation,
+/// either limited by `from` (inclusive) and `to` (exclusive) addresses or
+/// defined by a CIDR mask
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct IpRange {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    key: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    from: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    to: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mask: Option<String>,
+}
+
+impl IpRange {
+    /// Creates an instance of [`IpRange`] unbounded on both ends
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an instance of [`IpRange`] covering the addresses of a CIDR
+    /// mask, e.g. `10.0.0.0/25`
+    pub fn mask<T>(mask: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            mask: Some(mask.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Custom key of the bucket, defaults to the `from-to` representation or
+    /// the mask
+    pub fn key<T>(mut self, key: T) -> Self
+    where
+        T: ToString,
+    {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    /// Lower limit of the range, inclusive
+    pub fn from<T>(mut self, from: T) -> Self
+    where
+        T: ToString,
+    {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    /// Upper limit of the range, exclusive
+    pub fn to<T>(mut self, to: T) -> Self
+    where
+        T: ToString,
+    {
+        self.to = Some(to.to_string());
+        self
+    }
+}
+
+impl Validate for IpRange {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (path, address) in [("from", &self.from), ("to", &self.to)] {
+            if let Some(address) = address {
+                if address.parse::<IpAddr>().is_err() {
+                    errors.push(path, format!("`{address}` is not a valid IP address"));
+                }
+            }
+        }
+
+        if let Some(mask) = &self.mask {
+            let valid = match mask.split_once('/') {
+                Some((address, prefix_length)) => {
+                    match (address.parse::<IpAddr>(), prefix_length.parse::<u8>()) {
+                        (Ok(IpAddr::V4(_)), Ok(prefix_length)) => prefix_length <= 32,
+                        (Ok(IpAddr::V6(_)), Ok(prefix_length)) => prefix_length <= 128,
+                        _ => false,
+                    }
+                }
+                None => false,
+            };
+
+            if !valid {
+                errors.push("mask", format!("`{mask}` is not a valid CIDR mask"));
+            }
+        }
+
+        errors.into_result()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::util::*;
+
+/// Correlation function executed by the
+/// [`bucket_correlation`](crate::BucketCorrelationAggregation) aggregation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BucketCorrelationFunction {
+    /// Correlates the count values of the configured sibling multi-bucket
+    /// aggregation with the indicator
+    CountCorrelation {
+        /// The indicator to correlate the configured values against
+        indicator: CountCorrelationIndicator,
+    },
+}
+
+impl BucketCorrelationFunction {
+    /// Creates a [`BucketCorrelationFunction::CountCorrelation`] function
+    pub fn count_correlation(indicator: CountCorrelationIndicator) -> Self {
+        Self::CountCorrelation { indicator }
+    }
+}
+
+/// Indicator the bucket counts are correlated against
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CountCorrelationIndicator {
+    expectations: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fractions: Vec<f64>,
+
+    doc_count: u64,
+}
+
+impl CountCorrelationIndicator {
+    /// Creates an instance of [`CountCorrelationIndicator`]
+    ///
+    /// - `expectations` - the values to correlate the bucket counts with, must have the same
+    /// length as the number of buckets
+    /// - `doc_count` - the total number of documents that initially created the expectations
+    pub fn new<T>(expectations: T, doc_count: u64) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<f64>,
+    {
+        Self {
+            expectations: expectations.into_iter().map(Into::into).collect(),
+            fractions: Vec::new(),
+            doc_count,
+        }
+    }
+
+    /// The prior probability of each expectation, defaults to an equal
+    /// probability for every bucket
+    pub fn fractions<T>(mut self, fractions: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<f64>,
+    {
+        self.fractions = fractions.into_iter().map(Into::into).collect();
+        self
+    }
+}
@@ -0,0 +1,22 @@
+/// Mechanism by which cardinality aggregations are executed
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-cardinality-aggregation.html#_execution_hint>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardinalityExecutionHint {
+    /// Uses global ordinals to calculate the cardinality, only applicable to
+    /// fields with ordinals such as `keyword`
+    GlobalOrdinals,
+
+    /// Uses segment ordinals and resolves the values of each segment
+    SegmentOrdinals,
+
+    /// Uses the field values directly
+    Direct,
+
+    /// Picks the implementation using the least memory
+    SaveMemoryHeuristic,
+
+    /// Picks the fastest implementation, the default
+    SaveTimeHeuristic,
+}
@@ -0,0 +1,13 @@
+/// Strategy used to build the tree of buckets and sub-aggregations
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#search-aggregations-bucket-terms-aggregation-collect>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectMode {
+    /// Builds the whole tree in one pass, then prunes it
+    DepthFirst,
+
+    /// Builds and prunes the top level buckets before computing their
+    /// sub-aggregations, which lowers memory usage when few buckets are kept
+    BreadthFirst,
+}
@@ -0,0 +1,454 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Ordering of the bucket for documents without a value, relative to the
+/// other buckets of a [composite](crate::CompositeAggregation) source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingOrder {
+    /// Missing bucket comes first
+    First,
+
+    /// Missing bucket comes last
+    Last,
+
+    /// Missing bucket is first in ascending order and last in descending
+    /// order
+    Default,
+}
+
+/// A named source of a [composite](crate::CompositeAggregation) aggregation,
+/// the composite buckets are built from the combinations of the values of
+/// every source.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-composite-aggregation.html#_values_source_2>
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompositeSource {
+    /// Uses the values of a field as with a `terms` aggregation
+    Terms(TermsCompositeSource),
+
+    /// Groups numeric values into fixed size intervals
+    Histogram(HistogramCompositeSource),
+
+    /// Groups dates into calendar-aware or fixed intervals
+    DateHistogram(DateHistogramCompositeSource),
+
+    /// Groups geo points into map tiles
+    GeotileGrid(GeotileGridCompositeSource),
+}
+
+macro_rules! composite_source {
+    ($name:ident { $inner_name:ident: $inner:ident }) => {
+        impl $name {
+            /// Creates a bucket for documents without a value of the
+            /// source, by default they are ignored
+            pub fn missing_bucket(mut self, missing_bucket: bool) -> Self {
+                self.$inner_name.missing_bucket = Some(missing_bucket);
+                self
+            }
+
+            /// Position of the bucket for documents without a value,
+            /// requires [`missing_bucket`](Self::missing_bucket)
+            pub fn missing_order(mut self, missing_order: MissingOrder) -> Self {
+                self.$inner_name.missing_order = Some(missing_order);
+                self
+            }
+
+            /// Order of the values of the source, defaults to ascending
+            pub fn order(mut self, order: SortOrder) -> Self {
+                self.$inner_name.order = Some(order);
+                self
+            }
+        }
+
+        impl From<$name> for CompositeSource {
+            fn from(value: $name) -> Self {
+                Self::$inner(value)
+            }
+        }
+    };
+}
+
+impl serde::Serialize for CompositeSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Terms(source) => KeyValuePair::new(&source.name, source).serialize(serializer),
+            Self::Histogram(source) => {
+                KeyValuePair::new(&source.name, source).serialize(serializer)
+            }
+            Self::DateHistogram(source) => {
+                KeyValuePair::new(&source.name, source).serialize(serializer)
+            }
+            Self::GeotileGrid(source) => {
+                KeyValuePair::new(&source.name, source).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl CompositeSource {
+    /// Creates an instance of [`TermsCompositeSource`]
+    ///
+    /// - `name` - name of the source in the bucket keys
+    /// - `field` - field to take the values from
+    pub fn terms<T, U>(name: T, field: U) -> TermsCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
+        TermsCompositeSource {
+            name: name.to_string(),
+            terms: TermsCompositeSourceInner {
+                field: field.to_string(),
+                missing_bucket: None,
+                missing_order: None,
+                order: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`HistogramCompositeSource`]
+    ///
+    /// - `name` - name of the source in the bucket keys
+    /// - `field` - numeric field to take the values from
+    /// - `interval` - width of each interval
+    pub fn histogram<T, U, V>(name: T, field: U, interval: V) -> HistogramCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+        V: num_traits::AsPrimitive<f64>,
+    {
+        HistogramCompositeSource {
+            name: name.to_string(),
+            histogram: HistogramCompositeSourceInner {
+                field: field.to_string(),
+                interval: interval.as_(),
+                missing_bucket: None,
+                missing_order: None,
+                order: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`DateHistogramCompositeSource`] with a
+    /// calendar-aware interval
+    ///
+    /// - `name` - name of the source in the bucket keys
+    /// - `field` - date field to take the values from
+    /// - `calendar_interval` - calendar-aware interval
+    pub fn date_histogram<T, U>(
+        name: T,
+        field: U,
+        calendar_interval: CalendarInterval,
+    ) -> DateHistogramCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
+        Self::date_histogram_with_interval(
+            name,
+            field,
+            DateHistogramInterval::CalendarInterval(calendar_interval),
+        )
+    }
+
+    /// Creates an instance of [`DateHistogramCompositeSource`] with a fixed
+    /// interval
+    ///
+    /// - `name` - name of the source in the bucket keys
+    /// - `field` - date field to take the values from
+    /// - `fixed_interval` - fixed interval, e.g. `90m`
+    pub fn date_histogram_fixed<T, U, V>(
+        name: T,
+        field: U,
+        fixed_interval: V,
+    ) -> DateHistogramCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+        V: Into<Time>,
+    {
+        Self::date_histogram_with_interval(
+            name,
+            field,
+            DateHistogramInterval::FixedInterval(fixed_interval.into()),
+        )
+    }
+
+    fn date_histogram_with_interval<T, U>(
+        name: T,
+        field: U,
+        interval: DateHistogramInterval,
+    ) -> DateHistogramCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
+        DateHistogramCompositeSource {
+            name: name.to_string(),
+            date_histogram: DateHistogramCompositeSourceInner {
+                field: field.to_string(),
+                interval,
+                format: None,
+                time_zone: None,
+                offset: None,
+                missing_bucket: None,
+                missing_order: None,
+                order: None,
+            },
+        }
+    }
+
+    /// Creates an instance of [`GeotileGridCompositeSource`]
+    ///
+    /// - `name` - name of the source in the bucket keys
+    /// - `field` - geo point field to take the values from
+    pub fn geotile_grid<T, U>(name: T, field: U) -> GeotileGridCompositeSource
+    where
+        T: ToString,
+        U: ToString,
+    {
+        GeotileGridCompositeSource {
+            name: name.to_string(),
+            geotile_grid: GeotileGridCompositeSourceInner {
+                field: field.to_string(),
+                precision: None,
+                missing_bucket: None,
+                missing_order: None,
+                order: None,
+            },
+        }
+    }
+}
+
+/// Composite source using the values of a field, see [`CompositeSource::terms`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TermsCompositeSource {
+    #[serde(skip)]
+    name: String,
+
+    terms: TermsCompositeSourceInner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TermsCompositeSourceInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<SortOrder>,
+}
+
+composite_source!(TermsCompositeSource { terms: Terms });
+
+/// Composite source grouping numeric values into intervals, see
+/// [`CompositeSource::histogram`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramCompositeSource {
+    #[serde(skip)]
+    name: String,
+
+    histogram: HistogramCompositeSourceInner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct HistogramCompositeSourceInner {
+    field: String,
+
+    interval: f64,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<SortOrder>,
+}
+
+composite_source!(HistogramCompositeSource {
+    histogram: Histogram
+});
+
+/// Composite source grouping dates into intervals, see
+/// [`CompositeSource::date_histogram`] and
+/// [`CompositeSource::date_histogram_fixed`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DateHistogramCompositeSource {
+    #[serde(skip)]
+    name: String,
+
+    date_histogram: DateHistogramCompositeSourceInner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct DateHistogramCompositeSourceInner {
+    field: String,
+
+    #[serde(flatten)]
+    interval: DateHistogramInterval,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    offset: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<SortOrder>,
+}
+
+impl DateHistogramCompositeSource {
+    /// [Date format](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html)
+    /// of the values in the bucket keys, by default they are epoch
+    /// milliseconds
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.format = Some(format.to_string());
+        self
+    }
+
+    /// Time zone used for bucketing and rounding, either an ISO 8601 UTC
+    /// offset (e.g. `-01:00`) or an IANA time zone ID (e.g. `America/Los_Angeles`)
+    pub fn time_zone<T>(mut self, time_zone: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Changes the start value of each bucket by the given positive (`+`) or
+    /// negative (`-`) duration, e.g. `+6h` to start days at 6am
+    pub fn offset<T>(mut self, offset: T) -> Self
+    where
+        T: ToString,
+    {
+        self.date_histogram.offset = Some(offset.to_string());
+        self
+    }
+}
+
+composite_source!(DateHistogramCompositeSource {
+    date_histogram: DateHistogram
+});
+
+/// Composite source grouping geo points into map tiles, see
+/// [`CompositeSource::geotile_grid`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeotileGridCompositeSource {
+    #[serde(skip)]
+    name: String,
+
+    geotile_grid: GeotileGridCompositeSourceInner,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct GeotileGridCompositeSourceInner {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    precision: Option<u8>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_bucket: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing_order: Option<MissingOrder>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    order: Option<SortOrder>,
+}
+
+impl GeotileGridCompositeSource {
+    /// Zoom level of the tiles, between `0` and `29`.
+    ///
+    /// Defaults to `7`
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.geotile_grid.precision = Some(precision);
+        self
+    }
+}
+
+composite_source!(GeotileGridCompositeSource {
+    geotile_grid: GeotileGrid
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            [
+                CompositeSource::from(
+                    CompositeSource::terms("product", "product")
+                        .missing_bucket(true)
+                        .missing_order(MissingOrder::Last)
+                        .order(SortOrder::Desc),
+                ),
+                CompositeSource::histogram("histo", "price", 5).into(),
+                CompositeSource::date_histogram("date", "timestamp", CalendarInterval::Day)
+                    .format("yyyy-MM-dd")
+                    .time_zone("America/New_York")
+                    .offset("+6h")
+                    .into(),
+                CompositeSource::date_histogram_fixed("hourly", "timestamp", Time::Hours(1)).into(),
+                CompositeSource::geotile_grid("tile", "location")
+                    .precision(8)
+                    .into(),
+            ],
+            json!([
+                {
+                    "product": {
+                        "terms": {
+                            "field": "product",
+                            "missing_bucket": true,
+                            "missing_order": "last",
+                            "order": "desc"
+                        }
+                    }
+                },
+                { "histo": { "histogram": { "field": "price", "interval": 5.0 } } },
+                {
+                    "date": {
+                        "date_histogram": {
+                            "field": "timestamp",
+                            "calendar_interval": "day",
+                            "format": "yyyy-MM-dd",
+                            "time_zone": "America/New_York",
+                            "offset": "+6h"
+                        }
+                    }
+                },
+                {
+                    "hourly": {
+                        "date_histogram": { "field": "timestamp", "fixed_interval": "1h" }
+                    }
+                },
+                { "tile": { "geotile_grid": { "field": "location", "precision": 8 } } }
+            ]),
+        );
+    }
+}
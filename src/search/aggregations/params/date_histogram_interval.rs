@@ -0,0 +1,10 @@
+use crate::search::{CalendarInterval, Time};
+
+/// Either a calendar-aware or a fixed interval, the two are mutually
+/// exclusive and flattened into the enclosing date histogram
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DateHistogramInterval {
+    CalendarInterval(CalendarInterval),
+    FixedInterval(Time),
+}
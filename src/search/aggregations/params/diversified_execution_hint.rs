@@ -0,0 +1,20 @@
+/// Mechanism by which the values used for de-duplication are held by the
+/// diversified sampler aggregation
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-diversified-sampler-aggregation.html#_execution_hint_2>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiversifiedExecutionHint {
+    /// Hold field values directly
+    Map,
+
+    /// Hold ordinals of the field as determined by the Lucene index
+    GlobalOrdinals,
+
+    /// Hold hashes of the field values - with potential for hash collisions
+    BytesHash,
+}
+
+/// Former name of [`DiversifiedExecutionHint`]
+#[deprecated(note = "renamed to `DiversifiedExecutionHint`")]
+pub type ExecutionHint = DiversifiedExecutionHint;
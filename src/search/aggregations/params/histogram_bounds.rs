@@ -0,0 +1,112 @@
+use crate::search::ChronoTime;
+use crate::util::ShouldSkip;
+
+/// Lower and upper limits of histogram buckets, used by `extended_bounds` to
+/// force buckets to be created and by `hard_bounds` to drop buckets outside of
+/// the range
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramBounds<T> {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    min: Option<T>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max: Option<T>,
+}
+
+impl<T> HistogramBounds<T> {
+    /// Creates bounds limited on both ends
+    pub fn new<U, V>(min: U, max: V) -> Self
+    where
+        U: Into<T>,
+        V: Into<T>,
+    {
+        Self {
+            min: Some(min.into()),
+            max: Some(max.into()),
+        }
+    }
+
+    /// Creates bounds limited by the lower end only
+    pub fn at_least<U>(min: U) -> Self
+    where
+        U: Into<T>,
+    {
+        Self {
+            min: Some(min.into()),
+            max: None,
+        }
+    }
+
+    /// Creates bounds limited by the upper end only
+    pub fn at_most<U>(max: U) -> Self
+    where
+        U: Into<T>,
+    {
+        Self {
+            min: None,
+            max: Some(max.into()),
+        }
+    }
+}
+
+/// Date histogram bound or date range limit, either a date string parsed with the aggregation
+/// `format` (date math such as `now-1d/d` is supported) or milliseconds since
+/// the epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum DateBound {
+    /// Date string, e.g. `2020-01-01` or `now/M`
+    Date(String),
+
+    /// Milliseconds since the epoch
+    EpochMillis(i64),
+}
+
+impl From<&str> for DateBound {
+    fn from(value: &str) -> Self {
+        Self::Date(value.to_string())
+    }
+}
+
+impl From<String> for DateBound {
+    fn from(value: String) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl From<i64> for DateBound {
+    fn from(value: i64) -> Self {
+        Self::EpochMillis(value)
+    }
+}
+
+impl From<ChronoTime> for DateBound {
+    fn from(value: ChronoTime) -> Self {
+        Self::EpochMillis(value.timestamp_millis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+    use chrono::prelude::*;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            HistogramBounds::<DateBound>::new("2020-01-01", 1609459200000),
+            json!({ "min": "2020-01-01", "max": 1609459200000i64 }),
+        );
+        assert_serialize(
+            HistogramBounds::<DateBound>::at_least(
+                Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).single().unwrap(),
+            ),
+            json!({ "min": 1609459200000i64 }),
+        );
+        assert_serialize(
+            HistogramBounds::<DateBound>::at_most("now/d"),
+            json!({ "max": "now/d" }),
+        );
+    }
+}
@@ -0,0 +1,30 @@
+/// Sampling methodology used by the
+/// [`bucket_count_ks_test`](crate::BucketCountKsTestAggregation) aggregation to pick the
+/// values of the distribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KsTestSamplingMethod {
+    /// Samples the upper tail of the distribution, the default
+    UpperTail,
+
+    /// Samples the distribution uniformly
+    Uniform,
+
+    /// Samples the lower tail of the distribution
+    LowerTail,
+}
+
+/// Alternative hypothesis tested by the
+/// [`bucket_count_ks_test`](crate::BucketCountKsTestAggregation) aggregation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KsTestAlternative {
+    /// The sampled distribution is less than the bucket counts
+    Less,
+
+    /// The sampled distribution is greater than the bucket counts
+    Greater,
+
+    /// The sampled distribution differs from the bucket counts
+    TwoSided,
+}
@@ -0,0 +1,16 @@
+/// Which value to use for array or multi-valued fields in
+/// [MatrixStatsAggregation](crate::search::MatrixStatsAggregation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatrixStatsMode {
+    /// Use the average of all values
+    Avg,
+    /// Pick the lowest value
+    Min,
+    /// Pick the highest value
+    Max,
+    /// Use the sum of all values
+    Sum,
+    /// Use the median of all values
+    Median,
+}
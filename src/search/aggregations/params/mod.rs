@@ -1,11 +1,53 @@
 //! Value types accepted by aggregation clauses
 
 mod aggregation_name;
+mod aggregation_range;
+mod bucket_correlation_function;
+mod cardinality_execution_hint;
+mod collect_mode;
+mod composite_source;
+mod date_histogram_interval;
+mod diversified_execution_hint;
 mod gap_policy;
+mod histogram_bounds;
+mod ks_test_sampling_method;
+mod matrix_stats_mode;
+mod moving_average_model;
+mod multi_term_lookup;
+mod normalize_method;
+mod percentiles_method;
 mod rate_mode;
+mod significance_heuristic;
+mod tdigest_execution_hint;
+mod terms_execution_hint;
+mod terms_include_exclude;
 mod terms_order;
+mod top_metric;
+mod value_type;
+mod weighted_avg_source;
 
 pub use self::aggregation_name::*;
+pub use self::aggregation_range::*;
+pub use self::bucket_correlation_function::*;
+pub use self::cardinality_execution_hint::*;
+pub use self::collect_mode::*;
+pub use self::composite_source::*;
+pub(crate) use self::date_histogram_interval::*;
+pub use self::diversified_execution_hint::*;
 pub use self::gap_policy::*;
+pub use self::histogram_bounds::*;
+pub use self::ks_test_sampling_method::*;
+pub use self::matrix_stats_mode::*;
+pub use self::moving_average_model::*;
+pub use self::multi_term_lookup::*;
+pub use self::normalize_method::*;
+pub use self::percentiles_method::*;
 pub use self::rate_mode::*;
+pub use self::significance_heuristic::*;
+pub use self::tdigest_execution_hint::*;
+pub use self::terms_execution_hint::*;
+pub use self::terms_include_exclude::*;
 pub use self::terms_order::*;
+pub use self::top_metric::*;
+pub use self::value_type::*;
+pub use self::weighted_avg_source::*;
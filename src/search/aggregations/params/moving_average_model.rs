@@ -0,0 +1,112 @@
+use crate::util::*;
+
+/// Model used by the [`moving_avg`](crate::MovingAverageAggregation) aggregation to weight the
+/// values of its window
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/6.8/search-aggregations-pipeline-movavg-aggregation.html#_models>
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "model", content = "settings", rename_all = "snake_case")]
+pub enum MovingAverageModel {
+    /// Sums all the values in the window and divides by the size of the window, every value is
+    /// weighted equally
+    Simple,
+
+    /// Assigns a linear weighting to points in the series, such that "older" datapoints
+    /// contribute linearly less to the total average
+    Linear,
+
+    /// Exponentially weighted moving average, older datapoints become exponentially less
+    /// important
+    Ewma {
+        /// Decay of the weights, between `0` and `1`, defaults to `0.3`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        alpha: Option<f32>,
+    },
+
+    /// Double exponential model, tracking both the level and the trend of the data
+    Holt {
+        /// Decay of the level, between `0` and `1`, defaults to `0.3`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        alpha: Option<f32>,
+
+        /// Decay of the trend, between `0` and `1`, defaults to `0.1`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        beta: Option<f32>,
+    },
+
+    /// Triple exponential model, tracking the level, the trend and the seasonality of the data
+    HoltWinters {
+        /// Decay of the level, between `0` and `1`, defaults to `0.3`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        alpha: Option<f32>,
+
+        /// Decay of the trend, between `0` and `1`, defaults to `0.1`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        beta: Option<f32>,
+
+        /// Decay of the seasonality, between `0` and `1`, defaults to `0.3`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        gamma: Option<f32>,
+
+        /// Periodicity of the data, defaults to `1`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        period: Option<u32>,
+
+        /// Whether the seasonality is added to or multiplied with the data
+        #[serde(rename = "type", skip_serializing_if = "ShouldSkip::should_skip")]
+        ty: Option<HoltWintersType>,
+
+        /// Whether all values are padded by a small amount, avoiding a
+        /// division by zero when the data contains zeros
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        pad: Option<bool>,
+    },
+}
+
+/// How the seasonality is combined with the data by the
+/// [`HoltWinters`](MovingAverageModel::HoltWinters) model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoltWintersType {
+    /// The seasonality is added to the data
+    Add,
+
+    /// The seasonality is multiplied with the data
+    Mult,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(MovingAverageModel::Simple, json!({ "model": "simple" }));
+
+        assert_serialize(
+            MovingAverageModel::Ewma { alpha: Some(0.5) },
+            json!({ "model": "ewma", "settings": { "alpha": 0.5 } }),
+        );
+
+        assert_serialize(
+            MovingAverageModel::HoltWinters {
+                alpha: Some(0.5),
+                beta: Some(0.5),
+                gamma: Some(0.5),
+                period: Some(7),
+                ty: Some(HoltWintersType::Mult),
+                pad: None,
+            },
+            json!({
+                "model": "holt_winters",
+                "settings": {
+                    "alpha": 0.5,
+                    "beta": 0.5,
+                    "gamma": 0.5,
+                    "period": 7,
+                    "type": "mult"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Term source of a [`MultiTermsAggregation`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MultiTermLookup {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Term>,
+}
+
+impl MultiTermLookup {
+    /// Creates an instance of [`MultiTermLookup`]
+    ///
+    /// - `field` - field to group by
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            missing: None,
+        }
+    }
+
+    /// The value to use for documents missing the field, such documents
+    /// are ignored by default
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.missing = Term::new(missing);
+        self
+    }
+}
+
+impl<T> From<T> for MultiTermLookup
+where
+    T: ToString,
+{
+    fn from(field: T) -> Self {
+        Self::new(field)
+    }
+}
@@ -0,0 +1,34 @@
+/// Method used by the [`normalize`](crate::NormalizeAggregation) aggregation to rescale the
+/// values of its parent buckets
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-normalize-aggregation.html#_methods>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NormalizeMethod {
+    /// Rescales the data such that the minimum number is zero, and the maximum number is one,
+    /// with the rest normalized linearly in-between
+    #[serde(rename = "rescale_0_1")]
+    Rescale0To1,
+
+    /// Rescales the data such that the minimum number is zero, and the maximum number is 100,
+    /// with the rest normalized linearly in-between
+    #[serde(rename = "rescale_0_100")]
+    Rescale0To100,
+
+    /// Normalizes each value so that it represents a percentage of the total sum it attributes to
+    #[serde(rename = "percent_of_sum")]
+    PercentOfSum,
+
+    /// Normalizes such that each value is normalized by how much it differs from the average
+    #[serde(rename = "mean")]
+    Mean,
+
+    /// Normalizes such that each value represents how far it is from the mean relative to the
+    /// standard deviation
+    #[serde(rename = "z-score")]
+    ZScore,
+
+    /// Normalizes such that each value is exponentiated and relative to the sum of the
+    /// exponents of the original values
+    #[serde(rename = "softmax")]
+    Softmax,
+}
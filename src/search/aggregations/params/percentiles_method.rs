@@ -0,0 +1,48 @@
+use crate::util::*;
+use crate::Number;
+
+/// Algorithm used to approximate percentiles, the two are mutually exclusive
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html#search-aggregations-metrics-percentile-aggregation-approximation>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum PercentilesMethod {
+    /// TDigest algorithm, the default
+    #[serde(rename = "tdigest")]
+    TDigest {
+        /// Trades memory for accuracy, defaults to `100`
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        compression: Option<Number>,
+    },
+
+    /// HDR Histogram, faster than TDigest with a larger memory footprint
+    /// and only usable with positive values
+    #[serde(rename = "hdr")]
+    Hdr {
+        /// Resolution of values for the histogram in number of significant
+        /// digits
+        number_of_significant_value_digits: u8,
+    },
+}
+
+impl PercentilesMethod {
+    /// Creates a [`PercentilesMethod::TDigest`] method
+    ///
+    /// - `compression` - limits the maximum number of nodes to 20 * `compression`
+    pub fn tdigest<T>(compression: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        Self::TDigest {
+            compression: Some(compression.into()),
+        }
+    }
+
+    /// Creates a [`PercentilesMethod::Hdr`] method
+    ///
+    /// - `number_of_significant_value_digits` - resolution of the histogram
+    pub fn hdr(number_of_significant_value_digits: u8) -> Self {
+        Self::Hdr {
+            number_of_significant_value_digits,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::util::*;
+use crate::Script;
+
+/// Scores how significant a term is by comparing its frequency in the
+/// foreground set against the background set
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-significantterms-aggregation.html#_parameters>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignificanceHeuristic {
+    /// JLH score, used by default
+    Jlh {},
+
+    /// Mutual information as described in "Information Retrieval", Manning et al., Chapter 13.5.1
+    MutualInformation {
+        /// Whether terms that appear less often in the subset than outside
+        /// of it should be included
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        include_negatives: Option<bool>,
+
+        /// Set to `false` when the background set is not a superset of the
+        /// foreground set, e.g. with a custom background filter
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Chi square as described in "Information Retrieval", Manning et al., Chapter 13.5.2
+    ChiSquare {
+        /// Whether terms that appear less often in the subset than outside
+        /// of it should be included
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        include_negatives: Option<bool>,
+
+        /// Set to `false` when the background set is not a superset of the
+        /// foreground set, e.g. with a custom background filter
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Google normalized distance as described in "The Google Similarity Distance", Cilibrasi and
+    /// Vitanyi, 2007
+    Gnd {
+        /// Set to `false` when the background set is not a superset of the
+        /// foreground set, e.g. with a custom background filter
+        #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+        background_is_superset: Option<bool>,
+    },
+
+    /// Number of foreground documents with a term divided by the number of
+    /// background documents with the term
+    Percentage {},
+
+    /// Custom score computed by a script, with access to `_subset_freq`,
+    /// `_superset_freq`, `_subset_size` and `_superset_size` variables
+    ScriptHeuristic {
+        /// Script computing the score
+        script: Script,
+    },
+}
+
+impl SignificanceHeuristic {
+    /// Creates an instance of [`SignificanceHeuristic::Jlh`]
+    pub fn jlh() -> Self {
+        Self::Jlh {}
+    }
+
+    /// Creates an instance of [`SignificanceHeuristic::MutualInformation`]
+    /// with default parameters
+    pub fn mutual_information() -> Self {
+        Self::MutualInformation {
+            include_negatives: None,
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates an instance of [`SignificanceHeuristic::ChiSquare`] with
+    /// default parameters
+    pub fn chi_square() -> Self {
+        Self::ChiSquare {
+            include_negatives: None,
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates an instance of [`SignificanceHeuristic::Gnd`] with default
+    /// parameters
+    pub fn gnd() -> Self {
+        Self::Gnd {
+            background_is_superset: None,
+        }
+    }
+
+    /// Creates an instance of [`SignificanceHeuristic::Percentage`]
+    pub fn percentage() -> Self {
+        Self::Percentage {}
+    }
+
+    /// Creates an instance of [`SignificanceHeuristic::ScriptHeuristic`]
+    pub fn script(script: Script) -> Self {
+        Self::ScriptHeuristic { script }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(SignificanceHeuristic::jlh(), json!({ "jlh": {} }));
+        assert_serialize(
+            SignificanceHeuristic::MutualInformation {
+                include_negatives: Some(true),
+                background_is_superset: None,
+            },
+            json!({ "mutual_information": { "include_negatives": true } }),
+        );
+        assert_serialize(SignificanceHeuristic::gnd(), json!({ "gnd": {} }));
+        assert_serialize(
+            SignificanceHeuristic::percentage(),
+            json!({ "percentage": {} }),
+        );
+        assert_serialize(
+            SignificanceHeuristic::script(Script::source(
+                "params._subset_freq/(params._superset_freq - params._subset_freq + 1)",
+            )),
+            json!({
+                "script_heuristic": {
+                    "script": {
+                        "source": "params._subset_freq/(params._superset_freq - params._subset_freq + 1)"
+                    }
+                }
+            }),
+        );
+    }
+}
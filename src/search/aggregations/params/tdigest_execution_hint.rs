@@ -0,0 +1,13 @@
+/// Implementation of the TDigest algorithm used by percentile based
+/// aggregations
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-metrics-percentile-aggregation.html#tdigest-execution-hint>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TDigestExecutionHint {
+    /// Optimized implementation offering faster performance, the default
+    Default,
+
+    /// Slower implementation offering better accuracy
+    HighAccuracy,
+}
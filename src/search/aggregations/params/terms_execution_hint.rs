@@ -0,0 +1,13 @@
+/// Mechanism by which terms aggregations are executed
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#search-aggregations-bucket-terms-aggregation-execution-hint>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TermsExecutionHint {
+    /// Uses field values directly in order to aggregate data per-bucket
+    Map,
+
+    /// Uses global ordinals of the field and allocates one bucket per
+    /// ordinal, the default for `keyword` fields
+    GlobalOrdinals,
+}
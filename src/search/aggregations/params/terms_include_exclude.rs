@@ -0,0 +1,140 @@
+use crate::util::ShouldSkip;
+
+/// Filters the values for which buckets will be created
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#_filtering_values_4>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum TermsInclude {
+    /// Regular expression matching the values to include
+    Regex(String),
+
+    /// Exact values to include
+    Exact(Vec<String>),
+
+    /// Splits the unique terms into partitions and only includes the terms
+    /// of one of them, which allows processing many terms over several
+    /// requests
+    Partitions {
+        /// Zero based partition to include
+        partition: u32,
+
+        /// Number of partitions
+        num_partitions: u32,
+    },
+}
+
+impl TermsInclude {
+    /// Creates an instance of [`TermsInclude::Partitions`]
+    ///
+    /// - `partition` - Zero based partition to include
+    /// - `num_partitions` - Number of partitions
+    pub fn partition(partition: u32, num_partitions: u32) -> Self {
+        Self::Partitions {
+            partition,
+            num_partitions,
+        }
+    }
+}
+
+impl ShouldSkip for TermsInclude {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Regex(regex) => regex.should_skip(),
+            Self::Exact(values) => values.should_skip(),
+            Self::Partitions { .. } => false,
+        }
+    }
+}
+
+/// Filters out the values for which buckets would otherwise be created
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-terms-aggregation.html#_filtering_values_4>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum TermsExclude {
+    /// Regular expression matching the values to exclude
+    Regex(String),
+
+    /// Exact values to exclude
+    Exact(Vec<String>),
+}
+
+impl ShouldSkip for TermsExclude {
+    fn should_skip(&self) -> bool {
+        match self {
+            Self::Regex(regex) => regex.should_skip(),
+            Self::Exact(values) => values.should_skip(),
+        }
+    }
+}
+
+macro_rules! impl_from_values {
+    ($ty:ty) => {
+        impl From<&str> for $ty {
+            fn from(value: &str) -> Self {
+                Self::Regex(value.to_string())
+            }
+        }
+
+        impl From<String> for $ty {
+            fn from(value: String) -> Self {
+                Self::Regex(value)
+            }
+        }
+
+        impl<T> From<Vec<T>> for $ty
+        where
+            T: ToString,
+        {
+            fn from(values: Vec<T>) -> Self {
+                Self::Exact(values.iter().map(ToString::to_string).collect())
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for $ty
+        where
+            T: ToString,
+        {
+            fn from(values: [T; N]) -> Self {
+                Self::Exact(values.iter().map(ToString::to_string).collect())
+            }
+        }
+
+        impl<T> From<&[T]> for $ty
+        where
+            T: ToString,
+        {
+            fn from(values: &[T]) -> Self {
+                Self::Exact(values.iter().map(ToString::to_string).collect())
+            }
+        }
+    };
+}
+
+impl_from_values!(TermsInclude);
+impl_from_values!(TermsExclude);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(TermsInclude::from(".*sport.*"), json!(".*sport.*"));
+        assert_serialize(
+            TermsInclude::from(["mazda", "honda"]),
+            json!(["mazda", "honda"]),
+        );
+        assert_serialize(
+            TermsInclude::partition(0, 20),
+            json!({ "partition": 0, "num_partitions": 20 }),
+        );
+        assert_serialize(TermsExclude::from("water_.*"), json!("water_.*"));
+        assert_serialize(
+            TermsExclude::from(vec!["rover", "jensen"]),
+            json!(["rover", "jensen"]),
+        );
+    }
+}
@@ -3,14 +3,63 @@ use crate::{
     SortOrder,
 };
 
+/// Key terms aggregation buckets are sorted by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TermsOrderKey {
+    /// Sorts by document count, `_count`
+    Count,
+
+    /// Sorts alphabetically by term, `_key`
+    Key,
+
+    /// Sorts by a single value metrics sub-aggregation, identified by its
+    /// path, e.g. `my_avg` or `my_stats.max`
+    Aggregation(String),
+}
+
+impl TermsOrderKey {
+    /// Returns the key as it is sent to Elasticsearch
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Count => "_count",
+            Self::Key => "_key",
+            Self::Aggregation(path) => path,
+        }
+    }
+}
+
+impl serde::Serialize for TermsOrderKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<T> From<T> for TermsOrderKey
+where
+    T: ToString,
+{
+    fn from(value: T) -> Self {
+        let value = value.to_string();
+
+        match value.as_str() {
+            "_count" => Self::Count,
+            "_key" => Self::Key,
+            _ => Self::Aggregation(value),
+        }
+    }
+}
+
 /// Terms Aggregation sorting criterion
 #[derive(Clone, PartialEq, Eq, Serialize)]
-pub struct TermsOrder(KeyValuePair<String, SortOrder>);
+pub struct TermsOrder(KeyValuePair<TermsOrderKey, SortOrder>);
 
 impl std::fmt::Debug for TermsOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TermsOrder")
-            .field(&self.0.key, &self.0.value)
+            .field(self.0.key.as_str(), &self.0.value)
             .finish()
     }
 }
@@ -18,19 +67,19 @@ impl std::fmt::Debug for TermsOrder {
 impl TermsOrder {
     /// Creates an instance of [TermsOrder]
     ///
-    /// - `key` - Key to sort by
+    /// - `key` - Key to sort by, `_count`, `_key` or a sub-aggregation path
     /// - `order` - Sorting order
     pub fn new<T>(key: T, order: SortOrder) -> Self
     where
-        T: ToString,
+        T: Into<TermsOrderKey>,
     {
-        Self(KeyValuePair::new(key.to_string(), order))
+        Self(KeyValuePair::new(key.into(), order))
     }
 
     /// Sorts terms by a given key in ascending order
     pub fn ascending<T>(key: T) -> Self
     where
-        T: ToString,
+        T: Into<TermsOrderKey>,
     {
         Self::new(key, SortOrder::Asc)
     }
@@ -38,29 +87,29 @@ impl TermsOrder {
     /// Sorts terms by a given key in descending order
     pub fn descending<T>(key: T) -> Self
     where
-        T: ToString,
+        T: Into<TermsOrderKey>,
     {
         Self::new(key, SortOrder::Desc)
     }
 
     /// Sorts terms by count ascending
     pub fn count_ascending() -> Self {
-        Self::ascending("_count")
+        Self::ascending(TermsOrderKey::Count)
     }
 
     /// Sorts terms by count descending
     pub fn count_descending() -> Self {
-        Self::descending("_count")
+        Self::descending(TermsOrderKey::Count)
     }
 
-    /// Sorts terms by count ascending
+    /// Sorts terms by key ascending
     pub fn key_ascending() -> Self {
-        Self::ascending("_key")
+        Self::ascending(TermsOrderKey::Key)
     }
 
-    /// Sorts terms by count descending
+    /// Sorts terms by key descending
     pub fn key_descending() -> Self {
-        Self::descending("_key")
+        Self::descending(TermsOrderKey::Key)
     }
 }
 
@@ -105,5 +154,19 @@ mod tests {
     fn serializes() {
         assert_serialize(TermsOrder::key_ascending(), json!({ "_key": "asc" }));
         assert_serialize(TermsOrder::count_descending(), json!({ "_count": "desc" }));
+        assert_serialize(
+            TermsOrder::ascending("my_stats.max"),
+            json!({ "my_stats.max": "asc" }),
+        );
+    }
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(TermsOrderKey::from("_count"), TermsOrderKey::Count);
+        assert_eq!(TermsOrderKey::from("_key"), TermsOrderKey::Key);
+        assert_eq!(
+            TermsOrderKey::from("my_avg"),
+            TermsOrderKey::Aggregation("my_avg".to_string())
+        );
     }
 }
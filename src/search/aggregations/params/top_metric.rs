@@ -0,0 +1,48 @@
+use crate::util::*;
+use crate::Number;
+
+/// A field whose value is returned by the
+/// [`top_metrics`](crate::TopMetricsAggregation) aggregation
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TopMetric {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl TopMetric {
+    /// Creates an instance of [`TopMetric`]
+    ///
+    /// - `field` - field to return the value of
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            missing: None,
+        }
+    }
+
+    /// Value returned for documents missing a value in the field
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.missing = Some(missing.into());
+        self
+    }
+}
+
+impl From<&str> for TopMetric {
+    fn from(field: &str) -> Self {
+        Self::new(field)
+    }
+}
+
+impl From<String> for TopMetric {
+    fn from(field: String) -> Self {
+        Self::new(field)
+    }
+}
@@ -0,0 +1,32 @@
+/// Type of the values an aggregation works on, used to interpret values
+/// produced by scripts or unmapped fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueType {
+    /// Text values
+    String,
+
+    /// Any numeric values
+    Numeric,
+
+    /// Integer values
+    Long,
+
+    /// Floating point values
+    Double,
+
+    /// Date values, in milliseconds since the epoch
+    Date,
+
+    /// Date values, in nanoseconds since the epoch
+    DateNanos,
+
+    /// IPv4 and IPv6 addresses
+    Ip,
+
+    /// Geo points
+    GeoPoint,
+
+    /// Boolean values
+    Boolean,
+}
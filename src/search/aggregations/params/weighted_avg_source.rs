@@ -0,0 +1,49 @@
+use crate::util::*;
+use crate::{Number, Script};
+
+/// Where the values or the weights of a
+/// [`weighted_avg`](crate::WeightedAvgAggregation) aggregation are taken from
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WeightedAvgSource {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    missing: Option<Number>,
+}
+
+impl WeightedAvgSource {
+    /// Takes the values from a field
+    pub fn field<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: Some(field.to_string()),
+            script: None,
+            missing: None,
+        }
+    }
+
+    /// Takes the values from a script
+    pub fn script(script: Script) -> Self {
+        Self {
+            field: None,
+            script: Some(script),
+            missing: None,
+        }
+    }
+
+    /// The value to use if the field is missing entirely, by default
+    /// documents missing a value are ignored
+    pub fn missing<T>(mut self, missing: T) -> Self
+    where
+        T: Into<Number>,
+    {
+        self.missing = Some(missing.into());
+        self
+    }
+}
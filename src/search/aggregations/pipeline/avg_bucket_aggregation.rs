@@ -0,0 +1,63 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which calculates the mean value of a specified metric in a
+/// sibling aggregation. The specified metric must be numeric and the sibling aggregation must be
+/// a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-avg-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AvgBucketAggregation {
+    avg_bucket: AvgBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct AvgBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`AvgBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the mean value of
+    pub fn avg_bucket<T>(buckets_path: T) -> AvgBucketAggregation
+    where
+        T: ToString,
+    {
+        AvgBucketAggregation {
+            avg_bucket: AvgBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+            },
+        }
+    }
+}
+
+impl AvgBucketAggregation {
+    add_sibling_pipeline_methods!(avg_bucket);
+}
+
+impl ShouldSkip for AvgBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::avg_bucket("sales_per_month>sales")
+                .gap_policy(GapPolicy::Skip)
+                .format("#,##0.00;(#,##0.00)"),
+            json!({
+                "avg_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "gap_policy": "skip",
+                    "format": "#,##0.00;(#,##0.00)"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which executes a correlation function on the configured
+/// sibling multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-correlation-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketCorrelationAggregation {
+    bucket_correlation: BucketCorrelationAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketCorrelationAggregationInner {
+    buckets_path: String,
+
+    function: BucketCorrelationFunction,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketCorrelationAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets containing the values to correlate
+    /// - `function` - the correlation function to execute
+    pub fn bucket_correlation<T>(
+        buckets_path: T,
+        function: BucketCorrelationFunction,
+    ) -> BucketCorrelationAggregation
+    where
+        T: ToString,
+    {
+        BucketCorrelationAggregation {
+            bucket_correlation: BucketCorrelationAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                function,
+            },
+        }
+    }
+}
+
+impl ShouldSkip for BucketCorrelationAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::bucket_correlation(
+                "latency_ranges>_count",
+                BucketCorrelationFunction::count_correlation(CountCorrelationIndicator::new(
+                    [0.0, 52.5, 165.0],
+                    200,
+                )),
+            ),
+            json!({
+                "bucket_correlation": {
+                    "buckets_path": "latency_ranges>_count",
+                    "function": {
+                        "count_correlation": {
+                            "indicator": {
+                                "expectations": [0.0, 52.5, 165.0],
+                                "doc_count": 200
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::bucket_correlation(
+                "latency_ranges>_count",
+                BucketCorrelationFunction::count_correlation(
+                    CountCorrelationIndicator::new([0.0, 52.5], 200).fractions([0.1, 0.9]),
+                ),
+            ),
+            json!({
+                "bucket_correlation": {
+                    "buckets_path": "latency_ranges>_count",
+                    "function": {
+                        "count_correlation": {
+                            "indicator": {
+                                "expectations": [0.0, 52.5],
+                                "fractions": [0.1, 0.9],
+                                "doc_count": 200
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
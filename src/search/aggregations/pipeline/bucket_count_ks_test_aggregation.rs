@@ -0,0 +1,108 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A sibling pipeline aggregation which executes a two sample Kolmogorov–Smirnov test (referred
+/// to as a "K-S test" from now on) against a provided distribution, and the distribution implied
+/// by the documents counts in the configured sibling aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-bucket-count-ks-test-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketCountKsTestAggregation {
+    bucket_count_ks_test: BucketCountKsTestAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketCountKsTestAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    alternative: Vec<KsTestAlternative>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    fractions: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sampling_method: Option<KsTestSamplingMethod>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketCountKsTestAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets containing the values to test
+    /// against, must point to the `_count` of a multi-bucket aggregation
+    pub fn bucket_count_ks_test<T>(buckets_path: T) -> BucketCountKsTestAggregation
+    where
+        T: ToString,
+    {
+        BucketCountKsTestAggregation {
+            bucket_count_ks_test: BucketCountKsTestAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                alternative: Vec::new(),
+                fractions: Vec::new(),
+                sampling_method: None,
+            },
+        }
+    }
+}
+
+impl BucketCountKsTestAggregation {
+    /// The alternatives to calculate, defaults to all of them
+    pub fn alternative<T>(mut self, alternative: T) -> Self
+    where
+        T: IntoIterator<Item = KsTestAlternative>,
+    {
+        self.bucket_count_ks_test.alternative.extend(alternative);
+        self
+    }
+
+    /// A list of fractions indicating the provided distribution, defaults
+    /// to a uniform distribution
+    pub fn fractions<T>(mut self, fractions: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<f64>,
+    {
+        self.bucket_count_ks_test.fractions = fractions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The sampling methodology used when calculating the K-S test
+    pub fn sampling_method(mut self, sampling_method: KsTestSamplingMethod) -> Self {
+        self.bucket_count_ks_test.sampling_method = Some(sampling_method);
+        self
+    }
+}
+
+impl ShouldSkip for BucketCountKsTestAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::bucket_count_ks_test("latency_ranges>_count"),
+            json!({
+                "bucket_count_ks_test": {
+                    "buckets_path": "latency_ranges>_count"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::bucket_count_ks_test("latency_ranges>_count")
+                .alternative([KsTestAlternative::Less, KsTestAlternative::TwoSided])
+                .fractions([0.25, 0.25, 0.25, 0.25])
+                .sampling_method(KsTestSamplingMethod::Uniform),
+            json!({
+                "bucket_count_ks_test": {
+                    "buckets_path": "latency_ranges>_count",
+                    "alternative": ["less", "two_sided"],
+                    "fractions": [0.25, 0.25, 0.25, 0.25],
+                    "sampling_method": "uniform"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A parent pipeline aggregation which executes a script which can perform per bucket
+/// computations on specified metrics in the parent multi-bucket aggregation. The specified metric
+/// must be numeric and the script must return a numeric value.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-bucket-script-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketScriptAggregation {
+    bucket_script: BucketScriptAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketScriptAggregationInner {
+    buckets_path: Map<String, String>,
+
+    script: Script,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketScriptAggregation`]
+    ///
+    /// - `buckets_path` - script variables mapped to the path of the metrics
+    /// they hold
+    /// - `script` - script to run for this aggregation, the variables are
+    /// available through `params`
+    pub fn bucket_script<I, K, V>(buckets_path: I, script: Script) -> BucketScriptAggregation
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        BucketScriptAggregation {
+            bucket_script: BucketScriptAggregationInner {
+                buckets_path: buckets_path
+                    .into_iter()
+                    .map(|(name, path)| (name.to_string(), path.to_string()))
+                    .collect(),
+                script,
+                format: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl BucketScriptAggregation {
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.bucket_script.format = Some(format.to_string());
+        self
+    }
+
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.bucket_script.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl ShouldSkip for BucketScriptAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::bucket_script(
+                [("my_var1", "the_sum"), ("my_var2", "the_value_count")],
+                Script::source("params.my_var1 / params.my_var2"),
+            )
+            .format("0.00")
+            .gap_policy(GapPolicy::Skip),
+            json!({
+                "bucket_script": {
+                    "buckets_path": {
+                        "my_var1": "the_sum",
+                        "my_var2": "the_value_count"
+                    },
+                    "script": {
+                        "source": "params.my_var1 / params.my_var2"
+                    },
+                    "format": "0.00",
+                    "gap_policy": "skip"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A parent pipeline aggregation which executes a script which determines whether the current
+/// bucket will be retained in the parent multi-bucket aggregation. The specified metric must be
+/// numeric and the script must return a boolean value. If the script language is `expression`
+/// then a numeric return value is permitted. In this case `0.0` will be evaluated as `false` and
+/// all other values will evaluate to `true`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-bucket-selector-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BucketSelectorAggregation {
+    bucket_selector: BucketSelectorAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct BucketSelectorAggregationInner {
+    buckets_path: Map<String, String>,
+
+    script: Script,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`BucketSelectorAggregation`]
+    ///
+    /// - `buckets_path` - script variables mapped to the path of the metrics
+    /// they hold
+    /// - `script` - script deciding whether a bucket is retained, the
+    /// variables are available through `params`
+    pub fn bucket_selector<I, K, V>(buckets_path: I, script: Script) -> BucketSelectorAggregation
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        BucketSelectorAggregation {
+            bucket_selector: BucketSelectorAggregationInner {
+                buckets_path: buckets_path
+                    .into_iter()
+                    .map(|(name, path)| (name.to_string(), path.to_string()))
+                    .collect(),
+                script,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl BucketSelectorAggregation {
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.bucket_selector.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl ShouldSkip for BucketSelectorAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("date")
+                .calendar_interval(CalendarInterval::Month)
+                .aggregate("total_sales", Aggregation::sum("price"))
+                .aggregate(
+                    "t-shirts",
+                    Aggregation::filter(Query::term("type", "t-shirt"))
+                        .aggregate("sales", Aggregation::sum("price")),
+                )
+                .aggregate(
+                    "t-shirt-percentage",
+                    Aggregation::bucket_script(
+                        [
+                            ("tShirtSales", "t-shirts>sales"),
+                            ("totalSales", "total_sales"),
+                        ],
+                        Script::source("params.tShirtSales / params.totalSales * 100"),
+                    ),
+                )
+                .aggregate(
+                    "sales_bucket_filter",
+                    Aggregation::bucket_selector(
+                        [("totalSales", "total_sales")],
+                        Script::source("params.totalSales > 200"),
+                    )
+                    .gap_policy(GapPolicy::InsertZeros),
+                )
+                .aggregate(
+                    "sales_bucket_sort",
+                    Aggregation::bucket_sort()
+                        .sort(FieldSort::descending("total_sales"))
+                        .size(3),
+                ),
+            json!({
+                "date_histogram": {
+                    "field": "date",
+                    "calendar_interval": "month"
+                },
+                "aggs": {
+                    "total_sales": {
+                        "sum": { "field": "price" }
+                    },
+                    "t-shirts": {
+                        "filter": {
+                            "term": { "type": { "value": "t-shirt" } }
+                        },
+                        "aggs": {
+                            "sales": { "sum": { "field": "price" } }
+                        }
+                    },
+                    "t-shirt-percentage": {
+                        "bucket_script": {
+                            "buckets_path": {
+                                "tShirtSales": "t-shirts>sales",
+                                "totalSales": "total_sales"
+                            },
+                            "script": {
+                                "source": "params.tShirtSales / params.totalSales * 100"
+                            }
+                        }
+                    },
+                    "sales_bucket_filter": {
+                        "bucket_selector": {
+                            "buckets_path": {
+                                "totalSales": "total_sales"
+                            },
+                            "script": {
+                                "source": "params.totalSales > 200"
+                            },
+                            "gap_policy": "insert_zeros"
+                        }
+                    },
+                    "sales_bucket_sort": {
+                        "bucket_sort": {
+                            "sort": [
+                                { "total_sales": { "order": "desc" } }
+                            ],
+                            "size": 3
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which calculates the derivative of a specified metric in a
+/// parent histogram (or date_histogram) aggregation. The specified metric must be numeric and
+/// the enclosing histogram must have `min_doc_count` set to `0` (default for histogram
+/// aggregations).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-derivative-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DerivativeAggregation {
+    derivative: DerivativeAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct DerivativeAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    unit: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`DerivativeAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to find the derivative of
+    pub fn derivative<T>(buckets_path: T) -> DerivativeAggregation
+    where
+        T: ToString,
+    {
+        DerivativeAggregation {
+            derivative: DerivativeAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                gap_policy: None,
+                unit: None,
+                format: None,
+            },
+        }
+    }
+}
+
+impl DerivativeAggregation {
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.derivative.gap_policy = Some(gap_policy);
+        self
+    }
+
+    /// The time unit to use for the x-axis of the derivative, e.g. `day`,
+    /// the result is then also returned normalized to that unit
+    pub fn unit<T>(mut self, unit: T) -> Self
+    where
+        T: ToString,
+    {
+        self.derivative.unit = Some(unit.to_string());
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.derivative.format = Some(format.to_string());
+        self
+    }
+}
+
+impl ShouldSkip for DerivativeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::derivative("sales"),
+            json!({ "derivative": { "buckets_path": "sales" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::derivative("sales")
+                .gap_policy(GapPolicy::InsertZeros)
+                .unit("day")
+                .format("0.0"),
+            json!({
+                "derivative": {
+                    "buckets_path": "sales",
+                    "gap_policy": "insert_zeros",
+                    "unit": "day",
+                    "format": "0.0"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization_within_date_histogram() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("date")
+                .calendar_interval(CalendarInterval::Month)
+                .aggregate("sales", Aggregation::sum("price"))
+                .aggregate("sales_deriv", Aggregation::derivative("sales"))
+                .aggregate("sales_2nd_deriv", Aggregation::derivative("sales_deriv")),
+            json!({
+                "date_histogram": {
+                    "field": "date",
+                    "calendar_interval": "month"
+                },
+                "aggs": {
+                    "sales": { "sum": { "field": "price" } },
+                    "sales_deriv": {
+                        "derivative": { "buckets_path": "sales" }
+                    },
+                    "sales_2nd_deriv": {
+                        "derivative": { "buckets_path": "sales_deriv" }
+                    }
+                }
+            }),
+        );
+    }
+}
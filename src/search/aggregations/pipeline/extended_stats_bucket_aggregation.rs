@@ -0,0 +1,76 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::*;
+
+/// A sibling pipeline aggregation which calculates a variety of stats across all bucket of a
+/// specified metric in a sibling aggregation. The specified metric must be numeric and the
+/// sibling aggregation must be a multi-bucket aggregation.
+///
+/// This aggregation provides a few more statistics (sum of squares, standard deviation, etc)
+/// compared to the [`stats_bucket`](crate::StatsBucketAggregation) aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-extended-stats-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ExtendedStatsBucketAggregation {
+    extended_stats_bucket: ExtendedStatsBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct ExtendedStatsBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    sigma: Option<f64>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`ExtendedStatsBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the extended stats of
+    pub fn extended_stats_bucket<T>(buckets_path: T) -> ExtendedStatsBucketAggregation
+    where
+        T: ToString,
+    {
+        ExtendedStatsBucketAggregation {
+            extended_stats_bucket: ExtendedStatsBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+                sigma: None,
+            },
+        }
+    }
+}
+
+impl ExtendedStatsBucketAggregation {
+    /// The number of standard deviations above/below the mean to display,
+    /// defaults to `2`
+    pub fn sigma<T>(mut self, sigma: T) -> Self
+    where
+        T: Into<f64>,
+    {
+        self.extended_stats_bucket.sigma = Some(sigma.into());
+        self
+    }
+
+    add_sibling_pipeline_methods!(extended_stats_bucket);
+}
+
+impl ShouldSkip for ExtendedStatsBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::extended_stats_bucket("sales_per_month>sales").sigma(3),
+            json!({
+                "extended_stats_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "sigma": 3.0
+                }
+            }),
+        );
+    }
+}
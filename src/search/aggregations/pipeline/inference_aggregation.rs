@@ -0,0 +1,110 @@
+use crate::search::*;
+use crate::util::*;
+use crate::Map;
+
+/// A parent pipeline aggregation which loads a pre-trained model and performs inference on the
+/// collated result fields from the parent bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-inference-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InferenceAggregation {
+    inference: InferenceAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct InferenceAggregationInner {
+    model_id: String,
+
+    buckets_path: Map<String, String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inference_config: Option<serde_json::Value>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`InferenceAggregation`]
+    ///
+    /// - `model_id` - the ID or alias for the trained model
+    pub fn inference<T>(model_id: T) -> InferenceAggregation
+    where
+        T: ToString,
+    {
+        InferenceAggregation {
+            inference: InferenceAggregationInner {
+                model_id: model_id.to_string(),
+                buckets_path: Map::new(),
+                inference_config: None,
+            },
+        }
+    }
+}
+
+impl InferenceAggregation {
+    /// Maps a model input field to the path of the aggregation providing its
+    /// value
+    pub fn buckets_path<T, U>(mut self, field: T, path: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        let _ = self
+            .inference
+            .buckets_path
+            .insert(field.to_string(), path.to_string());
+        self
+    }
+
+    /// Contains the inference type and its options, its shape depends on the
+    /// type of the model
+    pub fn inference_config(mut self, inference_config: serde_json::Value) -> Self {
+        self.inference.inference_config = Some(inference_config);
+        self
+    }
+}
+
+impl ShouldSkip for InferenceAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::inference("my-model").buckets_path("price", "sales>avg_price"),
+            json!({
+                "inference": {
+                    "model_id": "my-model",
+                    "buckets_path": {
+                        "price": "sales>avg_price"
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::inference("malicious_clients_model")
+                .buckets_path("response_count", "responses_total")
+                .buckets_path("url_dc", "url_dc")
+                .inference_config(json!({
+                    "classification": {
+                        "num_top_classes": 2
+                    }
+                })),
+            json!({
+                "inference": {
+                    "model_id": "malicious_clients_model",
+                    "buckets_path": {
+                        "response_count": "responses_total",
+                        "url_dc": "url_dc"
+                    },
+                    "inference_config": {
+                        "classification": {
+                            "num_top_classes": 2
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which identifies the bucket(s) with the maximum value of a
+/// specified metric in a sibling aggregation and outputs both the value and the key(s) of the
+/// bucket(s). The specified metric must be numeric and the sibling aggregation must be a
+/// multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-max-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MaxBucketAggregation {
+    max_bucket: MaxBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MaxBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MaxBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the maximum value of
+    pub fn max_bucket<T>(buckets_path: T) -> MaxBucketAggregation
+    where
+        T: ToString,
+    {
+        MaxBucketAggregation {
+            max_bucket: MaxBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+            },
+        }
+    }
+}
+
+impl MaxBucketAggregation {
+    add_sibling_pipeline_methods!(max_bucket);
+}
+
+impl ShouldSkip for MaxBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::max_bucket("sales_per_month>sales"),
+            json!({
+                "max_bucket": {
+                    "buckets_path": "sales_per_month>sales"
+                }
+            }),
+        );
+
+        let search = Search::new()
+            .size(0)
+            .aggregate(
+                "sales_per_month",
+                Aggregation::date_histogram("date")
+                    .calendar_interval(CalendarInterval::Month)
+                    .aggregate("sales", Aggregation::sum("price")),
+            )
+            .aggregate(
+                "max_monthly_sales",
+                Aggregation::max_bucket("sales_per_month>sales"),
+            );
+
+        assert_serialize(
+            search,
+            json!({
+                "size": 0,
+                "aggs": {
+                    "sales_per_month": {
+                        "date_histogram": {
+                            "field": "date",
+                            "calendar_interval": "month"
+                        },
+                        "aggs": {
+                            "sales": {
+                                "sum": { "field": "price" }
+                            }
+                        }
+                    },
+                    "max_monthly_sales": {
+                        "max_bucket": {
+                            "buckets_path": "sales_per_month>sales"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which identifies the bucket(s) with the minimum value of a
+/// specified metric in a sibling aggregation and outputs both the value and the key(s) of the
+/// bucket(s). The specified metric must be numeric and the sibling aggregation must be a
+/// multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-min-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MinBucketAggregation {
+    min_bucket: MinBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MinBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MinBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the minimum value of
+    pub fn min_bucket<T>(buckets_path: T) -> MinBucketAggregation
+    where
+        T: ToString,
+    {
+        MinBucketAggregation {
+            min_bucket: MinBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+            },
+        }
+    }
+}
+
+impl MinBucketAggregation {
+    add_sibling_pipeline_methods!(min_bucket);
+}
+
+impl ShouldSkip for MinBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::min_bucket("sales_per_month>sales"),
+            json!({
+                "min_bucket": {
+                    "buckets_path": "sales_per_month>sales"
+                }
+            }),
+        );
+    }
+}
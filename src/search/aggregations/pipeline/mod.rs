@@ -23,5 +23,47 @@
 //!
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline.html>
 
-#[doc(hidden)]
-pub mod todo {}
+#[macro_use]
+mod sibling_pipeline;
+
+mod avg_bucket_aggregation;
+mod bucket_correlation_aggregation;
+mod bucket_count_ks_test_aggregation;
+mod bucket_script_aggregation;
+mod bucket_selector_aggregation;
+mod bucket_sort_aggregation;
+mod cumulative_sum_aggregation;
+mod derivative_aggregation;
+mod extended_stats_bucket_aggregation;
+mod inference_aggregation;
+mod max_bucket_aggregation;
+mod min_bucket_aggregation;
+mod moving_average_aggregation;
+mod moving_function_aggregation;
+mod normalize_aggregation;
+mod percentiles_bucket_aggregation;
+mod serial_diff_aggregation;
+mod stats_bucket_aggregation;
+mod sum_bucket_aggregation;
+
+pub use self::avg_bucket_aggregation::*;
+pub use self::bucket_correlation_aggregation::*;
+pub use self::bucket_count_ks_test_aggregation::*;
+pub use self::bucket_script_aggregation::*;
+pub use self::bucket_selector_aggregation::*;
+pub use self::bucket_sort_aggregation::*;
+pub use self::cumulative_sum_aggregation::*;
+pub use self::derivative_aggregation::*;
+pub use self::extended_stats_bucket_aggregation::*;
+pub use self::inference_aggregation::*;
+pub use self::max_bucket_aggregation::*;
+pub use self::min_bucket_aggregation::*;
+pub use self::moving_average_aggregation::*;
+pub use self::moving_function_aggregation::*;
+pub use self::normalize_aggregation::*;
+pub use self::percentiles_bucket_aggregation::*;
+pub use self::serial_diff_aggregation::*;
+pub use self::stats_bucket_aggregation::*;
+pub use self::sum_bucket_aggregation::*;
+
+use self::sibling_pipeline::SiblingPipeline;
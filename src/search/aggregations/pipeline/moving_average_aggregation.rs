@@ -0,0 +1,116 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Given an ordered series of data, the Moving Average aggregation will slide a window across
+/// the data and emit the average value of that window.
+///
+/// The `moving_avg` aggregation has been removed in Elasticsearch 8.0, prefer the
+/// [`moving_fn`](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-movfn-aggregation.html)
+/// aggregation on newer clusters.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/6.8/search-aggregations-pipeline-movavg-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MovingAverageAggregation {
+    moving_avg: MovingAverageAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MovingAverageAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    window: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
+    model: Option<MovingAverageModel>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MovingAverageAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to find the moving average of
+    pub fn moving_avg<T>(buckets_path: T) -> MovingAverageAggregation
+    where
+        T: ToString,
+    {
+        MovingAverageAggregation {
+            moving_avg: MovingAverageAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                window: None,
+                model: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl MovingAverageAggregation {
+    /// The size of window to "slide" across the histogram, defaults to `5`
+    pub fn window(mut self, window: u32) -> Self {
+        self.moving_avg.window = Some(window);
+        self
+    }
+
+    /// The moving average weighting model, defaults to
+    /// [`simple`](MovingAverageModel::Simple)
+    pub fn model(mut self, model: MovingAverageModel) -> Self {
+        self.moving_avg.model = Some(model);
+        self
+    }
+
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.moving_avg.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl ShouldSkip for MovingAverageAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::moving_avg("the_sum"),
+            json!({ "moving_avg": { "buckets_path": "the_sum" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::moving_avg("the_sum")
+                .window(30)
+                .model(MovingAverageModel::Holt {
+                    alpha: Some(0.5),
+                    beta: Some(0.5),
+                })
+                .gap_policy(GapPolicy::Skip),
+            json!({
+                "moving_avg": {
+                    "buckets_path": "the_sum",
+                    "window": 30,
+                    "model": "holt",
+                    "settings": {
+                        "alpha": 0.5,
+                        "beta": 0.5
+                    },
+                    "gap_policy": "skip"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::moving_avg("the_sum").model(MovingAverageModel::Linear),
+            json!({
+                "moving_avg": {
+                    "buckets_path": "the_sum",
+                    "model": "linear"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Given an ordered series of data, the Moving Function aggregation will slide a window across
+/// the data and allow the user to specify a custom script that is executed on each window of
+/// data. For convenience, a number of common functions are predefined such as min/max, moving
+/// averages, etc.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-movfn-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MovingFunctionAggregation {
+    moving_fn: MovingFunctionAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct MovingFunctionAggregationInner {
+    buckets_path: String,
+
+    window: u32,
+
+    script: Script,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    shift: Option<i32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`MovingFunctionAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric of interest
+    /// - `window` - the size of window to "slide" across the histogram
+    /// - `script` - the script that should be executed on each window of
+    /// data, e.g. `MovingFunctions.unweightedAvg(values)`
+    pub fn moving_fn<T>(buckets_path: T, window: u32, script: Script) -> MovingFunctionAggregation
+    where
+        T: ToString,
+    {
+        MovingFunctionAggregation {
+            moving_fn: MovingFunctionAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                window,
+                script,
+                shift: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl MovingFunctionAggregation {
+    /// Shift of window position, by default the window only includes the
+    /// buckets prior to the current one, a shift of `1` includes the
+    /// current bucket
+    pub fn shift(mut self, shift: i32) -> Self {
+        self.moving_fn.shift = Some(shift);
+        self
+    }
+
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.moving_fn.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl ShouldSkip for MovingFunctionAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("date")
+                .calendar_interval(CalendarInterval::Month)
+                .aggregate("the_sum", Aggregation::sum("price"))
+                .aggregate(
+                    "the_movfn",
+                    Aggregation::moving_fn(
+                        "the_sum",
+                        10,
+                        Script::source("MovingFunctions.unweightedAvg(values)"),
+                    )
+                    .shift(1)
+                    .gap_policy(GapPolicy::Skip),
+                ),
+            json!({
+                "date_histogram": {
+                    "field": "date",
+                    "calendar_interval": "month"
+                },
+                "aggs": {
+                    "the_sum": { "sum": { "field": "price" } },
+                    "the_movfn": {
+                        "moving_fn": {
+                            "buckets_path": "the_sum",
+                            "window": 10,
+                            "script": {
+                                "source": "MovingFunctions.unweightedAvg(values)"
+                            },
+                            "shift": 1,
+                            "gap_policy": "skip"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A parent pipeline aggregation which calculates the specific normalized/rescaled value for a
+/// specific bucket value. Values that cannot be normalized, will be skipped using the
+/// [skip gap policy](GapPolicy::Skip).
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-normalize-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NormalizeAggregation {
+    normalize: NormalizeAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct NormalizeAggregationInner {
+    buckets_path: String,
+
+    method: NormalizeMethod,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`NormalizeAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric to normalize
+    /// - `method` - the specific method to apply
+    pub fn normalize<T>(buckets_path: T, method: NormalizeMethod) -> NormalizeAggregation
+    where
+        T: ToString,
+    {
+        NormalizeAggregation {
+            normalize: NormalizeAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                method,
+                format: None,
+            },
+        }
+    }
+}
+
+impl NormalizeAggregation {
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.normalize.format = Some(format.to_string());
+        self
+    }
+}
+
+impl ShouldSkip for NormalizeAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::normalize("sales", NormalizeMethod::ZScore),
+            json!({
+                "normalize": {
+                    "buckets_path": "sales",
+                    "method": "z-score"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::date_histogram("date")
+                .calendar_interval(CalendarInterval::Month)
+                .aggregate("sales", Aggregation::sum("price"))
+                .aggregate(
+                    "percent_of_total_sales",
+                    Aggregation::normalize("sales", NormalizeMethod::PercentOfSum).format("00.00%"),
+                ),
+            json!({
+                "date_histogram": {
+                    "field": "date",
+                    "calendar_interval": "month"
+                },
+                "aggs": {
+                    "sales": { "sum": { "field": "price" } },
+                    "percent_of_total_sales": {
+                        "normalize": {
+                            "buckets_path": "sales",
+                            "method": "percent_of_sum",
+                            "format": "00.00%"
+                        }
+                    }
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::*;
+
+/// A sibling pipeline aggregation which calculates percentiles across all bucket of a specified
+/// metric in a sibling aggregation. The specified metric must be numeric and the sibling
+/// aggregation must be a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-percentiles-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PercentilesBucketAggregation {
+    percentiles_bucket: PercentilesBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct PercentilesBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    percents: Vec<f64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keyed: Option<bool>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`PercentilesBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the percentiles of
+    pub fn percentiles_bucket<T>(buckets_path: T) -> PercentilesBucketAggregation
+    where
+        T: ToString,
+    {
+        PercentilesBucketAggregation {
+            percentiles_bucket: PercentilesBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+                percents: Vec::new(),
+                keyed: None,
+            },
+        }
+    }
+}
+
+impl PercentilesBucketAggregation {
+    /// The list of percentiles to calculate, defaults to
+    /// `[ 1, 5, 25, 50, 75, 95, 99 ]`
+    pub fn percents<I>(mut self, percents: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<f64>,
+    {
+        self.percentiles_bucket.percents = percents.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Flag which returns the range as an hash instead of an array of
+    /// key-value pairs, defaults to `true`
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.percentiles_bucket.keyed = Some(keyed);
+        self
+    }
+
+    add_sibling_pipeline_methods!(percentiles_bucket);
+}
+
+impl ShouldSkip for PercentilesBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::percentiles_bucket("sales_per_month>sales"),
+            json!({
+                "percentiles_bucket": {
+                    "buckets_path": "sales_per_month>sales"
+                }
+            }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::percentiles_bucket("sales_per_month>sales")
+                .percents([25.0, 50.0, 75.0])
+                .keyed(false)
+                .format("0.0"),
+            json!({
+                "percentiles_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "format": "0.0",
+                    "percents": [25.0, 50.0, 75.0],
+                    "keyed": false
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Serial differencing is a technique where values in a time series are subtracted from itself
+/// at different time lags or periods. For example, the datapoint `f(x) = f(xt) - f(xt-n)`,
+/// where `n` is the period being used.
+///
+/// A period of 1 is equivalent to a derivative with no time normalization: it is simply the
+/// change from one point to the next. Single periods are useful for removing constant, linear
+/// trends.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-serialdiff-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SerialDiffAggregation {
+    serial_diff: SerialDiffAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SerialDiffAggregationInner {
+    buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    lag: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    gap_policy: Option<GapPolicy>,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SerialDiffAggregation`]
+    ///
+    /// - `buckets_path` - path to the metric of interest
+    pub fn serial_diff<T>(buckets_path: T) -> SerialDiffAggregation
+    where
+        T: ToString,
+    {
+        SerialDiffAggregation {
+            serial_diff: SerialDiffAggregationInner {
+                buckets_path: buckets_path.to_string(),
+                lag: None,
+                format: None,
+                gap_policy: None,
+            },
+        }
+    }
+}
+
+impl SerialDiffAggregation {
+    /// The historical bucket to subtract from the current value, e.g. a lag
+    /// of `7` subtracts the value of the bucket 7 buckets before, defaults
+    /// to `1`
+    pub fn lag(mut self, lag: u32) -> Self {
+        self.serial_diff.lag = Some(lag);
+        self
+    }
+
+    /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+    /// for the output value
+    pub fn format<T>(mut self, format: T) -> Self
+    where
+        T: ToString,
+    {
+        self.serial_diff.format = Some(format.to_string());
+        self
+    }
+
+    /// The policy to apply when gaps are found in the data
+    pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+        self.serial_diff.gap_policy = Some(gap_policy);
+        self
+    }
+}
+
+impl ShouldSkip for SerialDiffAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::serial_diff("the_sum"),
+            json!({ "serial_diff": { "buckets_path": "the_sum" } }),
+        );
+
+        assert_serialize_aggregation(
+            Aggregation::serial_diff("the_sum")
+                .lag(30)
+                .format("0.00")
+                .gap_policy(GapPolicy::KeepValues),
+            json!({
+                "serial_diff": {
+                    "buckets_path": "the_sum",
+                    "lag": 30,
+                    "format": "0.00",
+                    "gap_policy": "keep_values"
+                }
+            }),
+        );
+    }
+}
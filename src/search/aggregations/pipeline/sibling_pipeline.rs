@@ -0,0 +1,48 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Parameters shared by the sibling pipeline aggregations computing a metric
+/// across all the buckets of a sibling multi-bucket aggregation
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub(crate) struct SiblingPipeline {
+    pub(crate) buckets_path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) gap_policy: Option<GapPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub(crate) format: Option<String>,
+}
+
+impl SiblingPipeline {
+    pub(crate) fn new<T>(buckets_path: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            buckets_path: buckets_path.to_string(),
+            gap_policy: None,
+            format: None,
+        }
+    }
+}
+
+macro_rules! add_sibling_pipeline_methods {
+    ($root:ident) => {
+        /// The policy to apply when gaps are found in the data
+        pub fn gap_policy(mut self, gap_policy: GapPolicy) -> Self {
+            self.$root.pipeline.gap_policy = Some(gap_policy);
+            self
+        }
+
+        /// [DecimalFormat pattern](https://docs.oracle.com/en/java/javase/11/docs/api/java.base/java/text/DecimalFormat.html)
+        /// for the output value
+        pub fn format<T>(mut self, format: T) -> Self
+        where
+            T: ToString,
+        {
+            self.$root.pipeline.format = Some(format.to_string());
+            self
+        }
+    };
+}
@@ -0,0 +1,59 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which calculates a variety of stats across all bucket of a
+/// specified metric in a sibling aggregation. The specified metric must be numeric and the
+/// sibling aggregation must be a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-stats-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StatsBucketAggregation {
+    stats_bucket: StatsBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct StatsBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`StatsBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the stats of
+    pub fn stats_bucket<T>(buckets_path: T) -> StatsBucketAggregation
+    where
+        T: ToString,
+    {
+        StatsBucketAggregation {
+            stats_bucket: StatsBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+            },
+        }
+    }
+}
+
+impl StatsBucketAggregation {
+    add_sibling_pipeline_methods!(stats_bucket);
+}
+
+impl ShouldSkip for StatsBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::stats_bucket("sales_per_month>sales"),
+            json!({
+                "stats_bucket": {
+                    "buckets_path": "sales_per_month>sales"
+                }
+            }),
+        );
+    }
+}
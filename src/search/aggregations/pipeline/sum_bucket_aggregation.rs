@@ -0,0 +1,60 @@
+use super::SiblingPipeline;
+use crate::search::*;
+use crate::util::ShouldSkip;
+
+/// A sibling pipeline aggregation which calculates the sum of a specified metric across all
+/// buckets in a sibling aggregation. The specified metric must be numeric and the sibling
+/// aggregation must be a multi-bucket aggregation.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations-pipeline-sum-bucket-aggregation.html>
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SumBucketAggregation {
+    sum_bucket: SumBucketAggregationInner,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+struct SumBucketAggregationInner {
+    #[serde(flatten)]
+    pipeline: SiblingPipeline,
+}
+
+impl Aggregation {
+    /// Creates an instance of [`SumBucketAggregation`]
+    ///
+    /// - `buckets_path` - path to the buckets to find the sum of
+    pub fn sum_bucket<T>(buckets_path: T) -> SumBucketAggregation
+    where
+        T: ToString,
+    {
+        SumBucketAggregation {
+            sum_bucket: SumBucketAggregationInner {
+                pipeline: SiblingPipeline::new(buckets_path),
+            },
+        }
+    }
+}
+
+impl SumBucketAggregation {
+    add_sibling_pipeline_methods!(sum_bucket);
+}
+
+impl ShouldSkip for SumBucketAggregation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_aggregation(
+            Aggregation::sum_bucket("sales_per_month>sales").gap_policy(GapPolicy::InsertZeros),
+            json!({
+                "sum_bucket": {
+                    "buckets_path": "sales_per_month>sales",
+                    "gap_policy": "insert_zeros"
+                }
+            }),
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! You can use the `collapse` parameter to collapse search results based on field values.
+//! The collapsing is done by selecting only the top sorted document per collapse key.
+//!
+//! The field used for collapsing must be a single valued
+//! [`keyword`](https://www.elastic.co/guide/en/elasticsearch/reference/current/keyword.html) or
+//! [numeric](https://www.elastic.co/guide/en/elasticsearch/reference/current/number.html) field
+//! with [`doc_values`](https://www.elastic.co/guide/en/elasticsearch/reference/current/doc-values.html)
+//! activated.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/collapse-search-results.html>
+
+use crate::search::*;
+use crate::util::*;
+
+/// Collapses search results based on field values
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/collapse-search-results.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Collapse {
+    field: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Vec<InnerHits>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_concurrent_group_searches: Option<u64>,
+}
+
+impl<T> From<T> for Collapse
+where
+    T: ToString,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Collapse {
+    /// Creates an instance of [`Collapse`]
+    ///
+    /// - `field` - Field to collapse the result set on
+    pub fn new<T>(field: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            field: field.to_string(),
+            inner_hits: Default::default(),
+            max_concurrent_group_searches: None,
+        }
+    }
+
+    /// It is also possible to expand each collapsed top hits with the
+    /// [`inner_hits`](https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html)
+    /// option.
+    ///
+    /// Multiple [`InnerHits`] can be requested for each collapsed hit, which
+    /// is useful when you want to get multiple representations of the
+    /// collapsed hits. Each one of them must have a distinct
+    /// [name](InnerHits::name).
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits.push(inner_hits);
+        self
+    }
+
+    /// The expansion of the group is done by sending an additional query for
+    /// each inner hit request for each collapsed hit returned in the response.
+    /// This can significantly slow things down if you have too many groups
+    /// and/or inner hit requests.
+    ///
+    /// The `max_concurrent_group_searches` request parameter can be used to
+    /// control the maximum number of concurrent searches allowed in this
+    /// phase. The default is based on the number of data nodes and the default
+    /// search thread pool size.
+    pub fn max_concurrent_group_searches(mut self, max_concurrent_group_searches: u64) -> Self {
+        self.max_concurrent_group_searches = Some(max_concurrent_group_searches);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Collapse::new("user.id"), json!({ "field": "user.id" }));
+
+        assert_serialize(
+            Collapse::new("user.id")
+                .inner_hits(
+                    InnerHits::new()
+                        .name("largest_responses")
+                        .size(3)
+                        .sort(FieldSort::descending("http.response.bytes")),
+                )
+                .inner_hits(
+                    InnerHits::new()
+                        .name("most_recent")
+                        .size(3)
+                        .sort(FieldSort::descending("@timestamp")),
+                )
+                .max_concurrent_group_searches(4),
+            json!({
+                "field": "user.id",
+                "inner_hits": [
+                    {
+                        "name": "largest_responses",
+                        "size": 3,
+                        "sort": [
+                            { "http.response.bytes": { "order": "desc" } }
+                        ]
+                    },
+                    {
+                        "name": "most_recent",
+                        "size": 3,
+                        "sort": [
+                            { "@timestamp": { "order": "desc" } }
+                        ]
+                    }
+                ],
+                "max_concurrent_group_searches": 4
+            }),
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// Specifies how to break the highlighted fragments. Defaults to
@@ -118,6 +119,46 @@ impl Serialize for FvhBoundaryScanner {
     }
 }
 
+#[derive(Deserialize)]
+struct BoundaryScannerRepr {
+    boundary_scanner: String,
+    boundary_scanner_locale: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for UnifiedBoundaryScanner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = BoundaryScannerRepr::deserialize(deserializer)?;
+
+        match (repr.boundary_scanner.as_str(), repr.boundary_scanner_locale) {
+            ("sentence", locale) => Ok(Self::Sentence(locale)),
+            ("word", locale) => Ok(Self::Word(locale)),
+            (other, _) => Err(D::Error::unknown_variant(other, &["sentence", "word"])),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FvhBoundaryScanner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = BoundaryScannerRepr::deserialize(deserializer)?;
+
+        match (repr.boundary_scanner.as_str(), repr.boundary_scanner_locale) {
+            ("chars", None) => Ok(Self::Chars),
+            ("sentence", locale) => Ok(Self::Sentence(locale)),
+            ("word", locale) => Ok(Self::Word(locale)),
+            (other, _) => Err(D::Error::unknown_variant(
+                other,
+                &["chars", "sentence", "word"],
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
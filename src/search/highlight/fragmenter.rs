@@ -1,5 +1,5 @@
 /// Specifies how text should be broken up in highlight snippets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Fragmenter {
     /// Breaks up text into same-sized fragments.
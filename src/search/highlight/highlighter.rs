@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use serde::{Deserialize, Deserializer};
 
 /// Highlighter settings
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -19,7 +20,8 @@ pub enum Highlighter {
 }
 
 /// Highlighting settings can be set on a global level and overridden at the field level
-#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DefaultHighlighter {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boundary_chars: Option<String>,
@@ -80,7 +82,8 @@ pub struct DefaultHighlighter {
 /// try an alternative highlighter, such as the `unified` highlighter.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/highlighting.html#fast-vector-highlighter>
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FastVectorHighlighter {
     // Common
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -123,6 +126,7 @@ pub struct FastVectorHighlighter {
     tags: Option<Tags>,
 
     // Highlighter specific
+    #[serde(skip_deserializing)]
     r#type: &'static str,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -152,7 +156,8 @@ pub struct FastVectorHighlighter {
 /// fields.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/highlighting.html#plain-highlighter>
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PlainHighlighter {
     // Common
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -195,6 +200,7 @@ pub struct PlainHighlighter {
     tags: Option<Tags>,
 
     // Highlighter specific
+    #[serde(skip_deserializing)]
     r#type: &'static str,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -207,7 +213,8 @@ pub struct PlainHighlighter {
 /// highlighting. This is the default highlighter.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/highlighting.html#unified-highlighter>
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UnifiedHighlighter {
     // Common
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -250,12 +257,49 @@ pub struct UnifiedHighlighter {
     tags: Option<Tags>,
 
     // Highlighter specific
+    #[serde(skip_deserializing)]
     r#type: &'static str,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", flatten)]
     boundary_scanner: Option<UnifiedBoundaryScanner>,
 }
 
+impl<'de> Deserialize<'de> for Highlighter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let mut map = serde_json::Map::deserialize(deserializer)?;
+        let r#type = match map.remove("type") {
+            Some(serde_json::Value::String(r#type)) => Some(r#type),
+            Some(value) => {
+                return Err(D::Error::custom(format!(
+                    "invalid highlighter type: {}",
+                    value
+                )))
+            }
+            None => None,
+        };
+        let value = serde_json::Value::Object(map);
+
+        match r#type.as_deref() {
+            None => DefaultHighlighter::deserialize(value).map(Self::Default),
+            Some("fvh") => FastVectorHighlighter::deserialize(value).map(Self::Fvh),
+            Some("plain") => PlainHighlighter::deserialize(value).map(Self::Plain),
+            Some("unified") => UnifiedHighlighter::deserialize(value).map(Self::Unified),
+            Some(other) => {
+                return Err(D::Error::unknown_variant(
+                    other,
+                    &["fvh", "plain", "unified"],
+                ))
+            }
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
 impl Default for Highlighter {
     fn default() -> Self {
         Self::Default(Default::default())
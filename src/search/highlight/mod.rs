@@ -57,11 +57,11 @@ pub use self::order::*;
 pub use self::tags::*;
 
 /// Highlight structure
-#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Highlight {
     #[serde(flatten, skip_serializing_if = "ShouldSkip::should_skip")]
     highlighter: Option<Highlighter>,
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Vec<KeyValuePair<String, Highlighter>>,
 }
 
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// Set to `styled` to use the built-in tag schema or use custom tags
@@ -18,7 +19,7 @@ pub enum Tags {
 }
 
 /// Contains `pre_tags` and `post_tags` highlighting values
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct PrePostTags {
     pre_tags: Vec<String>,
     post_tags: Vec<String>,
@@ -119,6 +120,28 @@ impl Serialize for Tags {
     }
 }
 
+impl<'de> Deserialize<'de> for Tags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Schema { tags_schema: String },
+            Custom(PrePostTags),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Schema { tags_schema } if tags_schema == "styled" => Ok(Self::Styled),
+            Repr::Schema { tags_schema } => {
+                Err(D::Error::unknown_variant(&tags_schema, &["styled"]))
+            }
+            Repr::Custom(tags) => Ok(Self::Custom(tags)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
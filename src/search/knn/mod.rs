@@ -0,0 +1,297 @@
+//! A k-nearest neighbor (kNN) search finds the `k` nearest vectors to a query
+//! vector, as measured by a similarity metric.
+//!
+//! The top-level `knn` section runs an approximate kNN search against
+//! [`dense_vector`](https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html)
+//! fields and can be combined with a regular `query`, in which case the
+//! scores of both are summed (hybrid search).
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html>
+
+use crate::search::*;
+use crate::util::*;
+use serde::{Serialize, Serializer};
+
+/// Approximate k-nearest neighbor search clause
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html#approximate-knn>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Knn {
+    field: String,
+
+    query_vector: Vec<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    k: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    num_candidates: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    similarity: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<InnerHits>,
+}
+
+impl Knn {
+    /// Creates an instance of [`Knn`]
+    ///
+    /// - `field` - The name of the vector field to search against. Must be a
+    /// [`dense_vector` field with indexing enabled](https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html#index-vectors-knn-search)
+    /// - `query_vector` - Query vector. Must have the same number of
+    /// dimensions as the vector field you are searching against
+    pub fn new<T, U>(field: T, query_vector: U) -> Self
+    where
+        T: ToString,
+        U: IntoIterator,
+        U::Item: Into<f32>,
+    {
+        Self {
+            field: field.to_string(),
+            query_vector: query_vector.into_iter().map(Into::into).collect(),
+            k: None,
+            num_candidates: None,
+            filter: None,
+            similarity: None,
+            boost: None,
+            inner_hits: None,
+        }
+    }
+
+    /// Number of nearest neighbors to return as top hits. This value must be
+    /// less than or equal to `num_candidates`.
+    pub fn k(mut self, k: u64) -> Self {
+        self.k = Some(k);
+        self
+    }
+
+    /// The number of nearest neighbor candidates to consider per shard.
+    /// Increasing `num_candidates` tends to improve the accuracy of the final
+    /// `k` results.
+    pub fn num_candidates(mut self, num_candidates: u64) -> Self {
+        self.num_candidates = Some(num_candidates);
+        self
+    }
+
+    /// Query to filter the documents that can match. The kNN search will
+    /// return the top `k` documents that also match this filter.
+    ///
+    /// Skippable queries, e.g. empty `terms` query, leave the filter unset.
+    pub fn filter<Q>(mut self, filter: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.filter = Some(filter.into()).filter(ShouldSkip::should_keep);
+        self
+    }
+
+    /// The minimum similarity for a vector to be considered a match
+    pub fn similarity(mut self, similarity: f32) -> Self {
+        self.similarity = Some(similarity);
+        self
+    }
+
+    /// Floating point number used to weight the kNN scores against the
+    /// scores of the `query` when running a hybrid search
+    pub fn boost<T>(mut self, boost: T) -> Self
+    where
+        T: num_traits::AsPrimitive<f32>,
+    {
+        self.boost = Some(boost.as_());
+        self
+    }
+
+    /// Retrieves the nested vectors that matched when searching against a
+    /// `dense_vector` field inside a
+    /// [`nested`](https://www.elastic.co/guide/en/elasticsearch/reference/current/nested.html)
+    /// object
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html#nested-knn-search-inner-hits>
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits = Some(inner_hits);
+        self
+    }
+}
+
+impl IntoIterator for Knn {
+    type Item = Self;
+
+    type IntoIter = std::option::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Some(self).into_iter()
+    }
+}
+
+impl Validate for Knn {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if let (Some(k), Some(num_candidates)) = (self.k, self.num_candidates) {
+            if k > num_candidates {
+                errors.push("", "`k` cannot be greater than `num_candidates`");
+            }
+        }
+
+        errors.nested("filter", &self.filter);
+        errors.into_result()
+    }
+}
+
+/// Serializes a single clause as an object and multiple clauses as an array
+pub(crate) fn serialize_knn<S>(knn: &[Knn], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match knn {
+        [knn] => knn.serialize(serializer),
+        knn => knn.serialize(serializer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            Knn::new("image-vector", [0.3, 0.1, 1.2]),
+            json!({
+                "field": "image-vector",
+                "query_vector": [0.3, 0.1, 1.2]
+            }),
+        );
+
+        assert_serialize(
+            Knn::new("image-vector", [0.3, 0.1, 1.2])
+                .k(10)
+                .num_candidates(100)
+                .filter(Query::term("file-type", "png"))
+                .similarity(0.5)
+                .boost(2)
+                .inner_hits(InnerHits::new().size(3)),
+            json!({
+                "field": "image-vector",
+                "query_vector": [0.3, 0.1, 1.2],
+                "k": 10,
+                "num_candidates": 100,
+                "filter": { "term": { "file-type": { "value": "png" } } },
+                "similarity": 0.5,
+                "boost": 2.0,
+                "inner_hits": { "size": 3 }
+            }),
+        );
+    }
+
+    #[test]
+    fn search_serialization() {
+        assert_serialize(
+            Search::new().knn(
+                Knn::new("image-vector", [54.0, 10.0, -2.0])
+                    .k(5)
+                    .num_candidates(50),
+            ),
+            json!({
+                "knn": {
+                    "field": "image-vector",
+                    "query_vector": [54.0, 10.0, -2.0],
+                    "k": 5,
+                    "num_candidates": 50
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .knn(
+                    Knn::new("image-vector", [54.0, 10.0, -2.0])
+                        .k(5)
+                        .num_candidates(50)
+                        .boost(0.1),
+                )
+                .knn(
+                    Knn::new("title-vector", [1.0, 20.0, -52.0, 23.0, 10.0])
+                        .k(10)
+                        .num_candidates(10)
+                        .boost(0.5),
+                ),
+            json!({
+                "knn": [
+                    {
+                        "field": "image-vector",
+                        "query_vector": [54.0, 10.0, -2.0],
+                        "k": 5,
+                        "num_candidates": 50,
+                        "boost": 0.1
+                    },
+                    {
+                        "field": "title-vector",
+                        "query_vector": [1.0, 20.0, -52.0, 23.0, 10.0],
+                        "k": 10,
+                        "num_candidates": 10,
+                        "boost": 0.5
+                    }
+                ]
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::r#match("title", "mountain lake").boost(0.9))
+                .knn(
+                    Knn::new("image-vector", [54.0, 10.0, -2.0])
+                        .k(5)
+                        .num_candidates(50)
+                        .boost(0.1),
+                )
+                .size(10),
+            json!({
+                "query": {
+                    "match": {
+                        "title": {
+                            "query": "mountain lake",
+                            "boost": 0.9
+                        }
+                    }
+                },
+                "knn": {
+                    "field": "image-vector",
+                    "query_vector": [54.0, 10.0, -2.0],
+                    "k": 5,
+                    "num_candidates": 50,
+                    "boost": 0.1
+                },
+                "size": 10
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Search::new()
+                .knn(Knn::new("image-vector", [1.0]).k(10).num_candidates(100))
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Search::new()
+                .knn(Knn::new("image-vector", [1.0]).k(10).num_candidates(100))
+                .knn(Knn::new("title-vector", [1.0]).k(10).num_candidates(5))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "knn[1]",
+                "`k` cannot be greater than `num_candidates`"
+            )])
+        );
+    }
+}
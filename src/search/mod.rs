@@ -14,7 +14,9 @@ mod response;
 
 // Public modules
 pub mod aggregations;
+pub mod collapse;
 pub mod highlight;
+pub mod knn;
 pub mod params;
 pub mod queries;
 pub mod request;
@@ -23,10 +25,13 @@ pub mod runtime_mappings;
 pub mod script_fields;
 pub mod sort;
 pub mod suggesters;
+pub mod validation;
 
 // Public re-exports
 pub use self::aggregations::*;
+pub use self::collapse::*;
 pub use self::highlight::*;
+pub use self::knn::*;
 pub use self::params::*;
 pub use self::queries::params::*;
 pub use self::queries::*;
@@ -37,3 +42,4 @@ pub use self::runtime_mappings::*;
 pub use self::script_fields::*;
 pub use self::sort::*;
 pub use self::suggesters::*;
+pub use self::validation::*;
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::{fmt::Display, str::FromStr};
 
 /// Represents a point in two dimensional space
@@ -30,6 +30,15 @@ impl Serialize for Coordinate {
     }
 }
 
+impl<'de> Deserialize<'de> for Coordinate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[f32; 2]>::deserialize(deserializer).map(Self::from)
+    }
+}
+
 impl From<[f32; 2]> for Coordinate {
     fn from(value: [f32; 2]) -> Self {
         Self {
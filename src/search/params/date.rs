@@ -5,7 +5,7 @@ use std::time::SystemTime;
 pub type ChronoTime = DateTime<Utc>;
 
 /// Time variants to serialize
-#[derive(Clone, Copy, Serialize)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Date {
     /// System time
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Represents a point in two dimensional space
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -15,6 +15,14 @@ impl GeoLocation {
             longitude,
         }
     }
+
+    /// Coordinates in `[lon, lat]` order widened to `f64`, keeping the
+    /// shortest decimal representation of each `f32` value
+    pub(crate) fn coordinates(&self) -> [f64; 2] {
+        let widen = |value: f32| value.to_string().parse().unwrap_or(value as f64);
+
+        [widen(self.longitude), widen(self.latitude)]
+    }
 }
 
 impl Serialize for GeoLocation {
@@ -26,6 +34,15 @@ impl Serialize for GeoLocation {
     }
 }
 
+impl<'de> Deserialize<'de> for GeoLocation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[f32; 2]>::deserialize(deserializer).map(Self::from)
+    }
+}
+
 impl From<[f32; 2]> for GeoLocation {
     fn from(value: [f32; 2]) -> Self {
         Self {
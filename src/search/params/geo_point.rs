@@ -0,0 +1,248 @@
+use crate::search::GeoLocation;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+/// A [geo_point](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
+/// value in any of the formats accepted by Elasticsearch.
+///
+/// Beware that the [array](GeoPoint::Array) and [WKT](GeoPoint::Wkt) formats
+/// list the longitude first, while the [object](GeoPoint::Object) and
+/// [text](GeoPoint::Text) formats list the latitude first.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoPoint {
+    /// An object with `lat` and `lon` keys, e.g. `{ "lat": 41.12, "lon": -71.34 }`
+    Object {
+        /// Latitude
+        lat: f64,
+        /// Longitude
+        lon: f64,
+    },
+
+    /// A string in `"lat,lon"` format, e.g. `"41.12,-71.34"`
+    Text {
+        /// Latitude
+        lat: f64,
+        /// Longitude
+        lon: f64,
+    },
+
+    /// A geohash, e.g. `"drm3btev3e86"`
+    Geohash(String),
+
+    /// An array in `[lon, lat]` format, following the GeoJSON order, e.g. `[-71.34, 41.12]`
+    Array {
+        /// Longitude
+        lon: f64,
+        /// Latitude
+        lat: f64,
+    },
+
+    /// A [Well-Known Text](https://docs.opengeospatial.org/is/12-063r5/12-063r5.html)
+    /// point in `POINT (lon lat)` format, e.g. `"POINT (-71.34 41.12)"`
+    Wkt {
+        /// Longitude
+        lon: f64,
+        /// Latitude
+        lat: f64,
+    },
+}
+
+impl GeoPoint {
+    /// Creates an instance of [`GeoPoint`] in object format
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self::Object { lat, lon }
+    }
+
+    /// Creates an instance of [`GeoPoint`] from a geohash
+    pub fn geohash<T>(geohash: T) -> Self
+    where
+        T: ToString,
+    {
+        Self::Geohash(geohash.to_string())
+    }
+}
+
+impl Serialize for GeoPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Object {
+            lat: f64,
+            lon: f64,
+        }
+
+        match self {
+            Self::Object { lat, lon } => Object {
+                lat: *lat,
+                lon: *lon,
+            }
+            .serialize(serializer),
+            Self::Text { lat, lon } => serializer.collect_str(&format_args!("{lat},{lon}")),
+            Self::Geohash(geohash) => geohash.serialize(serializer),
+            Self::Array { lon, lat } => [lon, lat].serialize(serializer),
+            Self::Wkt { lon, lat } => serializer.collect_str(&format_args!("POINT ({lon} {lat})")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Object { lat: f64, lon: f64 },
+            Array([f64; 2]),
+            String(String),
+        }
+
+        let value = match Repr::deserialize(deserializer)? {
+            Repr::Object { lat, lon } => return Ok(Self::Object { lat, lon }),
+            Repr::Array([lon, lat]) => return Ok(Self::Array { lon, lat }),
+            Repr::String(value) => value,
+        };
+
+        let trimmed = value.trim();
+        let coordinates = |value: &str, separator: char| {
+            let (first, second) = value.split_once(separator)?;
+
+            Some((first.trim().parse().ok()?, second.trim().parse().ok()?))
+        };
+
+        if trimmed
+            .get(..5)
+            .map_or(false, |prefix| prefix.eq_ignore_ascii_case("POINT"))
+        {
+            return trimmed[5..]
+                .trim()
+                .strip_prefix('(')
+                .and_then(|value| value.strip_suffix(')'))
+                .and_then(|value| coordinates(value.trim(), ' '))
+                .map(|(lon, lat)| Self::Wkt { lon, lat })
+                .ok_or_else(|| D::Error::custom(format!("invalid WKT point `{value}`")));
+        }
+
+        if trimmed.contains(',') {
+            return coordinates(trimmed, ',')
+                .map(|(lat, lon)| Self::Text { lat, lon })
+                .ok_or_else(|| D::Error::custom(format!("invalid geo point `{value}`")));
+        }
+
+        Ok(Self::Geohash(value))
+    }
+}
+
+/// Treats the tuple as `(lat, lon)` and creates the object format
+impl From<(f64, f64)> for GeoPoint {
+    fn from((lat, lon): (f64, f64)) -> Self {
+        Self::Object { lat, lon }
+    }
+}
+
+/// Keeps the `[lon, lat]` array format of [`GeoLocation`]
+impl From<GeoLocation> for GeoPoint {
+    fn from(value: GeoLocation) -> Self {
+        let [lon, lat] = value.coordinates();
+
+        Self::Array { lon, lat }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            GeoPoint::new(40.0, -70.0),
+            json!({ "lat": 40.0, "lon": -70.0 }),
+        );
+        assert_serialize(
+            GeoPoint::from((40.5, -70.25)),
+            json!({ "lat": 40.5, "lon": -70.25 }),
+        );
+        assert_serialize(
+            GeoPoint::Text {
+                lat: 40.5,
+                lon: -70.25,
+            },
+            json!("40.5,-70.25"),
+        );
+        assert_serialize(GeoPoint::geohash("drm3btev3e86"), json!("drm3btev3e86"));
+        assert_serialize(
+            GeoPoint::Wkt {
+                lon: -70.25,
+                lat: 40.5,
+            },
+            json!("POINT (-70.25 40.5)"),
+        );
+    }
+
+    #[test]
+    fn array_format_lists_longitude_first() {
+        assert_serialize(
+            GeoPoint::Array {
+                lat: 40.5,
+                lon: -70.25,
+            },
+            json!([-70.25, 40.5]),
+        );
+
+        assert_eq!(
+            serde_json::from_value::<GeoPoint>(json!([-70.25, 40.5])).unwrap(),
+            GeoPoint::Array {
+                lon: -70.25,
+                lat: 40.5,
+            }
+        );
+
+        assert_serialize(
+            GeoPoint::from(GeoLocation::new(40.73, -74.1)),
+            json!([-74.1, 40.73]),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let result: Vec<GeoPoint> = serde_json::from_value(json!([
+            { "lat": 40.5, "lon": -70.25 },
+            "40.5,-70.25",
+            "drm3btev3e86",
+            [-70.25, 40.5],
+            "POINT (-70.25 40.5)",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            result,
+            [
+                GeoPoint::Object {
+                    lat: 40.5,
+                    lon: -70.25
+                },
+                GeoPoint::Text {
+                    lat: 40.5,
+                    lon: -70.25
+                },
+                GeoPoint::geohash("drm3btev3e86"),
+                GeoPoint::Array {
+                    lon: -70.25,
+                    lat: 40.5
+                },
+                GeoPoint::Wkt {
+                    lon: -70.25,
+                    lat: 40.5
+                },
+            ]
+        );
+
+        assert!(serde_json::from_value::<GeoPoint>(json!("POINT (-70.25)")).is_err());
+        assert!(serde_json::from_value::<GeoPoint>(json!("40.5,abc")).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::search::*;
 use crate::util::*;
-use crate::Set;
+use crate::{Map, Set};
 
 /// The [parent-join](https://www.elastic.co/guide/en/elasticsearch/reference/current/parent-join.html)
 /// and [nested](https://www.elastic.co/guide/en/elasticsearch/reference/current/nested.html)
@@ -20,8 +20,11 @@ use crate::Set;
 /// or `has_parent` query and filter.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html>
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InnerHits {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    name: Option<String>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     _source: Option<SourceFilter>,
 
@@ -31,14 +34,23 @@ pub struct InnerHits {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     size: Option<u64>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     sort: SortCollection,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     highlight: Option<Highlight>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     docvalue_fields: Set<String>,
+
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    script_fields: Map<String, ScriptField>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<bool>,
 }
 
 impl InnerHits {
@@ -47,6 +59,20 @@ impl InnerHits {
         Default::default()
     }
 
+    /// The name to be used for the particular inner hit definition in the
+    /// response. Useful when multiple inner hits have been defined in a single
+    /// search request. The default depends in which query the inner hit is
+    /// defined. For `has_child` query and filter this is the child type,
+    /// `has_parent` query and filter this is the parent type and the nested
+    /// query and filter this is the nested path.
+    pub fn name<T>(mut self, name: T) -> Self
+    where
+        T: ToString,
+    {
+        self.name = Some(name.to_string());
+        self
+    }
+
     /// Indicates which source fields are returned for matching documents
     pub fn source<T>(mut self, source: T) -> Self
     where
@@ -101,4 +127,65 @@ impl InnerHits {
             .extend(docvalue_fields.into_iter().map(|x| x.to_string()));
         self
     }
+
+    /// Add script fields to the inner hits
+    pub fn script_fields<S, T>(mut self, name: S, script: T) -> Self
+    where
+        S: ToString,
+        T: Into<ScriptField>,
+    {
+        let _ = self.script_fields.insert(name.to_string(), script.into());
+        self
+    }
+
+    /// If `true`, returns detailed information about score computation as
+    /// part of each inner hit
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.explain = Some(explain);
+        self
+    }
+
+    /// If `true`, returns document version as part of each inner hit
+    pub fn version(mut self, version: bool) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(InnerHits::new(), json!({}));
+
+        assert_serialize(
+            InnerHits::new()
+                .name("comments")
+                .source(false)
+                .from(1)
+                .size(2)
+                .sort(FieldSort::descending("comments.date"))
+                .highlight(Highlight::new().field("comments.text"))
+                .docvalue_fields(["comments.author"])
+                .script_fields("votes", Script::source("doc['comments.votes'].value * 2"))
+                .explain(true)
+                .version(true),
+            json!({
+                "name": "comments",
+                "_source": false,
+                "from": 1,
+                "size": 2,
+                "sort": [{ "comments.date": { "order": "desc" } }],
+                "highlight": { "fields": [{ "comments.text": {} }] },
+                "docvalue_fields": ["comments.author"],
+                "script_fields": {
+                    "votes": { "script": { "source": "doc['comments.votes'].value * 2" } }
+                },
+                "explain": true,
+                "version": true
+            }),
+        );
+    }
 }
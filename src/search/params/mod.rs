@@ -4,12 +4,17 @@ mod coordinate;
 mod date;
 mod geo_distance_type;
 mod geo_location;
+mod geo_point;
 mod geo_shape;
+mod inner_hits;
 mod number;
+mod point_in_time;
 mod score_mode;
+mod script;
 mod script_sort_type;
 mod search_filter;
 mod shape;
+mod slice;
 mod term;
 mod terms;
 mod text;
@@ -20,12 +25,17 @@ pub use self::coordinate::*;
 pub use self::date::*;
 pub use self::geo_distance_type::*;
 pub use self::geo_location::*;
+pub use self::geo_point::*;
 pub use self::geo_shape::*;
+pub use self::inner_hits::*;
 pub use self::number::*;
+pub use self::point_in_time::*;
 pub use self::score_mode::*;
+pub use self::script::*;
 pub use self::script_sort_type::*;
 pub use self::search_filter::*;
 pub use self::shape::*;
+pub use self::slice::*;
 pub use self::term::*;
 pub use self::terms::*;
 pub use self::text::*;
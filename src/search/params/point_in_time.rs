@@ -0,0 +1,101 @@
+use crate::search::*;
+use crate::util::*;
+
+/// A point in time (PIT) is a lightweight view into the state of the data as it existed when
+/// initiated. Searching with a PIT preserves the index state across requests, which makes it the
+/// recommended companion of `search_after` for deep pagination.
+///
+/// A search request with the `pit` parameter must not specify `index`, `routing`, or
+/// `preference` as these parameters are copied from the point in time.
+///
+/// Rather than taking the id and keep alive together, the keep alive is set with the
+/// [`keep_alive`](Self::keep_alive) builder and the point in time is passed to
+/// [`Search::pit`], which also accepts a bare id.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/point-in-time-api.html>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PointInTime {
+    id: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    keep_alive: Option<Time>,
+}
+
+impl<T> From<T> for PointInTime
+where
+    T: ToString,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl PointInTime {
+    /// Creates an instance of [`PointInTime`]
+    ///
+    /// - `id` - Point in time id returned by the open point in time API
+    pub fn new<T>(id: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            id: id.to_string(),
+            keep_alive: None,
+        }
+    }
+
+    /// Extends the time to live of the point in time. The value doesn't need to be long enough
+    /// to process all data, it just needs to be long enough for the next request.
+    ///
+    /// Takes a [`Time`] rather than a raw string, so both `Time::Minutes(1)` and `"1m"` are
+    /// accepted.
+    pub fn keep_alive<T>(mut self, keep_alive: T) -> Self
+    where
+        T: Into<Time>,
+    {
+        self.keep_alive = Some(keep_alive.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            PointInTime::new("46ToAwMDaWR5"),
+            json!({ "id": "46ToAwMDaWR5" }),
+        );
+
+        assert_serialize(
+            PointInTime::new("46ToAwMDaWR5").keep_alive(Time::Minutes(1)),
+            json!({ "id": "46ToAwMDaWR5", "keep_alive": "1m" }),
+        );
+
+        assert_serialize(
+            PointInTime::new("46ToAwMDaWR5").keep_alive("5m"),
+            json!({ "id": "46ToAwMDaWR5", "keep_alive": "5m" }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .size(10_000)
+                .query(Query::match_all())
+                .pit(PointInTime::new("46ToAwMDaWR5").keep_alive(Time::Minutes(1)))
+                .sort(FieldSort::ascending("@timestamp"))
+                .search_after(vec![
+                    json!("2021-05-20T05:30:04.832Z"),
+                    json!(4294967298u64),
+                ]),
+            json!({
+                "size": 10000,
+                "query": { "match_all": {} },
+                "pit": { "id": "46ToAwMDaWR5", "keep_alive": "1m" },
+                "sort": [ { "@timestamp": { "order": "asc" } } ],
+                "search_after": ["2021-05-20T05:30:04.832Z", 4294967298u64]
+            }),
+        );
+    }
+}
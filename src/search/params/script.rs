@@ -8,14 +8,14 @@
 //! <https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting.html>
 
 use crate::{util::*, Map};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Wherever scripting is supported in the Elasticsearch APIs, the syntax follows the same pattern;
 /// you specify the language of your script, provide the script logic (or source, and add parameters
 /// that are passed into the script.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Script {
     #[serde(flatten)]
     source: ScriptSource,
@@ -23,7 +23,7 @@ pub struct Script {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     lang: Option<ScriptLang>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     params: Map<String, serde_json::Value>,
 }
 
@@ -31,7 +31,7 @@ pub struct Script {
 /// `id` for a stored script. Use the
 /// [stored script APIs](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html#prefer-params)
 /// to create and manage stored scripts.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ScriptSource {
     /// Inline script
@@ -42,6 +42,22 @@ pub enum ScriptSource {
 }
 
 impl Script {
+    /// Creates an instance of inlined [`Script`], an alias of [`Script::source`]
+    pub fn inline<S>(source: S) -> Self
+    where
+        S: ToString,
+    {
+        Self::source(source)
+    }
+
+    /// Creates an instance of stored [`Script`], an alias of [`Script::id`]
+    pub fn stored<S>(id: S) -> Self
+    where
+        S: ToString,
+    {
+        Self::id(id)
+    }
+
     /// Creates an instance of inlined [`Script`]
     pub fn source<S>(source: S) -> Self
     where
@@ -88,6 +104,16 @@ impl Script {
         self
     }
 }
+
+impl ShouldSkip for Script {
+    fn should_skip(&self) -> bool {
+        match &self.source {
+            ScriptSource::Source(source) => source.should_skip(),
+            ScriptSource::Id(id) => id.should_skip(),
+        }
+    }
+}
+
 /// Available scripting language
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScriptLang {
@@ -144,6 +170,15 @@ impl Serialize for ScriptLang {
     }
 }
 
+impl<'de> Deserialize<'de> for ScriptLang {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
 impl<T> From<T> for ScriptLang
 where
     T: ToString,
@@ -192,6 +227,19 @@ mod tests {
             }),
         );
 
+        assert_serialize(
+            Script::inline("doc['my_field'].value"),
+            json!({ "source": "doc['my_field'].value" }),
+        );
+
+        assert_serialize(
+            Script::stored("calculate-score").param("my_modifier", 2),
+            json!({
+                "id": "calculate-score",
+                "params": { "my_modifier": 2 }
+            }),
+        );
+
         assert_serialize(
             Script::id(123).param("multiplier", [1, 2, 3]),
             json!({
@@ -202,4 +250,12 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Script::inline("").should_skip());
+        assert!(Script::stored(" ").should_skip());
+        assert!(!Script::inline("_score").should_skip());
+        assert!(!Script::stored("calculate-score").should_skip());
+    }
 }
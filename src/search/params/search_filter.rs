@@ -1,3 +1,4 @@
+use crate::util::*;
 use std::borrow::Cow;
 
 /// Control how the `_source` field is returned with every hit.
@@ -20,14 +21,67 @@ pub enum SourceFilter {
     /// and should not be returned
     IncludesExcludes {
         /// A collection of wildcard patterns to control what parts of `_source` should be returned
+        #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
         includes: Vec<String>,
 
         /// A collection of wildcard patterns to control what parts of `_source` should not be
         /// returned
+        #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
         excludes: Vec<String>,
     },
 }
 
+impl SourceFilter {
+    /// Creates an instance of [`SourceFilter`] that returns only the parts of
+    /// `_source` matching the given wildcard patterns
+    pub fn includes<T>(includes: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        SourceFilter::IncludesExcludes {
+            includes: includes.into_iter().map(|x| x.to_string()).collect(),
+            excludes: Vec::new(),
+        }
+    }
+
+    /// Excludes the parts of `_source` matching the given wildcard patterns
+    /// from the returned fields
+    pub fn excludes<T>(self, excludes: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: ToString,
+    {
+        let excludes = excludes.into_iter().map(|x| x.to_string());
+
+        match self {
+            SourceFilter::Enable(_) => SourceFilter::IncludesExcludes {
+                includes: Vec::new(),
+                excludes: excludes.collect(),
+            },
+            SourceFilter::Include(include) => SourceFilter::IncludesExcludes {
+                includes: vec![include],
+                excludes: excludes.collect(),
+            },
+            SourceFilter::Includes(includes) => SourceFilter::IncludesExcludes {
+                includes,
+                excludes: excludes.collect(),
+            },
+            SourceFilter::IncludesExcludes {
+                includes,
+                excludes: mut existing,
+            } => {
+                existing.extend(excludes);
+
+                SourceFilter::IncludesExcludes {
+                    includes,
+                    excludes: existing,
+                }
+            }
+        }
+    }
+}
+
 // -- Boolean
 
 impl From<bool> for SourceFilter {
@@ -149,7 +203,12 @@ impl<const M: usize, const N: usize> From<([Cow<'_, str>; M], [Cow<'_, str>; N])
 
 #[cfg(test)]
 mod tests {
-    use crate::{util::assert_serialize, Search};
+    use crate::{util::assert_serialize, Search, SourceFilter};
+
+    #[test]
+    fn skips_when_unset() {
+        assert_serialize(Search::new(), json!({}));
+    }
 
     #[test]
     fn adds_boolean() {
@@ -183,4 +242,36 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn adds_includes_excludes_builder() {
+        assert_serialize(
+            Search::new()
+                .source(SourceFilter::includes(["obj1.*", "obj2.*"]).excludes(["*.description"])),
+            json!({
+                "_source": {
+                    "includes": ["obj1.*", "obj2.*"],
+                    "excludes": ["*.description"]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new().source(SourceFilter::includes(["obj1.*"])),
+            json!({
+                "_source": {
+                    "includes": ["obj1.*"]
+                }
+            }),
+        );
+
+        assert_serialize(
+            Search::new().source(SourceFilter::from(true).excludes(["*.description"])),
+            json!({
+                "_source": {
+                    "excludes": ["*.description"]
+                }
+            }),
+        );
+    }
 }
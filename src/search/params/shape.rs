@@ -4,7 +4,7 @@ use crate::search::*;
 /// arbitrary `x, y` cartesian shapes such as rectangles and polygons. It can
 /// be used to index and query geometries whose coordinates fall in a
 /// 2-dimensional planar coordinate system.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum Shape {
     /// A single `x, y` coordinate
@@ -0,0 +1,92 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Splits a scroll or point in time search into multiple slices which can be consumed
+/// independently, e.g. by parallel workers.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#slice-scroll>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Slice {
+    id: u32,
+
+    max: u32,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    field: Option<String>,
+}
+
+impl Slice {
+    /// Creates an instance of [`Slice`]
+    ///
+    /// - `id` - Zero based id of the slice, must be lower than `max`
+    /// - `max` - Total number of slices
+    pub fn new(id: u32, max: u32) -> Self {
+        Self {
+            id,
+            max,
+            field: None,
+        }
+    }
+
+    /// Field used to split documents between slices, defaults to `_id`
+    /// for scroll searches and `_shard_doc` for point in time searches
+    pub fn field<T>(mut self, field: T) -> Self
+    where
+        T: ToString,
+    {
+        self.field = Some(field.to_string());
+        self
+    }
+}
+
+impl Validate for Slice {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if self.id >= self.max {
+            errors.push("", "`id` must be lower than `max`");
+        }
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Slice::new(0, 2), json!({ "id": 0, "max": 2 }));
+
+        assert_serialize(
+            Slice::new(1, 2).field("@timestamp"),
+            json!({ "id": 1, "max": 2, "field": "@timestamp" }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .slice(Slice::new(0, 2))
+                .pit(PointInTime::new("46ToAwMDaWR5").keep_alive(Time::Minutes(1)))
+                .sort(FieldSort::ascending("@timestamp")),
+            json!({
+                "slice": { "id": 0, "max": 2 },
+                "pit": { "id": "46ToAwMDaWR5", "keep_alive": "1m" },
+                "sort": [ { "@timestamp": { "order": "asc" } } ]
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Slice::new(1, 2).validate(), Ok(()));
+
+        assert_eq!(
+            Search::new().slice(Slice::new(2, 2)).validate(),
+            Err(vec![ValidationError::new(
+                "slice",
+                "`id` must be lower than `max`"
+            )])
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Visitor};
 use serde::ser::{self, Serialize};
 
 /// Elasticsearch term value
@@ -66,6 +67,53 @@ impl Term {
     }
 }
 
+impl<'de> Deserialize<'de> for Term {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TermVisitor;
+
+        impl<'de> Visitor<'de> for TermVisitor {
+            type Value = Term;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a boolean, number or string")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(Term::Boolean(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                if v < 0 {
+                    Ok(Term::NegativeNumber(v))
+                } else {
+                    Ok(Term::PositiveNumber(v as u64))
+                }
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Term::PositiveNumber(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(Term::Float64(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Term::String(v.to_string()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Term::String(v))
+            }
+        }
+
+        deserializer.deserialize_any(TermVisitor)
+    }
+}
+
 struct Serializer;
 
 impl ser::Serializer for Serializer {
@@ -460,6 +508,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserializes_primitives_correctly() {
+        let subject: Vec<Term> =
+            serde_json::from_value(json!([true, 12345, -1234, 1.5, "str"])).unwrap();
+
+        assert_eq!(
+            subject,
+            [
+                Term::Boolean(true),
+                Term::PositiveNumber(12345),
+                Term::NegativeNumber(-1234),
+                Term::Float64(1.5),
+                Term::String("str".into()),
+            ]
+        );
+    }
+
     #[test]
     fn custom_partial_eq() {
         assert_eq!(Term::Float32(1.0), Term::Float64(1.0));
@@ -2,7 +2,7 @@ use crate::util::*;
 use std::borrow::Cow;
 
 /// Search text
-#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Text(Option<String>);
 
 impl std::fmt::Debug for Text {
@@ -1,29 +1,29 @@
 /// Control how the total number of hits should be tracked.
 ///
-/// When set to `Track` with a value `true`, the response will always track the number of hits that
-/// match the query accurately.
+/// When set to `Enabled` with a value `true`, the response will always track the number of hits
+/// that match the query accurately.
 ///
-/// When set to `Count` with an integer value `n`, the response accurately tracks the total
+/// When set to `Threshold` with an integer value `n`, the response accurately tracks the total
 /// hit count that match the query up to `n` documents.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TrackTotalHits {
     /// Whether to accurately track the number of hits that match the query accurately
-    Track(bool),
+    Enabled(bool),
 
     /// Accurately track the number of hits up to the specified value
-    Count(i64),
+    Threshold(u64),
 }
 
 impl From<bool> for TrackTotalHits {
     fn from(value: bool) -> Self {
-        TrackTotalHits::Track(value)
+        TrackTotalHits::Enabled(value)
     }
 }
 
-impl From<i64> for TrackTotalHits {
-    fn from(value: i64) -> Self {
-        TrackTotalHits::Count(value)
+impl From<u64> for TrackTotalHits {
+    fn from(value: u64) -> Self {
+        TrackTotalHits::Threshold(value)
     }
 }
 
@@ -31,16 +31,27 @@ impl From<i64> for TrackTotalHits {
 mod tests {
     use super::*;
     use crate::util::*;
+    use crate::Search;
 
     #[test]
     fn serialization() {
         assert_serialize(
             [
-                TrackTotalHits::Track(false),
-                TrackTotalHits::Track(true),
-                TrackTotalHits::Count(10),
+                TrackTotalHits::Enabled(false),
+                TrackTotalHits::Enabled(true),
+                TrackTotalHits::Threshold(10),
             ],
             json!([false, true, 10,]),
-        )
+        );
+
+        assert_serialize(
+            Search::new().track_total_hits(true),
+            json!({ "track_total_hits": true }),
+        );
+
+        assert_serialize(
+            Search::new().track_total_hits(100_000),
+            json!({ "track_total_hits": 100000 }),
+        );
     }
 }
@@ -1,10 +1,14 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
 
 /// Whenever durations need to be specified, e.g. for a `timeout` parameter,
 /// the duration must specify the unit, like `2d` for 2 days.
 ///
+/// Unlike other units, `Time` is not `Copy`, as it can hold a
+/// [raw](Time::Raw) notation.
+///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#time-units>
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[allow(missing_docs)]
 pub enum Time {
     Days(u64),
@@ -14,6 +18,37 @@ pub enum Time {
     Milliseconds(u64),
     Microseconds(u64),
     Nanoseconds(u64),
+
+    /// Time already expressed in Elasticsearch notation, e.g. `1.5h`
+    Raw(String),
+}
+
+impl From<std::time::Duration> for Time {
+    fn from(value: std::time::Duration) -> Self {
+        let nanos = value.subsec_nanos();
+
+        if nanos == 0 {
+            Self::Seconds(value.as_secs())
+        } else if nanos % 1_000_000 == 0 {
+            Self::Milliseconds(value.as_millis() as u64)
+        } else if nanos % 1_000 == 0 {
+            Self::Microseconds(value.as_micros() as u64)
+        } else {
+            Self::Nanoseconds(value.as_nanos() as u64)
+        }
+    }
+}
+
+impl From<&str> for Time {
+    fn from(value: &str) -> Self {
+        Self::Raw(value.to_string())
+    }
+}
+
+impl From<String> for Time {
+    fn from(value: String) -> Self {
+        Self::Raw(value)
+    }
 }
 
 impl Serialize for Time {
@@ -29,11 +64,33 @@ impl Serialize for Time {
             Self::Milliseconds(u) => format!("{u}ms"),
             Self::Microseconds(u) => format!("{u}micros"),
             Self::Nanoseconds(u) => format!("{u}nanos"),
+            Self::Raw(value) => value.clone(),
         }
         .serialize(serializer)
     }
 }
 
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        match split_unit(&value) {
+            Some((u, "d")) => Ok(Self::Days(u)),
+            Some((u, "h")) => Ok(Self::Hours(u)),
+            Some((u, "m")) => Ok(Self::Minutes(u)),
+            Some((u, "s")) => Ok(Self::Seconds(u)),
+            Some((u, "ms")) => Ok(Self::Milliseconds(u)),
+            Some((u, "micros")) => Ok(Self::Microseconds(u)),
+            Some((u, "nanos")) => Ok(Self::Nanoseconds(u)),
+            _ if is_fractional_time(&value) => Ok(Self::Raw(value)),
+            _ => Err(D::Error::custom(format!("invalid time `{value}`"))),
+        }
+    }
+}
+
 /// Calendar-aware intervals are configured with the `calendar_interval` parameter. You can specify
 /// calendar intervals using the unit name, such as `month`, or as a single unit quantity, such as
 /// `1M`. For example,`day` and `1d` are equivalent. Multiple quantities, such as `2d`, are not supported.
@@ -73,6 +130,25 @@ pub enum CalendarInterval {
     Year,
 }
 
+impl std::str::FromStr for CalendarInterval {
+    type Err = String;
+
+    /// Parses either the unit name, e.g. `month`, or the single unit
+    /// quantity, e.g. `1M`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minute" | "1m" => Ok(Self::Minute),
+            "hour" | "1h" => Ok(Self::Hour),
+            "day" | "1d" => Ok(Self::Day),
+            "week" | "1w" => Ok(Self::Week),
+            "month" | "1M" => Ok(Self::Month),
+            "quarter" | "1q" => Ok(Self::Quarter),
+            "year" | "1y" => Ok(Self::Year),
+            _ => Err(format!("invalid calendar interval `{s}`")),
+        }
+    }
+}
+
 /// Whenever the byte size of data needs to be specified, e.g. when setting a
 /// buffer size parameter, the value must specify the unit,
 /// like `10kb` for 10 kilobytes.
@@ -182,6 +258,28 @@ impl Serialize for Distance {
     }
 }
 
+impl<'de> Deserialize<'de> for Distance {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        match split_unit(&value) {
+            Some((u, "mi")) => Ok(Self::Miles(u)),
+            Some((u, "yd")) => Ok(Self::Yards(u)),
+            Some((u, "ft")) => Ok(Self::Feet(u)),
+            Some((u, "in")) => Ok(Self::Inches(u)),
+            Some((u, "km")) => Ok(Self::Kilometers(u)),
+            Some((u, "m")) => Ok(Self::Meters(u)),
+            Some((u, "cm")) => Ok(Self::Centimeter(u)),
+            Some((u, "mm")) => Ok(Self::Millimeters(u)),
+            Some((u, "nmi")) => Ok(Self::NauticalMiles(u)),
+            _ => Err(D::Error::custom(format!("invalid distance `{value}`"))),
+        }
+    }
+}
+
 /// Wherever distances need to be specified, such as the `distance` parameter
 /// in the
 /// [Geo-distance](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-distance-query.html)
@@ -223,3 +321,97 @@ impl Serialize for DistanceUnit {
         .serialize(serializer)
     }
 }
+
+impl<'de> Deserialize<'de> for DistanceUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        match value.as_str() {
+            "mi" => Ok(Self::Miles),
+            "yd" => Ok(Self::Yards),
+            "ft" => Ok(Self::Feet),
+            "in" => Ok(Self::Inches),
+            "km" => Ok(Self::Kilometers),
+            "m" => Ok(Self::Meters),
+            "cm" => Ok(Self::Centimeter),
+            "mm" => Ok(Self::Millimeters),
+            "nmi" => Ok(Self::NauticalMiles),
+            _ => Err(D::Error::custom(format!("invalid distance unit `{value}`"))),
+        }
+    }
+}
+
+/// Splits a value such as `200km` into its quantity and unit
+fn split_unit(value: &str) -> Option<(u64, &str)> {
+    let index = value.find(|c: char| !c.is_ascii_digit())?;
+    let (quantity, unit) = value.split_at(index);
+
+    quantity.parse().ok().map(|quantity| (quantity, unit))
+}
+
+/// Whether the value is a fractional quantity of a time unit, e.g. `1.5h`
+fn is_fractional_time(value: &str) -> bool {
+    value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map(|index| value.split_at(index))
+        .filter(|(quantity, _)| quantity.parse::<f64>().is_ok())
+        .filter(|(_, unit)| ["d", "h", "m", "s", "ms", "micros", "nanos"].contains(unit))
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+    use std::time::Duration;
+
+    #[test]
+    fn serializes_time_from_duration() {
+        assert_serialize(
+            [
+                Time::from(Duration::from_secs(2)),
+                Time::from(Duration::from_millis(1500)),
+                Time::from(Duration::from_millis(250)),
+                Time::from(Duration::from_micros(10)),
+                Time::from(Duration::from_nanos(5)),
+                Time::from("1.5h"),
+            ],
+            json!(["2s", "1500ms", "250ms", "10micros", "5nanos", "1.5h"]),
+        );
+    }
+
+    #[test]
+    fn deserializes_time() {
+        let result: Vec<Time> =
+            serde_json::from_value(json!(["2d", "30m", "100ms", "1.5h"])).unwrap();
+
+        assert_eq!(
+            result,
+            [
+                Time::Days(2),
+                Time::Minutes(30),
+                Time::Milliseconds(100),
+                Time::Raw("1.5h".to_string()),
+            ]
+        );
+
+        assert!(serde_json::from_value::<Time>(json!("soon")).is_err());
+        assert!(serde_json::from_value::<Time>(json!("1.5")).is_err());
+        assert!(serde_json::from_value::<Time>(json!("2w")).is_err());
+    }
+
+    #[test]
+    fn parses_calendar_interval() {
+        assert_eq!("month".parse(), Ok(CalendarInterval::Month));
+        assert_eq!("1M".parse(), Ok(CalendarInterval::Month));
+        assert_eq!("1m".parse(), Ok(CalendarInterval::Minute));
+        assert_eq!("1q".parse(), Ok(CalendarInterval::Quarter));
+        assert_eq!(
+            "2d".parse::<CalendarInterval>(),
+            Err("invalid calendar interval `2d`".to_string())
+        );
+    }
+}
@@ -25,24 +25,34 @@ use crate::util::*;
 ///    .boost(1.3)
 ///    .name("test");
 /// ```
+/// Every clause method accepts either a single query or any collection of
+/// queries, which is handy when conditions are collected programmatically:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # let user_ids = vec![1, 2, 3];
+/// # let query =
+/// Query::bool()
+///    .filter(user_ids.iter().map(|id| Query::term("user_id", *id)))
+///    .filter([Query::exists("title"), Query::exists("body")]);
+/// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-bool-query.html>
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct BoolQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     must: QueryCollection,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     filter: QueryCollection,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     should: QueryCollection,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     must_not: QueryCollection,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -116,15 +126,60 @@ impl BoolQuery {
     /// [minimum_should_match parameter](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-minimum-should-match.html).
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
+        self
+    }
+
+    /// Appends the clauses of `other` to the clauses of this query.
+    ///
+    /// When both queries set `minimum_should_match`, the stricter one is kept,
+    /// i.e. the higher count or percentage of `should` clauses required to
+    /// match. Expressions of different kinds can't be compared, in which case
+    /// the value of this query wins. `boost` and `_name` of this query take
+    /// precedence as well.
+    pub fn merge(mut self, other: BoolQuery) -> Self {
+        self.must.extend(other.must);
+        self.filter.extend(other.filter);
+        self.should.extend(other.should);
+        self.must_not.extend(other.must_not);
+
+        self.minimum_should_match = match (self.minimum_should_match, other.minimum_should_match) {
+            (Some(left), Some(right)) => Some(stricter_minimum_should_match(left, right)),
+            (left, right) => left.or(right),
+        };
+        self.boost = self.boost.or(other.boost);
+        self._name = self._name.or(other._name);
+
         self
     }
 
+    /// Whether the query has any `should` clauses
+    pub(crate) fn has_should(&self) -> bool {
+        self.should.should_keep()
+    }
+
     add_boost_and_name!();
 }
 
+fn stricter_minimum_should_match(
+    left: MinimumShouldMatch,
+    right: MinimumShouldMatch,
+) -> MinimumShouldMatch {
+    use MinimumShouldMatch::*;
+
+    match (left, right) {
+        (Count(left), Count(right)) => Count(left.max(right)),
+        (Percentage(left), Percentage(right)) => Percentage(left.max(right)),
+        (NegativeCount(left), NegativeCount(right)) => NegativeCount(left.min(right)),
+        (NegativePercentage(left), NegativePercentage(right)) => {
+            NegativePercentage(left.min(right))
+        }
+        (left, _) => left,
+    }
+}
+
 impl ShouldSkip for BoolQuery {
     fn should_skip(&self) -> bool {
         self.must.should_skip()
@@ -134,7 +189,25 @@ impl ShouldSkip for BoolQuery {
     }
 }
 
+impl Validate for BoolQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if self.should_skip() && self.minimum_should_match.is_some() {
+            errors.push("bool", "`minimum_should_match` is set without any clauses");
+        }
+
+        errors.nested("bool.must", &self.must);
+        errors.nested("bool.filter", &self.filter);
+        errors.nested("bool.should", &self.should);
+        errors.nested("bool.must_not", &self.must_not);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("bool": BoolQuery);
+deserialize_with_root!("bool": BoolQuery);
 
 #[cfg(test)]
 mod tests {
@@ -216,4 +289,108 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_collected_filters() {
+        let filters = (0..10)
+            .map(|i| Query::term(format!("field{i}"), i))
+            .collect::<Vec<_>>();
+
+        let expected_filters = (0..10)
+            .map(|i| json!({ "term": { format!("field{i}"): { "value": i } } }))
+            .collect::<Vec<_>>();
+
+        assert_serialize_query(
+            Query::bool().filter(filters),
+            json!({ "bool": { "filter": expected_filters } }),
+        );
+    }
+
+    #[test]
+    fn merges() {
+        let left = Query::bool()
+            .must(Query::term("test1", 1))
+            .should(Query::term("test1", 2))
+            .minimum_should_match(1)
+            .name("left");
+
+        let right = Query::bool()
+            .must(Query::term("test2", 1))
+            .filter(Query::term("test2", 2))
+            .should(Query::term("test2", 3))
+            .must_not(Query::term("test2", 4))
+            .minimum_should_match(2)
+            .boost(2);
+
+        assert_serialize_query(
+            left.merge(right),
+            json!({
+                "bool": {
+                    "must": [
+                        { "term": { "test1": { "value": 1 } } },
+                        { "term": { "test2": { "value": 1 } } },
+                    ],
+                    "filter": [
+                        { "term": { "test2": { "value": 2 } } },
+                    ],
+                    "should": [
+                        { "term": { "test1": { "value": 2 } } },
+                        { "term": { "test2": { "value": 3 } } },
+                    ],
+                    "must_not": [
+                        { "term": { "test2": { "value": 4 } } },
+                    ],
+                    "minimum_should_match": "2",
+                    "boost": 2.0,
+                    "_name": "left"
+                }
+            }),
+        );
+
+        assert_eq!(
+            Query::bool()
+                .minimum_should_match("-25%")
+                .merge(Query::bool().minimum_should_match("-10%"))
+                .minimum_should_match,
+            Some(MinimumShouldMatch::NegativePercentage(10.0))
+        );
+
+        assert_eq!(
+            Query::bool()
+                .merge(Query::bool().minimum_should_match("75%"))
+                .minimum_should_match,
+            Some(MinimumShouldMatch::Percentage(75.0))
+        );
+
+        assert_eq!(
+            Query::bool()
+                .minimum_should_match("3<90%")
+                .merge(Query::bool().minimum_should_match(2))
+                .minimum_should_match,
+            Some(MinimumShouldMatch::CombinedExpression("3<90%".to_string()))
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::bool()
+                .must(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                .should(
+                    Query::bool()
+                        .filter(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                )
+                .validate(),
+            Err(vec![
+                ValidationError::new(
+                    "bool.must[0].fuzzy.test.fuzziness",
+                    "edit distance cannot be greater than 2"
+                ),
+                ValidationError::new(
+                    "bool.should[0].bool.filter[0].fuzzy.test.fuzziness",
+                    "edit distance cannot be greater than 2"
+                )
+            ])
+        );
+    }
 }
@@ -17,8 +17,8 @@ use crate::util::*;
 ///    .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-boosting-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct BoostingQuery {
     positive: Box<Query>,
 
@@ -74,7 +74,19 @@ impl ShouldSkip for BoostingQuery {
     }
 }
 
+impl Validate for BoostingQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("boosting.positive", &self.positive);
+        errors.nested("boosting.negative", &self.negative);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("boosting": BoostingQuery);
+deserialize_with_root!("boosting": BoostingQuery);
 
 #[cfg(test)]
 mod tests {
@@ -132,4 +144,20 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::boosting(
+                Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)),
+                Query::fuzzy("test", "abd"),
+                0.5
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "boosting.positive.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
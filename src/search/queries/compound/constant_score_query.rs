@@ -16,8 +16,8 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-constant-score-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ConstantScoreQuery {
     filter: Box<Query>,
 
@@ -58,7 +58,18 @@ impl ShouldSkip for ConstantScoreQuery {
     }
 }
 
+impl Validate for ConstantScoreQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("constant_score.filter", &self.filter);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("constant_score": ConstantScoreQuery);
+deserialize_with_root!("constant_score": ConstantScoreQuery);
 
 #[cfg(test)]
 mod tests {
@@ -100,4 +111,16 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::constant_score(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "constant_score.filter.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
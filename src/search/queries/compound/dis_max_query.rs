@@ -24,8 +24,8 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-dis-max-query.html>
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct DisMaxQuery {
     queries: QueryCollection,
 
@@ -92,7 +92,18 @@ impl ShouldSkip for DisMaxQuery {
     }
 }
 
+impl Validate for DisMaxQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("dis_max.queries", &self.queries);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("dis_max": DisMaxQuery);
+deserialize_with_root!("dis_max": DisMaxQuery);
 
 #[cfg(test)]
 mod tests {
@@ -182,4 +193,17 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::dis_max()
+                .query(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "dis_max.queries[0].fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
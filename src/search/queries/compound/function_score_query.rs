@@ -26,13 +26,13 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct FunctionScoreQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     query: Option<Box<Query>>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     functions: Vec<Function>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -138,7 +138,29 @@ impl ShouldSkip for FunctionScoreQuery {
     }
 }
 
+impl Validate for FunctionScoreQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if self.query.is_none() && self.functions.is_empty() {
+            errors.push(
+                "function_score",
+                "at least one function or a query is required",
+            );
+        }
+
+        errors.nested("function_score.query", &self.query);
+
+        for (index, function) in self.functions.iter().enumerate() {
+            errors.nested(&format!("function_score.functions[{}]", index), function);
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("function_score": FunctionScoreQuery);
+deserialize_with_root!("function_score": FunctionScoreQuery);
 
 #[cfg(test)]
 mod tests {
@@ -292,4 +314,27 @@ mod tests {
                     .filter(Query::term("type", "street")),
             );
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::function_score()
+                .query(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                .function(
+                    Function::weight(2.0)
+                        .filter(Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3)))
+                )
+                .validate(),
+            Err(vec![
+                ValidationError::new(
+                    "function_score.query.fuzzy.test.fuzziness",
+                    "edit distance cannot be greater than 2"
+                ),
+                ValidationError::new(
+                    "function_score.functions[0].filter.fuzzy.test.fuzziness",
+                    "edit distance cannot be greater than 2"
+                )
+            ])
+        );
+    }
 }
@@ -12,7 +12,7 @@ use crate::util::*;
 /// ```
 /// **NOTE**: This is fallible and can lead to incorrect queries and
 /// rejected search requests, use ar your own risk.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JsonQuery(serde_json::Value);
 
 impl Query {
@@ -42,6 +42,8 @@ impl ShouldSkip for JsonQuery {
     }
 }
 
+impl Validate for JsonQuery {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,5 +54,11 @@ mod tests {
             Query::json(json!({ "term": { "user": "username" } })),
             json!({ "term": { "user": "username" } }),
         );
+
+        assert_serialize_query(
+            Query::bool()
+                .must([json!({ "intervals": { "my_text": { "match": { "query": "food" } } } })]),
+            json!({ "bool": { "must": [{ "intervals": { "my_text": { "match": { "query": "food" } } } }] } }),
+        );
     }
 }
@@ -17,13 +17,13 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-combined-fields-query.html>
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct CombinedFieldsQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Vec<String>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -33,7 +33,7 @@ pub struct CombinedFieldsQuery {
     operator: Option<Operator>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     zero_terms_query: Option<ZeroTermsQuery>,
@@ -97,9 +97,9 @@ impl CombinedFieldsQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -119,7 +119,10 @@ impl ShouldSkip for CombinedFieldsQuery {
     }
 }
 
+impl Validate for CombinedFieldsQuery {}
+
 serialize_with_root!("combined_fields": CombinedFieldsQuery);
+deserialize_with_root!("combined_fields": CombinedFieldsQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// A `match_bool_prefix` query analyzes its input and constructs a
 /// [`bool` query](crate::BoolQuery) from the terms. Each term except the last is used in a
@@ -16,20 +17,20 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-bool-prefix-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchBoolPrefixQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     analyzer: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     operator: Option<Operator>,
@@ -48,11 +49,11 @@ impl Query {
     /// - `query` - Text, number, boolean value or date you wish to find in the provided `<field>`
     pub fn match_bool_prefix<T, U>(field: T, query: U) -> MatchBoolPrefixQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<Text>,
     {
         MatchBoolPrefixQuery {
-            field: field.to_string(),
+            field: field.into(),
             query: query.into(),
             analyzer: None,
             minimum_should_match: None,
@@ -80,9 +81,9 @@ impl MatchBoolPrefixQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -101,7 +102,10 @@ impl ShouldSkip for MatchBoolPrefixQuery {
     }
 }
 
+impl Validate for MatchBoolPrefixQuery {}
+
 serialize_with_root_keyed!("match_bool_prefix": MatchBoolPrefixQuery);
+deserialize_with_root_keyed!("match_bool_prefix": MatchBoolPrefixQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Returns documents that contain the words of a provided text, in the **same order** as provided.
 /// The last term of the provided text is treated as a [prefix](crate::PrefixQuery), matching any
@@ -15,23 +16,23 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query-phrase-prefix.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchPhrasePrefixQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     analyzer: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    slop: Option<u8>,
+    slop: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     zero_terms_query: Option<ZeroTermsQuery>,
@@ -53,11 +54,11 @@ impl Query {
     /// that term.
     pub fn match_phrase_prefix<T, U>(field: T, query: U) -> MatchPhrasePrefixQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<Text>,
     {
         MatchPhrasePrefixQuery {
-            field: field.to_string(),
+            field: field.into(),
             query: query.into(),
             analyzer: None,
             max_expansions: None,
@@ -84,14 +85,14 @@ impl MatchPhrasePrefixQuery {
 
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.max_expansions = Some(max_expansions);
         self
     }
 
     /// The maximum number of intervening unmatched positions, as well as
     /// whether matches are required to be in-order.
-    pub fn slop(mut self, slop: u8) -> Self {
+    pub fn slop(mut self, slop: u32) -> Self {
         self.slop = Some(slop);
         self
     }
@@ -112,7 +113,10 @@ impl ShouldSkip for MatchPhrasePrefixQuery {
     }
 }
 
+impl Validate for MatchPhrasePrefixQuery {}
+
 serialize_with_root_keyed!("match_phrase_prefix": MatchPhrasePrefixQuery);
+deserialize_with_root_keyed!("match_phrase_prefix": MatchPhrasePrefixQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// The `match_phrase` query analyzes the text and creates a phrase query out
 /// of the analyzed text.
@@ -14,20 +15,23 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query-phrase.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchPhraseQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     analyzer: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    slop: Option<u8>,
+    slop: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    zero_terms_query: Option<ZeroTermsQuery>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -50,14 +54,15 @@ impl Query {
     /// fields for analyzed tokens rather than an exact term.
     pub fn match_phrase<T, U>(field: T, query: U) -> MatchPhraseQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<Text>,
     {
         MatchPhraseQuery {
-            field: field.to_string(),
+            field: field.into(),
             query: query.into(),
             analyzer: None,
             slop: None,
+            zero_terms_query: None,
             boost: None,
             _name: None,
         }
@@ -79,11 +84,18 @@ impl MatchPhraseQuery {
 
     /// The maximum number of intervening unmatched positions, as well as
     /// whether matches are required to be in-order.
-    pub fn slop(mut self, slop: u8) -> Self {
+    pub fn slop(mut self, slop: u32) -> Self {
         self.slop = Some(slop);
         self
     }
 
+    /// Indicates whether no documents are returned if the `analyzer` removes
+    /// all tokens, such as when using a `stop` filter.
+    pub fn zero_terms_query(mut self, zero_terms_query: ZeroTermsQuery) -> Self {
+        self.zero_terms_query = Some(zero_terms_query);
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -93,7 +105,10 @@ impl ShouldSkip for MatchPhraseQuery {
     }
 }
 
+impl Validate for MatchPhraseQuery {}
+
 serialize_with_root_keyed!("match_phrase": MatchPhraseQuery);
+deserialize_with_root_keyed!("match_phrase": MatchPhraseQuery);
 
 #[cfg(test)]
 mod tests {
@@ -115,7 +130,8 @@ mod tests {
         assert_serialize_query(
             Query::match_phrase("test", "search text")
                 .analyzer("search_time_analyzer")
-                .slop(1u8)
+                .slop(1)
+                .zero_terms_query(ZeroTermsQuery::All)
                 .boost(2)
                 .name("test"),
             json!({
@@ -124,6 +140,7 @@ mod tests {
                         "query": "search text",
                         "analyzer": "search_time_analyzer",
                         "slop": 1,
+                        "zero_terms_query": "all",
                         "boost": 2.0,
                         "_name": "test"
                     }
@@ -131,4 +148,27 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn deserialization() {
+        let query: Query = serde_json::from_value(json!({
+            "match_phrase": {
+                "message": {
+                    "query": "this is a test",
+                    "slop": 2,
+                    "zero_terms_query": "none"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::from(
+                Query::match_phrase("message", "this is a test")
+                    .slop(2)
+                    .zero_terms_query(ZeroTermsQuery::None)
+            )
+        );
+    }
 }
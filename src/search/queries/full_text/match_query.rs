@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Returns documents that match a provided text, number, date or boolean value.
 /// The provided text is analyzed before matching.
@@ -17,13 +18,13 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -36,10 +37,10 @@ pub struct MatchQuery {
     fuzziness: Option<Fuzziness>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    prefix_length: Option<u8>,
+    prefix_length: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     fuzzy_transpositions: Option<bool>,
@@ -54,7 +55,7 @@ pub struct MatchQuery {
     operator: Option<Operator>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     zero_terms_query: Option<ZeroTermsQuery>,
@@ -80,11 +81,11 @@ impl Query {
     /// fields for analyzed tokens rather than an exact term.
     pub fn r#match<T, U>(field: T, query: U) -> MatchQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<Text>,
     {
         MatchQuery {
-            field: field.to_string(),
+            field: field.into(),
             query: query.into(),
             analyzer: None,
             auto_generate_synonyms_phrase_query: None,
@@ -145,14 +146,14 @@ impl MatchQuery {
 
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.max_expansions = Some(max_expansions);
         self
     }
 
     /// Number of beginning characters left unchanged for fuzzy matching.
     /// Defaults to `0`.
-    pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+    pub fn prefix_length(mut self, prefix_length: u32) -> Self {
         self.prefix_length = Some(prefix_length);
         self
     }
@@ -195,9 +196,9 @@ impl MatchQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -217,7 +218,17 @@ impl ShouldSkip for MatchQuery {
     }
 }
 
+impl Validate for MatchQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested(&format!("match.{}.fuzziness", self.field), &self.fuzziness);
+        errors.into_result()
+    }
+}
+
 serialize_with_root_keyed!("match": MatchQuery);
+deserialize_with_root_keyed!("match": MatchQuery);
 
 #[cfg(test)]
 mod tests {
@@ -240,7 +251,7 @@ mod tests {
             Query::r#match("test", "search text")
                 .analyzer("search_time_analyzer")
                 .auto_generate_synonyms_phrase_query(true)
-                .fuzziness(23)
+                .fuzziness(Fuzziness::Distance(2))
                 .max_expansions(2)
                 .prefix_length(3)
                 .fuzzy_transpositions(false)
@@ -257,7 +268,7 @@ mod tests {
                         "query": "search text",
                         "analyzer": "search_time_analyzer",
                         "auto_generate_synonyms_phrase_query": true,
-                        "fuzziness": 23,
+                        "fuzziness": 2,
                         "max_expansions": 2,
                         "prefix_length": 3,
                         "fuzzy_transpositions": false,
@@ -273,4 +284,54 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_fuzzy_parameters() {
+        assert_serialize_query(
+            Query::r#match("title", "quikc brwn fox")
+                .fuzziness(Fuzziness::Auto)
+                .prefix_length(2)
+                .max_expansions(500)
+                .fuzzy_rewrite(Rewrite::TopTermsBlendedFrequencies(10))
+                .fuzzy_transpositions(true)
+                .lenient(true)
+                .zero_terms_query(ZeroTermsQuery::All)
+                .auto_generate_synonyms_phrase_query(false),
+            json!({
+                "match": {
+                    "title": {
+                        "query": "quikc brwn fox",
+                        "fuzziness": "AUTO",
+                        "prefix_length": 2,
+                        "max_expansions": 500,
+                        "fuzzy_rewrite": "top_terms_blended_freqs_10",
+                        "fuzzy_transpositions": true,
+                        "lenient": true,
+                        "zero_terms_query": "all",
+                        "auto_generate_synonyms_phrase_query": false
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::r#match("test", "abc")
+                .fuzziness(Fuzziness::Distance(1))
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Query::r#match("test", "abc")
+                .fuzziness(Fuzziness::Distance(5))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "match.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
@@ -16,25 +16,51 @@ use crate::util::*;
 ///     .boost(2)
 ///     .name("test");
 /// ```
+///
+/// Individual fields can be boosted with the caret (`^`) notation:
+/// ```
+/// # use elasticsearch_dsl::queries::*;
+/// # use elasticsearch_dsl::queries::params::*;
+/// # let query =
+/// Query::multi_match(["title^3", "summary"], "search text")
+///     .field_with_boost("body", 0.5)
+///     .r#type(MultiMatchType::Phrase)
+///     .slop(2);
+/// ```
+///
+/// Some parameters only apply to some [types](MultiMatchType). As the type
+/// can be set before or after them, combining incompatible ones neither fails
+/// to compile nor panics, but is reported by [`Validate`](crate::Validate):
+/// - `tie_breaker` is ignored when scores are combined, so it is rejected for
+/// [`most_fields`](MultiMatchType::MostFields) and
+/// [`bool_prefix`](MultiMatchType::BoolPrefix)
+/// - `slop` applies to [`phrase`](MultiMatchType::Phrase) and
+/// [`phrase_prefix`](MultiMatchType::PhrasePrefix) only
+/// - `fuzziness` is not supported by `cross_fields`, `phrase` and `phrase_prefix`
+/// - `max_expansions` applies to `phrase_prefix`, `bool_prefix` and fuzzy queries
+///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-match-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MultiMatchQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Vec<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    r#type: Option<TextQueryType>,
+    r#type: Option<MultiMatchType>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     tie_breaker: Option<f32>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     analyzer: Option<String>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slop: Option<u32>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     auto_generate_synonyms_phrase_query: Option<bool>,
 
@@ -42,10 +68,10 @@ pub struct MultiMatchQuery {
     fuzziness: Option<Fuzziness>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    prefix_length: Option<u8>,
+    prefix_length: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     fuzzy_transpositions: Option<bool>,
@@ -60,7 +86,7 @@ pub struct MultiMatchQuery {
     operator: Option<Operator>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     zero_terms_query: Option<ZeroTermsQuery>,
@@ -75,7 +101,8 @@ pub struct MultiMatchQuery {
 impl Query {
     /// Creates an instance of [`MultiMatchQuery`]
     ///
-    /// - `fields` - Fields you wish to search.
+    /// - `fields` - Fields you wish to search, individual fields can be boosted
+    /// with the caret (`^`) notation, e.g. `title^3`.
     /// - `query` - Text, number, boolean value or date you wish to find in the provided
     /// `<field>`. The `match` query
     /// [analyzes](https://www.elastic.co/guide/en/elasticsearch/reference/current/analysis.html)
@@ -95,6 +122,7 @@ impl Query {
             tie_breaker: None,
             query: query.into(),
             analyzer: None,
+            slop: None,
             auto_generate_synonyms_phrase_query: None,
             fuzziness: None,
             max_expansions: None,
@@ -112,9 +140,21 @@ impl Query {
 }
 
 impl MultiMatchQuery {
+    /// Adds a field to search, boosted by the given factor, e.g.
+    /// `field_with_boost("title", 3)` searches `title^3`
+    pub fn field_with_boost<T, B>(mut self, field: T, boost: B) -> Self
+    where
+        T: ToString,
+        B: num_traits::AsPrimitive<f32>,
+    {
+        self.fields
+            .push(format!("{}^{}", field.to_string(), boost.as_()));
+        self
+    }
+
     /// The way the multi_match query is executed internally depends on the
     /// type parameter
-    pub fn r#type(mut self, r#type: TextQueryType) -> Self {
+    pub fn r#type(mut self, r#type: MultiMatchType) -> Self {
         self.r#type = Some(r#type);
         self
     }
@@ -154,6 +194,17 @@ impl MultiMatchQuery {
         self
     }
 
+    /// The maximum number of intervening unmatched positions, as well as
+    /// whether matches are required to be in-order.
+    ///
+    /// Only applies to [`phrase`](MultiMatchType::Phrase) and
+    /// [`phrase_prefix`](MultiMatchType::PhrasePrefix) types, setting it for
+    /// other types is only reported by [`Validate`](crate::Validate).
+    pub fn slop(mut self, slop: u32) -> Self {
+        self.slop = Some(slop);
+        self
+    }
+
     /// If `true`,
     /// [match phrase](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-query-phrase.html)
     /// queries are automatically created for multi-term synonyms. Defaults to `true`.
@@ -183,14 +234,14 @@ impl MultiMatchQuery {
 
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.max_expansions = Some(max_expansions);
         self
     }
 
     /// Number of beginning characters left unchanged for fuzzy matching.
     /// Defaults to `0`.
-    pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+    pub fn prefix_length(mut self, prefix_length: u32) -> Self {
         self.prefix_length = Some(prefix_length);
         self
     }
@@ -233,9 +284,9 @@ impl MultiMatchQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -255,7 +306,57 @@ impl ShouldSkip for MultiMatchQuery {
     }
 }
 
+impl Validate for MultiMatchQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        let r#type = self.r#type.unwrap_or_default();
+
+        if self.tie_breaker.is_some()
+            && matches!(
+                r#type,
+                MultiMatchType::MostFields | MultiMatchType::BoolPrefix
+            )
+        {
+            errors.push(
+                "multi_match.tie_breaker",
+                "`tie_breaker` is not supported by `most_fields` and `bool_prefix` types",
+            );
+        }
+
+        if self.slop.is_some() && !r#type.is_phrase() {
+            errors.push(
+                "multi_match.slop",
+                "`slop` is only supported by `phrase` and `phrase_prefix` types",
+            );
+        }
+
+        if self.fuzziness.is_some()
+            && matches!(
+                r#type,
+                MultiMatchType::CrossFields | MultiMatchType::Phrase | MultiMatchType::PhrasePrefix
+            )
+        {
+            errors.push(
+                "multi_match.fuzziness",
+                "`fuzziness` is not supported by `cross_fields`, `phrase` and `phrase_prefix` types",
+            );
+        }
+
+        if self.max_expansions.is_some() && !r#type.is_prefix() && self.fuzziness.is_none() {
+            errors.push(
+                "multi_match.max_expansions",
+                "`max_expansions` requires `fuzziness` unless the type is `phrase_prefix` or `bool_prefix`",
+            );
+        }
+
+        errors.nested("multi_match.fuzziness", &self.fuzziness);
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("multi_match": MultiMatchQuery);
+deserialize_with_root!("multi_match": MultiMatchQuery);
 
 #[cfg(test)]
 mod tests {
@@ -275,11 +376,11 @@ mod tests {
 
         assert_serialize_query(
             Query::multi_match(["test"], "search text")
-                .r#type(TextQueryType::BestFields)
+                .r#type(MultiMatchType::BestFields)
                 .tie_breaker(0.2)
                 .analyzer("search_time_analyzer")
                 .auto_generate_synonyms_phrase_query(true)
-                .fuzziness(23)
+                .fuzziness(Fuzziness::Distance(2))
                 .max_expansions(2)
                 .prefix_length(3)
                 .fuzzy_transpositions(false)
@@ -298,7 +399,7 @@ mod tests {
                     "tie_breaker": 0.2,
                     "analyzer": "search_time_analyzer",
                     "auto_generate_synonyms_phrase_query": true,
-                    "fuzziness": 23,
+                    "fuzziness": 2,
                     "max_expansions": 2,
                     "prefix_length": 3,
                     "fuzzy_transpositions": false,
@@ -313,4 +414,102 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_types() {
+        for (r#type, name) in [
+            (MultiMatchType::BestFields, "best_fields"),
+            (MultiMatchType::MostFields, "most_fields"),
+            (MultiMatchType::CrossFields, "cross_fields"),
+            (MultiMatchType::Phrase, "phrase"),
+            (MultiMatchType::PhrasePrefix, "phrase_prefix"),
+            (MultiMatchType::BoolPrefix, "bool_prefix"),
+        ] {
+            assert_serialize_query(
+                Query::multi_match(["test"], "search text").r#type(r#type),
+                json!({
+                    "multi_match": {
+                        "query": "search text",
+                        "fields": ["test"],
+                        "type": name,
+                    }
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn serializes_boosted_fields() {
+        assert_serialize_query(
+            Query::multi_match(["title^3", "summary"], "quick brown fox")
+                .field_with_boost("body", 0.5)
+                .field_with_boost("tags", 2)
+                .r#type(MultiMatchType::PhrasePrefix)
+                .slop(3)
+                .max_expansions(10),
+            json!({
+                "multi_match": {
+                    "query": "quick brown fox",
+                    "fields": ["title^3", "summary", "body^0.5", "tags^2"],
+                    "type": "phrase_prefix",
+                    "slop": 3,
+                    "max_expansions": 10,
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::multi_match(["test"], "abc")
+                .tie_breaker(0.3)
+                .fuzziness(Fuzziness::Auto)
+                .max_expansions(10)
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Query::multi_match(["test"], "abc")
+                .r#type(MultiMatchType::BoolPrefix)
+                .max_expansions(10)
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Query::multi_match(["test"], "abc")
+                .r#type(MultiMatchType::MostFields)
+                .tie_breaker(0.3)
+                .slop(2)
+                .max_expansions(10)
+                .validate(),
+            Err(vec![
+                ValidationError::new(
+                    "multi_match.tie_breaker",
+                    "`tie_breaker` is not supported by `most_fields` and `bool_prefix` types"
+                ),
+                ValidationError::new(
+                    "multi_match.slop",
+                    "`slop` is only supported by `phrase` and `phrase_prefix` types"
+                ),
+                ValidationError::new(
+                    "multi_match.max_expansions",
+                    "`max_expansions` requires `fuzziness` unless the type is `phrase_prefix` or `bool_prefix`"
+                ),
+            ])
+        );
+
+        assert_eq!(
+            Query::multi_match(["test"], "abc")
+                .r#type(MultiMatchType::CrossFields)
+                .fuzziness(Fuzziness::Distance(1))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "multi_match.fuzziness",
+                "`fuzziness` is not supported by `cross_fields`, `phrase` and `phrase_prefix` types"
+            )])
+        );
+    }
 }
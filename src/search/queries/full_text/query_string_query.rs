@@ -1,13 +1,14 @@
 use crate::search::*;
 use crate::util::*;
 
-/// Returns documents that match a provided text, number, date or boolean value.
-/// The provided text is analyzed before matching.
+/// Returns documents based on a provided query string, using a parser with a
+/// strict syntax.
 ///
-/// The `match` query is the standard query for performing a full-text search,
-/// including options for fuzzy matching.
+/// This query uses a syntax to parse and split the provided query string based
+/// on operators, such as `AND` or `NOT`. The query then analyzes each split text
+/// independently before returning matching documents.
 ///
-/// To create a Match query with numeric values:
+/// To create a query string query:
 /// ```
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
@@ -17,10 +18,10 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-query-string-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct QueryStringQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -44,9 +45,12 @@ pub struct QueryStringQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     enable_position_increments: Option<bool>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Vec<String>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    r#type: Option<MultiMatchType>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     fuzziness: Option<Fuzziness>,
 
@@ -66,7 +70,7 @@ pub struct QueryStringQuery {
     max_determinized_states: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     quote_analyzer: Option<String>,
@@ -102,6 +106,7 @@ impl Query {
         QueryStringQuery {
             query: query.into(),
             fields: vec![],
+            r#type: None,
             default_operator: None,
             analyze_wildcard: None,
             analyzer: None,
@@ -231,6 +236,16 @@ impl QueryStringQuery {
         self
     }
 
+    /// Determines how the query matches and scores documents when it is
+    /// expanded to several [`fields`](Self::fields), see
+    /// [`MultiMatchType`] for the available types.
+    ///
+    /// Defaults to [`best_fields`](MultiMatchType::BestFields).
+    pub fn r#type(mut self, r#type: MultiMatchType) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
     /// Maximum edit distance allowed for fuzzy matching. For fuzzy syntax, see
     /// [`Fuzziness`].
     pub fn fuzziness<T>(mut self, fuzziness: T) -> Self
@@ -300,9 +315,9 @@ impl QueryStringQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -376,7 +391,17 @@ impl ShouldSkip for QueryStringQuery {
     }
 }
 
+impl Validate for QueryStringQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("query_string.fuzziness", &self.fuzziness);
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("query_string": QueryStringQuery);
+deserialize_with_root!("query_string": QueryStringQuery);
 
 #[cfg(test)]
 mod tests {
@@ -428,4 +453,60 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_all_parameters() {
+        assert_serialize_query(
+            Query::query_string("title:(quick OR brown) AND \"lazy dog\"~2")
+                .default_field("content")
+                .fields(["title^3", "content"])
+                .r#type(MultiMatchType::CrossFields)
+                .default_operator(Operator::Or)
+                .analyzer("standard")
+                .quote_analyzer("whitespace")
+                .allow_leading_wildcard(false)
+                .enable_position_increments(true)
+                .fuzzy_max_expansions(50)
+                .fuzziness(Fuzziness::Auto)
+                .fuzzy_prefix_length(1)
+                .fuzzy_transpositions(true)
+                .phrase_slop(2)
+                .auto_generate_synonyms_phrase_query(false)
+                .analyze_wildcard(true)
+                .max_determinized_states(10000)
+                .minimum_should_match("75%")
+                .lenient(true)
+                .time_zone("+01:00")
+                .quote_field_suffix(".exact")
+                .rewrite(Rewrite::ConstantScore)
+                .boost(1.5),
+            json!({
+                "query_string": {
+                    "query": "title:(quick OR brown) AND \"lazy dog\"~2",
+                    "default_field": "content",
+                    "fields": ["title^3", "content"],
+                    "type": "cross_fields",
+                    "default_operator": "OR",
+                    "analyzer": "standard",
+                    "quote_analyzer": "whitespace",
+                    "allow_leading_wildcard": false,
+                    "enable_position_increments": true,
+                    "fuzzy_max_expansions": 50,
+                    "fuzziness": "AUTO",
+                    "fuzzy_prefix_length": 1,
+                    "fuzzy_transpositions": true,
+                    "phrase_slop": 2,
+                    "auto_generate_synonyms_phrase_query": false,
+                    "analyze_wildcard": true,
+                    "max_determinized_states": 10000,
+                    "minimum_should_match": "75%",
+                    "lenient": true,
+                    "time_zone": "+01:00",
+                    "quote_field_suffix": ".exact",
+                    "rewrite": "constant_score",
+                    "boost": 1.5,
+                }
+            }),
+        );
+    }
 }
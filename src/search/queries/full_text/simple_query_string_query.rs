@@ -1,13 +1,19 @@
 use crate::search::*;
 use crate::util::*;
 
-/// Returns documents that match a provided text, number, date or boolean value.
-/// The provided text is analyzed before matching.
+/// Returns documents based on a provided query string, using a parser with a
+/// limited but fault-tolerant syntax.
 ///
-/// The `match` query is the standard query for performing a full-text search,
-/// including options for fuzzy matching.
+/// This query uses a simple syntax to parse and split the provided query string
+/// into terms based on special operators. The query then analyzes each term
+/// independently before returning matching documents.
 ///
-/// To create a Match query with numeric values:
+/// While its syntax is more limited than the
+/// [`query_string` query](crate::QueryStringQuery), the `simple_query_string`
+/// query does not return errors for invalid syntax. Instead, it ignores any
+/// invalid parts of the query string.
+///
+/// To create a SimpleQueryString query:
 /// ```
 /// # use elasticsearch_dsl::queries::*;
 /// # use elasticsearch_dsl::queries::params::*;
@@ -17,13 +23,13 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-simple-query-string-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SimpleQueryStringQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     query: Text,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Vec<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -39,8 +45,10 @@ pub struct SimpleQueryStringQuery {
     auto_generate_synonyms_phrase_query: Option<bool>,
 
     #[serde(
+        default,
         skip_serializing_if = "ShouldSkip::should_skip",
-        serialize_with = "join_with_pipe"
+        serialize_with = "join_with_pipe",
+        deserialize_with = "split_by_pipe"
     )]
     flags: Vec<SimpleQueryStringQueryFlags>,
 
@@ -57,7 +65,7 @@ pub struct SimpleQueryStringQuery {
     lenient: Option<bool>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     quote_field_suffix: Option<String>,
@@ -172,7 +180,7 @@ impl SimpleQueryStringQuery {
     where
         I: IntoIterator<Item = SimpleQueryStringQueryFlags>,
     {
-        self.flags.extend(flags.into_iter());
+        self.flags.extend(flags);
         self
     }
 
@@ -216,9 +224,9 @@ impl SimpleQueryStringQuery {
     /// `minimum_should_match` parameter for valid values and more information.
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -244,7 +252,10 @@ impl ShouldSkip for SimpleQueryStringQuery {
     }
 }
 
+impl Validate for SimpleQueryStringQuery {}
+
 serialize_with_root!("simple_query_string": SimpleQueryStringQuery);
+deserialize_with_root!("simple_query_string": SimpleQueryStringQuery);
 
 #[cfg(test)]
 mod tests {
@@ -301,4 +312,29 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn deserialization() {
+        let query: Query = serde_json::from_value(json!({
+            "simple_query_string": {
+                "query": "foo | bar + baz*",
+                "flags": "OR|AND|PREFIX",
+                "quote_field_suffix": ".exact"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::from(
+                Query::simple_query_string("foo | bar + baz*")
+                    .flags([
+                        SimpleQueryStringQueryFlags::Or,
+                        SimpleQueryStringQueryFlags::And,
+                        SimpleQueryStringQueryFlags::Prefix,
+                    ])
+                    .quote_field_suffix(".exact")
+            )
+        );
+    }
 }
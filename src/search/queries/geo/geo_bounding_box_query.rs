@@ -1,19 +1,20 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Matches [geo_point](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
 /// and [geo_shape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
 /// values that intersect a bounding box.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-bounding-box-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct GeoBoundingBoxQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     bounding_box: GeoBoundingBox,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -32,11 +33,11 @@ impl Query {
     /// - `bounding_box` - A series of vertex coordinates of a geo bounding box
     pub fn geo_bounding_box<T, U>(field: T, bounding_box: U) -> GeoBoundingBoxQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<GeoBoundingBox>,
     {
         GeoBoundingBoxQuery {
-            field: field.to_string(),
+            field: field.into(),
             bounding_box: bounding_box.into(),
             validation_method: None,
             boost: None,
@@ -58,7 +59,10 @@ impl GeoBoundingBoxQuery {
 
 impl ShouldSkip for GeoBoundingBoxQuery {}
 
+impl Validate for GeoBoundingBoxQuery {}
+
 serialize_with_root_key_value_pair!("geo_bounding_box": GeoBoundingBoxQuery, field, bounding_box);
+deserialize_with_root_key_value_pair!("geo_bounding_box": GeoBoundingBoxQuery, field, bounding_box);
 
 #[cfg(test)]
 mod tests {
@@ -85,10 +89,10 @@ mod tests {
         assert_serialize_query(
             Query::geo_bounding_box(
                 "pin.location",
-                GeoBoundingBox::MainDiagonal {
-                    top_left: GeoLocation::new(40.73, -74.1),
-                    bottom_right: GeoLocation::new(40.01, -71.12),
-                },
+                GeoBoundingBox::main_diagonal(
+                    GeoLocation::new(40.73, -74.1),
+                    GeoLocation::new(40.01, -71.12),
+                ),
             )
             .validation_method(ValidationMethod::Strict)
             .name("test_name"),
@@ -130,6 +134,27 @@ mod tests {
                     }
                 }
             }),
-        )
+        );
+    }
+
+    #[test]
+    fn deserializes_object_vertices() {
+        let query: Query = serde_json::from_value(json!({
+            "geo_bounding_box": {
+                "pin.location": {
+                    "top_left": { "lat": 40.73, "lon": -74.1 },
+                    "bottom_right": { "lat": 40.01, "lon": -71.12 }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::from(Query::geo_bounding_box(
+                "pin.location",
+                GeoBoundingBox::main_diagonal((40.73, -74.1), (40.01, -71.12)),
+            ))
+        );
     }
 }
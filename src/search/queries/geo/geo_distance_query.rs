@@ -1,18 +1,19 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Matches [geo_point](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-point.html)
 /// and [geo_shape](https://www.elastic.co/guide/en/elasticsearch/reference/current/geo-shape.html)
 /// values within a given distance of a geopoint.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-distance-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct GeoDistanceQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     location: GeoLocation,
 
     distance: Distance,
@@ -38,12 +39,12 @@ impl Query {
     /// - `distance` - Distance threshold
     pub fn geo_distance<T, U, V>(field: T, origin: U, distance: V) -> GeoDistanceQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Into<GeoLocation>,
         V: Into<Distance>,
     {
         GeoDistanceQuery {
-            field: field.to_string(),
+            field: field.into(),
             location: origin.into(),
             distance: distance.into(),
             distance_type: None,
@@ -75,7 +76,10 @@ impl GeoDistanceQuery {
 
 impl ShouldSkip for GeoDistanceQuery {}
 
+impl Validate for GeoDistanceQuery {}
+
 serialize_with_root_key_value_pair!("geo_distance": GeoDistanceQuery, field, location);
+deserialize_with_root_key_value_pair!("geo_distance": GeoDistanceQuery, field, location);
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,274 @@
+use crate::search::*;
+use crate::util::*;
+use std::borrow::Cow;
+
+/// Returns hits that only fall within a polygon of points.
+///
+/// Deprecated in Elasticsearch 7.12 in favour of the
+/// [`geo_shape`](crate::GeoShapeQuery) query, which supports polygons too.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/7.17/query-dsl-geo-polygon-query.html>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
+pub struct GeoPolygonQuery {
+    #[serde(skip)]
+    field: Cow<'static, str>,
+
+    #[serde(skip_serializing)]
+    polygon: GeoPolygon,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    validation_method: Option<ValidationMethod>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    ignore_unmapped: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    boost: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    _name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GeoPolygon {
+    points: Vec<GeoPoint>,
+}
+
+impl Query {
+    /// Creates an instance of [`GeoPolygonQuery`]
+    ///
+    /// - `field` - Field you wish to search.
+    /// - `points` - Vertices of the polygon, the last point must be the same
+    /// as the first one to close the polygon
+    pub fn geo_polygon<T, I>(field: T, points: I) -> GeoPolygonQuery
+    where
+        T: Into<Cow<'static, str>>,
+        I: IntoIterator,
+        I::Item: Into<GeoPoint>,
+    {
+        GeoPolygonQuery {
+            field: field.into(),
+            polygon: GeoPolygon {
+                points: points.into_iter().map(Into::into).collect(),
+            },
+            validation_method: None,
+            ignore_unmapped: None,
+            boost: None,
+            _name: None,
+        }
+    }
+
+    /// Creates an instance of [`GeoPolygonQuery`], same as
+    /// [`Query::geo_polygon`], but fails when the polygon has less than
+    /// 3 points or isn't closed
+    pub fn try_geo_polygon<T, I>(
+        field: T,
+        points: I,
+    ) -> Result<GeoPolygonQuery, Vec<ValidationError>>
+    where
+        T: Into<Cow<'static, str>>,
+        I: IntoIterator,
+        I::Item: Into<GeoPoint>,
+    {
+        let query = Self::geo_polygon(field, points);
+
+        query.validate().map(|_| query)
+    }
+}
+
+impl GeoPolygonQuery {
+    /// Set to `IGNORE_MALFORMED` to accept geo points with invalid latitude or longitude, set to
+    /// `COERCE` to also try to infer correct latitude or longitude. (default is `STRICT`).
+    pub fn validation_method(mut self, validation_method: ValidationMethod) -> Self {
+        self.validation_method = Some(validation_method);
+        self
+    }
+
+    /// When set to `true` the `ignore_unmapped` option will ignore an unmapped field and will
+    /// not match any documents for this query. This can be useful when querying multiple indexes
+    /// which might have different mappings. When set to `false` (the default value) the query
+    /// will throw an exception if the field is not mapped.
+    pub fn ignore_unmapped(mut self, ignore_unmapped: bool) -> Self {
+        self.ignore_unmapped = Some(ignore_unmapped);
+        self
+    }
+
+    add_boost_and_name!();
+}
+
+impl ShouldSkip for GeoPolygonQuery {}
+
+impl Validate for GeoPolygonQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+        let points = &self.polygon.points;
+
+        if points.len() < 4 {
+            errors.push(
+                format!("geo_polygon.{}.points", self.field),
+                "polygon must have at least 3 points followed by the first one",
+            );
+        } else if points.first() != points.last() {
+            errors.push(
+                format!("geo_polygon.{}.points", self.field),
+                "polygon must be closed, the last point must be the same as the first one",
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
+serialize_with_root_key_value_pair!("geo_polygon": GeoPolygonQuery, field, polygon);
+deserialize_with_root_key_value_pair!("geo_polygon": GeoPolygonQuery, field, polygon);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize_query(
+            Query::geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (40.0, -70.0)],
+            ),
+            json!({
+                "geo_polygon": {
+                    "person.location": {
+                        "points": [
+                            { "lat": 40.0, "lon": -70.0 },
+                            { "lat": 30.0, "lon": -80.0 },
+                            { "lat": 20.0, "lon": -90.0 },
+                            { "lat": 40.0, "lon": -70.0 }
+                        ]
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::geo_polygon(
+                "person.location",
+                [
+                    GeoPoint::from(GeoLocation::new(40.0, -70.0)),
+                    GeoPoint::geohash("drn5x1g8cu2y"),
+                    GeoPoint::from(GeoLocation::new(20.0, -90.0)),
+                    GeoPoint::from(GeoLocation::new(40.0, -70.0)),
+                ],
+            )
+            .validation_method(ValidationMethod::Strict)
+            .ignore_unmapped(true)
+            .boost(2)
+            .name("triangle"),
+            json!({
+                "geo_polygon": {
+                    "person.location": {
+                        "points": [
+                            [-70.0, 40.0],
+                            "drn5x1g8cu2y",
+                            [-90.0, 20.0],
+                            [-70.0, 40.0]
+                        ]
+                    },
+                    "validation_method": "STRICT",
+                    "ignore_unmapped": true,
+                    "boost": 2.0,
+                    "_name": "triangle"
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let query: Query = serde_json::from_value(json!({
+            "geo_polygon": {
+                "person.location": {
+                    "points": [
+                        { "lat": 40.0, "lon": -70.0 },
+                        { "lat": 30.0, "lon": -80.0 },
+                        { "lat": 20.0, "lon": -90.0 },
+                        { "lat": 40.0, "lon": -70.0 }
+                    ]
+                },
+                "validation_method": "COERCE"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::from(
+                Query::geo_polygon(
+                    "person.location",
+                    [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (40.0, -70.0)],
+                )
+                .validation_method(ValidationMethod::Coerce)
+            )
+        );
+    }
+
+    #[test]
+    fn fallible_constructor() {
+        assert_eq!(
+            Query::try_geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (40.0, -70.0)],
+            ),
+            Ok(Query::geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (40.0, -70.0)],
+            ))
+        );
+
+        assert_eq!(
+            Query::try_geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (10.0, -70.0)],
+            ),
+            Err(vec![ValidationError::new(
+                "geo_polygon.person.location.points",
+                "polygon must be closed, the last point must be the same as the first one"
+            )])
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (40.0, -70.0)],
+            )
+            .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Query::geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (40.0, -70.0)]
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "geo_polygon.person.location.points",
+                "polygon must have at least 3 points followed by the first one"
+            )])
+        );
+
+        assert_eq!(
+            Query::geo_polygon(
+                "person.location",
+                [(40.0, -70.0), (30.0, -80.0), (20.0, -90.0), (10.0, -70.0)],
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "geo_polygon.person.location.points",
+                "polygon must be closed, the last point must be the same as the first one"
+            )])
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Filter documents indexed using the `geo_shape` or `geo_point` type.
 ///
@@ -15,13 +16,13 @@ use serde::Serialize;
 /// as defined for the field mapping.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-shape-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct GeoShapeLookupQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     shape: Shape,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -34,7 +35,7 @@ pub struct GeoShapeLookupQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Shape {
     indexed_shape: IndexedShape,
 
@@ -42,7 +43,7 @@ struct Shape {
     relation: Option<SpatialRelation>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct IndexedShape {
     id: String,
 
@@ -63,11 +64,11 @@ impl Query {
     /// - `id` - The ID of the document that containing the pre-indexed shape
     pub fn geo_shape_lookup<S, T>(field: S, id: T) -> GeoShapeLookupQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: ToString,
     {
         GeoShapeLookupQuery {
-            field: field.to_string(),
+            field: field.into(),
             shape: Shape {
                 indexed_shape: IndexedShape {
                     id: id.to_string(),
@@ -135,7 +136,10 @@ impl GeoShapeLookupQuery {
 
 impl ShouldSkip for GeoShapeLookupQuery {}
 
+impl Validate for GeoShapeLookupQuery {}
+
 serialize_with_root_key_value_pair!("geo_shape": GeoShapeLookupQuery, field, shape);
+deserialize_with_root_key_value_pair!("geo_shape": GeoShapeLookupQuery, field, shape);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Filter documents indexed using the `geo_shape` or `geo_point` type.
 ///
@@ -15,13 +16,13 @@ use serde::Serialize;
 /// as defined for the field mapping.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-shape-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct GeoShapeQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     shape: InlineShape,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -34,7 +35,7 @@ pub struct GeoShapeQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct InlineShape {
     shape: GeoShape,
 
@@ -49,11 +50,11 @@ impl Query {
     /// - `shape` - Shape you with to search
     pub fn geo_shape<S, T>(field: S, shape: T) -> GeoShapeQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: Into<GeoShape>,
     {
         GeoShapeQuery {
-            field: field.to_string(),
+            field: field.into(),
             shape: InlineShape {
                 shape: shape.into(),
                 relation: None,
@@ -89,7 +90,10 @@ impl GeoShapeQuery {
 
 impl ShouldSkip for GeoShapeQuery {}
 
+impl Validate for GeoShapeQuery {}
+
 serialize_with_root_key_value_pair!("geo_shape": GeoShapeQuery, field, shape);
+deserialize_with_root_key_value_pair!("geo_shape": GeoShapeQuery, field, shape);
 
 #[cfg(test)]
 mod tests {
@@ -12,8 +12,8 @@ use crate::util::*;
 /// Query::has_child("child", Query::term("tag", "elasticsearch"));
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-has-child-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct HasChildQuery {
     r#type: String,
 
@@ -31,6 +31,9 @@ pub struct HasChildQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     score_mode: Option<HasChildScoreMode>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<Box<InnerHits>>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
 
@@ -56,6 +59,7 @@ impl Query {
             max_children: None,
             min_children: None,
             score_mode: None,
+            inner_hits: None,
             boost: None,
             _name: None,
         }
@@ -96,6 +100,15 @@ impl HasChildQuery {
         self
     }
 
+    /// Returns the matching child documents that caused a parent document to be
+    /// returned, see [`InnerHits`]
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html#parent-child-inner-hits>
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits = Some(Box::new(inner_hits));
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -105,7 +118,18 @@ impl ShouldSkip for HasChildQuery {
     }
 }
 
+impl Validate for HasChildQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("has_child.query", &self.query);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("has_child": HasChildQuery);
+deserialize_with_root!("has_child": HasChildQuery);
 
 #[cfg(test)]
 mod tests {
@@ -156,5 +180,40 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_query(
+            Query::has_child("child", Query::term("tag", "elasticsearch"))
+                .inner_hits(InnerHits::new().size(1)),
+            json!({
+                "has_child": {
+                    "type": "child",
+                    "query": {
+                        "term": {
+                            "tag": {
+                                "value": "elasticsearch"
+                            }
+                        }
+                    },
+                    "inner_hits": {
+                        "size": 1
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::has_child(
+                "test",
+                Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3))
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "has_child.query.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
     }
 }
@@ -12,8 +12,8 @@ use crate::util::*;
 /// Query::has_parent("parent", Query::term("tag", "elasticsearch"));
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-HasParent-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct HasParentQuery {
     parent_type: String,
 
@@ -25,6 +25,9 @@ pub struct HasParentQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     ignore_unmapped: Option<bool>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    inner_hits: Option<Box<InnerHits>>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
 
@@ -48,6 +51,7 @@ impl Query {
             query: Box::new(query.into()),
             score: None,
             ignore_unmapped: None,
+            inner_hits: None,
             boost: None,
             _name: None,
         }
@@ -80,6 +84,15 @@ impl HasParentQuery {
         self
     }
 
+    /// Returns the matching parent documents that caused a child document to be
+    /// returned, see [`InnerHits`]
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/inner-hits.html#parent-child-inner-hits>
+    pub fn inner_hits(mut self, inner_hits: InnerHits) -> Self {
+        self.inner_hits = Some(Box::new(inner_hits));
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -89,7 +102,18 @@ impl ShouldSkip for HasParentQuery {
     }
 }
 
+impl Validate for HasParentQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("has_parent.query", &self.query);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("has_parent": HasParentQuery);
+deserialize_with_root!("has_parent": HasParentQuery);
 
 #[cfg(test)]
 mod tests {
@@ -136,5 +160,80 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_query(
+            Query::has_parent("parent", Query::term("tag", "elasticsearch"))
+                .inner_hits(InnerHits::new().size(1)),
+            json!({
+                "has_parent": {
+                    "parent_type": "parent",
+                    "query": {
+                        "term": {
+                            "tag": {
+                                "value": "elasticsearch"
+                            }
+                        }
+                    },
+                    "inner_hits": {
+                        "size": 1
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::has_parent("blog", Query::term("tag", "rust")).score(true),
+            json!({
+                "has_parent": {
+                    "parent_type": "blog",
+                    "query": { "term": { "tag": { "value": "rust" } } },
+                    "score": true
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::has_parent("blog", Query::term("tag", "rust")).score(false),
+            json!({
+                "has_parent": {
+                    "parent_type": "blog",
+                    "query": { "term": { "tag": { "value": "rust" } } },
+                    "score": false
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::has_parent("blog", Query::term("tag", "rust")).ignore_unmapped(true),
+            json!({
+                "has_parent": {
+                    "parent_type": "blog",
+                    "query": { "term": { "tag": { "value": "rust" } } },
+                    "ignore_unmapped": true
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::has_parent("blog", Query::term("tag", None::<String>)).should_skip());
+        assert!(Query::has_parent("", Query::term("tag", "rust")).should_skip());
+        assert!(!Query::has_parent("blog", Query::term("tag", "rust")).should_skip());
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::has_parent(
+                "test",
+                Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3))
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "has_parent.query.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
     }
 }
@@ -28,8 +28,8 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-nested-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct NestedQuery {
     path: String,
 
@@ -135,7 +135,18 @@ impl ShouldSkip for NestedQuery {
     }
 }
 
+impl Validate for NestedQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("nested.query", &self.query);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("nested": NestedQuery);
+deserialize_with_root!("nested": NestedQuery);
 
 #[cfg(test)]
 mod tests {
@@ -205,5 +216,60 @@ mod tests {
                 }
             }),
         );
+
+        for (score_mode, expected) in [
+            (NestedQueryScoreMode::Average, "avg"),
+            (NestedQueryScoreMode::Maximum, "max"),
+            (NestedQueryScoreMode::Minimum, "min"),
+            (NestedQueryScoreMode::None, "none"),
+            (NestedQueryScoreMode::Sum, "sum"),
+        ] {
+            assert_serialize_query(
+                Query::nested("comments", Query::term("comments.author", "kimchy"))
+                    .score_mode(score_mode)
+                    .ignore_unmapped(true)
+                    .inner_hits(InnerHits::new().size(2)),
+                json!({
+                    "nested": {
+                        "path": "comments",
+                        "query": {
+                            "term": {
+                                "comments.author": {
+                                    "value": "kimchy"
+                                }
+                            }
+                        },
+                        "score_mode": expected,
+                        "ignore_unmapped": true,
+                        "inner_hits": {
+                            "size": 2
+                        }
+                    }
+                }),
+            );
+        }
+    }
+
+    #[test]
+    fn should_skip() {
+        assert!(
+            Query::nested("comments", Query::term("comments.author", None::<String>)).should_skip()
+        );
+        assert!(!Query::nested("comments", Query::term("comments.author", "kimchy")).should_skip());
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::nested(
+                "test",
+                Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3))
+            )
+            .validate(),
+            Err(vec![ValidationError::new(
+                "nested.query.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
     }
 }
@@ -12,8 +12,8 @@ use crate::util::*;
 /// Query::parent_id("test", 1);
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-ParentId-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ParentIdQuery {
     r#type: String,
 
@@ -67,11 +67,24 @@ impl ParentIdQuery {
 
 impl ShouldSkip for ParentIdQuery {
     fn should_skip(&self) -> bool {
-        self.r#type.should_skip() || self.id.should_skip()
+        self.id.should_skip()
+    }
+}
+
+impl Validate for ParentIdQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if self.r#type.should_skip() {
+            errors.push("parent_id", "`type` is required");
+        }
+
+        errors.into_result()
     }
 }
 
 serialize_with_root!("parent_id": ParentIdQuery);
+deserialize_with_root!("parent_id": ParentIdQuery);
 
 #[cfg(test)]
 mod tests {
@@ -105,4 +118,23 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn should_skip() {
+        assert!(Query::parent_id("my-child", "").should_skip());
+        assert!(!Query::parent_id("my-child", 1).should_skip());
+        assert!(!Query::parent_id("", 1).should_skip());
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Query::parent_id("my-child", 1).validate(), Ok(()));
+        assert_eq!(
+            Query::parent_id(" ", 1).validate(),
+            Err(vec![ValidationError::new(
+                "parent_id",
+                "`type` is required"
+            )])
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use super::Query;
 use crate::util::*;
+use crate::Validate;
 
 /// The most simple query, which matches all documents, giving them all a
 /// `_score` of `1.0`.
@@ -14,8 +15,8 @@ use crate::util::*;
 ///     .name("matches_everything");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-all-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchAllQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -24,7 +25,10 @@ pub struct MatchAllQuery {
     _name: Option<String>,
 }
 
+impl Validate for MatchAllQuery {}
+
 serialize_with_root!("match_all": MatchAllQuery);
+deserialize_with_root!("match_all": MatchAllQuery);
 
 impl Query {
     /// Creates an instance of [`MatchAllQuery`]
@@ -1,5 +1,6 @@
 use super::Query;
 use crate::util::*;
+use crate::Validate;
 
 /// This is the inverse of the [`match_all`](crate::queries::MatchAllQuery)
 /// query, which matches no documents.
@@ -14,8 +15,8 @@ use crate::util::*;
 ///     .name("matches_nothing");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-all-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MatchNoneQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -35,7 +36,10 @@ impl MatchNoneQuery {
     add_boost_and_name!();
 }
 
+impl Validate for MatchNoneQuery {}
+
 serialize_with_root!("match_none": MatchNoneQuery);
+deserialize_with_root!("match_none": MatchNoneQuery);
 
 impl ShouldSkip for MatchNoneQuery {}
 
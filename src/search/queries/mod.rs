@@ -45,9 +45,70 @@ pub use self::match_none_query::*;
 pub use self::query_collection::*;
 
 use crate::util::*;
+use crate::{Validate, ValidationError};
+use serde::{Deserialize, Deserializer};
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string_pretty(self).map_err(|_| std::fmt::Error)?;
+
+        f.write_str(&json)
+    }
+}
+
+impl Query {
+    /// Serializes the query into pretty-printed JSON, same as its
+    /// [`Display`](std::fmt::Display) implementation
+    pub fn to_json(&self) -> String {
+        self.to_string()
+    }
+
+    /// Converts dynamic JSON into a typed query.
+    ///
+    /// Queries that are not supported yet are kept as [`JsonQuery`], the
+    /// conversion fails only when a supported query is malformed. Unlike
+    /// `From<serde_json::Value>`, which wraps any value into a [`JsonQuery`]
+    /// as is.
+    pub fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl Query {
+    /// Combines two queries into one that matches documents matching both.
+    ///
+    /// Two [`bool`](BoolQuery) queries without `should` clauses are
+    /// [merged](BoolQuery::merge) into a single one, as flattening `should`
+    /// clauses would change which documents match. Any other queries are
+    /// wrapped into the `must` clause of a new `bool` query. Skipped queries
+    /// are ignored.
+    pub fn merge<T>(self, other: T) -> Query
+    where
+        T: Into<Query>,
+    {
+        match (self, other.into()) {
+            (left, right) if right.should_skip() => left,
+            (left, right) if left.should_skip() => right,
+            (Query::Bool(left), Query::Bool(right))
+                if !left.has_should() && !right.has_should() =>
+            {
+                left.merge(right).into()
+            }
+            (left, right) => Query::bool().must([left, right]).into(),
+        }
+    }
+}
+
+impl std::str::FromStr for Query {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
 
 macro_rules! query {
-    ($($variant:ident($query:ty)),+ $(,)?) => {
+    ($($variant:ident($query:ty) $(= $name:literal)?),+ $(,)?) => {
         /// A container enum for supported Elasticsearch query types
         #[derive(Clone, PartialEq, Serialize)]
         #[serde(untagged)]
@@ -81,16 +142,56 @@ macro_rules! query {
             }
         }
 
-        impl std::fmt::Debug for Query {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<'de> Deserialize<'de> for Query {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                let name = match &value {
+                    serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().cloned(),
+                    _ => None,
+                };
+
+                let mut error = None;
+
+                $(
+                    $(
+                        if name.as_deref() == Some($name) {
+                            match <$query as Deserialize>::deserialize(&value) {
+                                Ok(query) => return Ok(Self::$variant(query)),
+                                Err(e) => error = Some(e),
+                            }
+                        }
+                    )?
+                )+
+
+                match (name, error) {
+                    (Some(name), Some(error)) => Err(serde::de::Error::custom(format!(
+                        "invalid `{}` query: {}",
+                        name, error
+                    ))),
+                    _ => Ok(Self::Json(JsonQuery::from(value))),
+                }
+            }
+        }
+
+        impl Validate for Query {
+            fn validate(&self) -> Result<(), Vec<ValidationError>> {
                 match self {
                     $(
-                        Self::$variant(q) => q.fmt(f),
+                        Self::$variant(q) => q.validate(),
                     )+
                 }
             }
         }
 
+        impl std::fmt::Debug for Query {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(self, f)
+            }
+        }
+
         $(
             impl From<$query> for Query {
                 fn from(q: $query) -> Self {
@@ -144,66 +245,67 @@ macro_rules! query {
 }
 
 query!(
-    Bool(BoolQuery),
-    Prefix(PrefixQuery),
-    Regexp(RegexpQuery),
-    Wildcard(WildcardQuery),
-    TermsSet(TermsSetQuery),
-    Term(TermQuery),
-    Terms(TermsQuery),
-    TermsLookup(TermsLookupQuery),
-    Exists(ExistsQuery),
-    Range(RangeQuery),
-    Ids(IdsQuery),
-    ConstantScore(ConstantScoreQuery),
-    DistanceFeatureDate(DistanceFeatureQuery<chrono::DateTime<chrono::Utc>>),
-    DistanceFeatureGeo(DistanceFeatureQuery<crate::GeoLocation>),
-    Match(MatchQuery),
-    MatchBoolPrefix(MatchBoolPrefixQuery),
-    MatchPhrasePrefix(MatchPhrasePrefixQuery),
-    MatchAll(MatchAllQuery),
-    MatchNone(MatchNoneQuery),
-    MatchPhrase(MatchPhraseQuery),
-    MultiMatch(MultiMatchQuery),
-    Nested(NestedQuery),
-    Boosting(BoostingQuery),
-    DisMax(DisMaxQuery),
-    Pinned(PinnedQuery),
-    Percolate(PercolateQuery),
-    PercolateLookup(PercolateLookupQuery),
-    FunctionScore(FunctionScoreQuery),
-    RankFeature(RankFeatureQuery),
-    RankFeatureSaturation(RankFeatureSaturationQuery),
-    RankFeatureLogarithm(RankFeatureLogarithmQuery),
-    RankFeatureSigmoid(RankFeatureSigmoidQuery),
-    RankFeatureLinear(RankFeatureLinearQuery),
-    MoreLikeThis(MoreLikeThisQuery),
-    Fuzzy(FuzzyQuery),
-    GeoDistance(GeoDistanceQuery),
-    GeoBoundingBox(GeoBoundingBoxQuery),
-    GeoShapeLookup(GeoShapeLookupQuery),
-    GeoShape(GeoShapeQuery),
-    ShapeLookup(ShapeLookupQuery),
-    Shape(ShapeQuery),
+    Bool(BoolQuery) = "bool",
+    Prefix(PrefixQuery) = "prefix",
+    Regexp(RegexpQuery) = "regexp",
+    Wildcard(WildcardQuery) = "wildcard",
+    TermsSet(TermsSetQuery) = "terms_set",
+    Term(TermQuery) = "term",
+    Terms(TermsQuery) = "terms",
+    TermsLookup(TermsLookupQuery) = "terms",
+    Exists(ExistsQuery) = "exists",
+    Range(RangeQuery) = "range",
+    Ids(IdsQuery) = "ids",
+    ConstantScore(ConstantScoreQuery) = "constant_score",
+    DistanceFeatureDate(DistanceFeatureQuery<chrono::DateTime<chrono::Utc>>) = "distance_feature",
+    DistanceFeatureGeo(DistanceFeatureQuery<crate::GeoLocation>) = "distance_feature",
+    Match(MatchQuery) = "match",
+    MatchBoolPrefix(MatchBoolPrefixQuery) = "match_bool_prefix",
+    MatchPhrasePrefix(MatchPhrasePrefixQuery) = "match_phrase_prefix",
+    MatchAll(MatchAllQuery) = "match_all",
+    MatchNone(MatchNoneQuery) = "match_none",
+    MatchPhrase(MatchPhraseQuery) = "match_phrase",
+    MultiMatch(MultiMatchQuery) = "multi_match",
+    Nested(NestedQuery) = "nested",
+    Boosting(BoostingQuery) = "boosting",
+    DisMax(DisMaxQuery) = "dis_max",
+    Pinned(PinnedQuery) = "pinned",
+    Percolate(PercolateQuery) = "percolate",
+    PercolateLookup(PercolateLookupQuery) = "percolate",
+    FunctionScore(FunctionScoreQuery) = "function_score",
+    RankFeature(RankFeatureQuery) = "rank_feature",
+    RankFeatureSaturation(RankFeatureSaturationQuery) = "rank_feature",
+    RankFeatureLogarithm(RankFeatureLogarithmQuery) = "rank_feature",
+    RankFeatureSigmoid(RankFeatureSigmoidQuery) = "rank_feature",
+    RankFeatureLinear(RankFeatureLinearQuery) = "rank_feature",
+    MoreLikeThis(MoreLikeThisQuery) = "more_like_this",
+    Fuzzy(FuzzyQuery) = "fuzzy",
+    GeoDistance(GeoDistanceQuery) = "geo_distance",
+    GeoBoundingBox(GeoBoundingBoxQuery) = "geo_bounding_box",
+    GeoPolygon(GeoPolygonQuery) = "geo_polygon",
+    GeoShapeLookup(GeoShapeLookupQuery) = "geo_shape",
+    GeoShape(GeoShapeQuery) = "geo_shape",
+    ShapeLookup(ShapeLookupQuery) = "shape",
+    Shape(ShapeQuery) = "shape",
+    Wrapper(WrapperQuery) = "wrapper",
+    Script(ScriptQuery) = "script",
+    ScriptScore(ScriptScoreQuery) = "script_score",
+    ParentId(ParentIdQuery) = "parent_id",
+    HasParent(HasParentQuery) = "has_parent",
+    HasChild(HasChildQuery) = "has_child",
+    SimpleQueryString(SimpleQueryStringQuery) = "simple_query_string",
+    QueryString(QueryStringQuery) = "query_string",
+    CombinedFields(CombinedFieldsQuery) = "combined_fields",
+    SpanContaining(SpanContainingQuery) = "span_containing",
+    SpanFieldMasking(SpanFieldMaskingQuery) = "span_field_masking",
+    SpanFirst(SpanFirstQuery) = "span_first",
+    SpanMulti(SpanMultiQuery) = "span_multi",
+    SpanNear(SpanNearQuery) = "span_near",
+    SpanNot(SpanNotQuery) = "span_not",
+    SpanOr(SpanOrQuery) = "span_or",
+    SpanTerm(SpanTermQuery) = "span_term",
+    SpanWithin(SpanWithinQuery) = "span_within",
     Json(JsonQuery),
-    Wrapper(WrapperQuery),
-    Script(ScriptQuery),
-    ScriptScore(ScriptScoreQuery),
-    ParentId(ParentIdQuery),
-    HasParent(HasParentQuery),
-    HasChild(HasChildQuery),
-    SimpleQueryString(SimpleQueryStringQuery),
-    QueryString(QueryStringQuery),
-    CombinedFields(CombinedFieldsQuery),
-    SpanContaining(SpanContainingQuery),
-    SpanFieldMasking(SpanFieldMaskingQuery),
-    SpanFirst(SpanFirstQuery),
-    SpanMulti(SpanMultiQuery),
-    SpanNear(SpanNearQuery),
-    SpanNot(SpanNotQuery),
-    SpanOr(SpanOrQuery),
-    SpanTerm(SpanTermQuery),
-    SpanWithin(SpanWithinQuery),
 );
 
 #[cfg(test)]
@@ -221,4 +323,137 @@ mod tests {
             Query::term("field", "value"),
         );
     }
+
+    #[test]
+    fn merges() {
+        assert_eq!(
+            Query::from(Query::term("a", 1)).merge(Query::term("b", 2)),
+            Query::from(Query::bool().must([Query::term("a", 1), Query::term("b", 2)]))
+        );
+
+        assert_eq!(
+            Query::from(Query::bool().filter(Query::term("a", 1)))
+                .merge(Query::bool().filter(Query::term("b", 2))),
+            Query::from(Query::bool().filter([Query::term("a", 1), Query::term("b", 2)]))
+        );
+
+        assert_eq!(
+            Query::from(Query::term("a", 1)).merge(Query::bool()),
+            Query::from(Query::term("a", 1))
+        );
+
+        assert_eq!(
+            Query::from(Query::bool().should([Query::term("a", 1), Query::term("a", 2)]))
+                .merge(Query::bool().should([Query::term("b", 1), Query::term("b", 2)])),
+            Query::from(Query::bool().must([
+                Query::bool().should([Query::term("a", 1), Query::term("a", 2)]),
+                Query::bool().should([Query::term("b", 1), Query::term("b", 2)]),
+            ]))
+        );
+
+        assert_eq!(
+            Query::from(Query::bool().filter(Query::term("a", 1)))
+                .merge(Query::bool().should(Query::term("b", 1))),
+            Query::from(Query::bool().must([
+                Query::bool().filter(Query::term("a", 1)),
+                Query::bool().should(Query::term("b", 1)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn displays_pretty_json() {
+        let query = Query::from(Query::bool().must(Query::term("user.id", "kimchy")));
+
+        let expectation = serde_json::to_string_pretty(&json!({
+            "bool": {
+                "must": [
+                    { "term": { "user.id": { "value": "kimchy" } } }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(query.to_string(), expectation);
+        assert_eq!(format!("{:?}", query), expectation);
+        assert_eq!(query.to_json(), expectation);
+    }
+
+    #[test]
+    fn deserializes_nested_queries() {
+        let query: Query = serde_json::from_value(json!({
+            "bool": {
+                "must": [
+                    { "term": { "user.id": { "value": "kimchy" } } },
+                    { "range": { "age": { "gte": 10, "lte": 20 } } }
+                ],
+                "should": [
+                    { "terms": { "tags": ["env1", "deployed"] } }
+                ],
+                "minimum_should_match": "1",
+                "boost": 1.0
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::bool()
+                .must(Query::term("user.id", "kimchy"))
+                .must(Query::range("age").gte(10).lte(20))
+                .should(Query::terms("tags", ["env1", "deployed"]))
+                .minimum_should_match("1")
+                .boost(1)
+        );
+    }
+
+    #[test]
+    fn converts_from_value() {
+        let value = json!({ "term": { "user.id": { "value": "kimchy" } } });
+
+        assert_eq!(Query::from(value.clone()), Query::json(value.clone()));
+        assert_eq!(
+            Query::from_value(value).unwrap(),
+            Query::term("user.id", "kimchy")
+        );
+    }
+
+    #[test]
+    fn parses_from_str() {
+        let query: Query = r#"{ "match_all": { "boost": 1.5 } }"#.parse().unwrap();
+
+        assert_eq!(query, Query::match_all().boost(1.5));
+    }
+
+    #[test]
+    fn names_attempted_query_type_on_failure() {
+        let error = Query::from_value(json!({ "range": { "age": { "gte": 10, "unknown": 1 } } }))
+            .unwrap_err();
+
+        assert!(
+            error.to_string().starts_with("invalid `range` query: "),
+            "{}",
+            error
+        );
+
+        let error = "{ \"bool\": { \"must\": [{ \"term\": {} }] } }"
+            .parse::<Query>()
+            .unwrap_err();
+
+        assert!(
+            error.to_string().starts_with("invalid `bool` query: "),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn deserializes_unsupported_query_as_json() {
+        let value =
+            json!({ "intervals": { "my_text": { "match": { "query": "my favorite food" } } } });
+
+        let query: Query = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(query, Query::json(value));
+    }
 }
@@ -1,12 +1,14 @@
 use crate::search::*;
 use crate::util::*;
 use chrono::{DateTime, Utc};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error, MapAccess, Visitor};
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
 /// Each document is scored by the defined functions. The parameter `score_mode` specifies how
 /// the computed scores are combined
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionScoreMode {
     /// Scores are multiplied (default)
@@ -36,7 +38,7 @@ impl Default for FunctionScoreMode {
 
 /// The newly computed score is combined with the score of the query. The parameter
 /// `boost_mode` defines how.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionBoostMode {
     /// Query score and function score is multiplied (default)
@@ -67,7 +69,7 @@ impl Default for FunctionBoostMode {
 macro_rules! function {
     ($name:ident { $($variant:ident($query:ty)),+ $(,)? }) => {
         /// Functions available for use in [FunctionScoreQuery](crate::FunctionScoreQuery)
-        #[derive(Debug, Clone, PartialEq, Serialize)]
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         #[allow(missing_docs)]
         #[serde(untagged)]
         pub enum $name {
@@ -76,6 +78,16 @@ macro_rules! function {
             )*
         }
 
+        impl Validate for $name {
+            fn validate(&self) -> Result<(), Vec<ValidationError>> {
+                match self {
+                    $(
+                        Self::$variant(q) => q.validate(),
+                    )+
+                }
+            }
+        }
+
         $(
             impl From<$query> for $name {
                 fn from(q: $query) -> Self {
@@ -172,13 +184,24 @@ impl Function {
 ///
 /// This can sometimes be desired since boost value set on specific queries gets normalized, while
 /// for this score function it does not
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Weight {
     weight: f32,
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     filter: Option<Query>,
 }
 
+impl Validate for Weight {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("filter", &self.filter);
+
+        errors.into_result()
+    }
+}
+
 impl Weight {
     /// Creates an instance of [Weight](Weight)
     pub fn new(weight: f32) -> Self {
@@ -213,7 +236,8 @@ impl Weight {
 /// for all documents. A good default choice might be to use the `_seq_no` field, whose only
 /// drawback is that scores will change if the document is updated since update operations also
 /// update the value of the `_seq_no` field.
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct RandomScore {
     random_score: RandomScoreInner,
 
@@ -224,7 +248,8 @@ pub struct RandomScore {
     weight: Option<f32>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct RandomScoreInner {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     seed: Option<Term>,
@@ -233,6 +258,16 @@ struct RandomScoreInner {
     field: Option<String>,
 }
 
+impl Validate for RandomScore {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("filter", &self.filter);
+
+        errors.into_result()
+    }
+}
+
 impl RandomScore {
     /// Creates an instance of [RandomScore](RandomScore)
     pub fn new() -> Self {
@@ -299,7 +334,8 @@ impl RandomScore {
 /// ```text
 /// sqrt(1.2 * doc['my-int'].value)
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct FieldValueFactor {
     field_value_factor: FieldValueFactorInner,
 
@@ -310,7 +346,8 @@ pub struct FieldValueFactor {
     weight: Option<f32>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct FieldValueFactorInner {
     field: String,
 
@@ -324,6 +361,16 @@ struct FieldValueFactorInner {
     missing: Option<f32>,
 }
 
+impl Validate for FieldValueFactor {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("filter", &self.filter);
+
+        errors.into_result()
+    }
+}
+
 impl FieldValueFactor {
     /// Creates an instance of [FieldValueFactor](FieldValueFactor)
     ///
@@ -387,7 +434,7 @@ impl FieldValueFactor {
 /// Modifier to apply to the field value
 ///
 /// Defaults to [none](FieldValueFactorModifier::None)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum FieldValueFactorModifier {
     /// Do not apply any multiplier to the field value
@@ -433,9 +480,9 @@ pub enum FieldValueFactorModifier {
 }
 
 #[doc(hidden)]
-pub trait Origin: Debug + PartialEq + Serialize + Clone {
-    type Scale: Debug + PartialEq + Serialize + Clone;
-    type Offset: Debug + PartialEq + Serialize + Clone;
+pub trait Origin: Debug + PartialEq + Serialize + DeserializeOwned + Clone {
+    type Scale: Debug + PartialEq + Serialize + DeserializeOwned + Clone;
+    type Offset: Debug + PartialEq + Serialize + DeserializeOwned + Clone;
 }
 
 impl Origin for DateTime<Utc> {
@@ -484,7 +531,8 @@ struct DecayFieldInner<T: Origin> {
     inner: DecayInner<T>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, bound(deserialize = ""))]
 struct DecayInner<O>
 where
     O: Origin,
@@ -573,6 +621,16 @@ where
     }
 }
 
+impl<T: Origin> Validate for Decay<T> {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("filter", &self.filter);
+
+        errors.into_result()
+    }
+}
+
 impl<T: Origin> Serialize for Decay<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -607,10 +665,72 @@ impl<T: Origin> Serialize for DecayFieldInner<T> {
     }
 }
 
+impl<'de, T: Origin> Deserialize<'de> for Decay<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DecayVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Origin> Visitor<'de> for DecayVisitor<T> {
+            type Value = Decay<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a decay function")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut decay = None;
+                let mut filter = None;
+                let mut weight = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "filter" => filter = map.next_value()?,
+                        "weight" => weight = map.next_value()?,
+                        _ => {
+                            let function =
+                                DecayFunction::deserialize(serde::de::value::StrDeserializer::<
+                                    A::Error,
+                                >::new(
+                                    &key
+                                ))?;
+                            let KeyValuePair { key, value } = map.next_value()?;
+
+                            decay = Some((
+                                function,
+                                DecayFieldInner {
+                                    field: key,
+                                    inner: value,
+                                },
+                            ));
+                        }
+                    }
+                }
+
+                let (function, inner) =
+                    decay.ok_or_else(|| A::Error::custom("missing decay function"))?;
+
+                Ok(Decay {
+                    function,
+                    inner,
+                    filter,
+                    weight,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(DecayVisitor(PhantomData))
+    }
+}
+
 /// Decay function variants
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-function-score-query.html#_supported_decay_functions>
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DecayFunction {
     /// Linear decay
@@ -626,17 +746,20 @@ pub enum DecayFunction {
 /// The script_score function allows you to wrap another query and customize the scoring of it
 /// optionally with a computation derived from other numeric field values in the doc using a script
 /// expression
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Script {
     script_score: ScriptInnerWrapper,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct ScriptInnerWrapper {
     script: ScriptInner,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 struct ScriptInner {
     source: String,
 
@@ -669,6 +792,8 @@ impl Script {
     }
 }
 
+impl Validate for Script {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,12 @@
+use crate::search::{Validate, ValidationError, ValidationErrors};
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
 use serde::{Serialize, Serializer};
+use std::convert::TryFrom;
 use std::ops::Range;
+use std::str::FromStr;
+
+/// Maximum edit distance supported by Elasticsearch
+const MAX_DISTANCE: u8 = 2;
 
 /// Some queries and APIs support parameters to allow inexact _fuzzy_ matching,
 /// using the `fuzziness` parameter.
@@ -33,10 +40,44 @@ pub enum Fuzziness {
     /// &nbsp;&nbsp;&nbsp;&nbsp;Two edits allowed
     Range(u8, u8),
 
-    /// The maximum allowed Levenshtein Edit Distance (or number of edits)
+    /// The maximum allowed Levenshtein Edit Distance (or number of edits),
+    /// from `0` to `2`
     Distance(u8),
 }
 
+impl FromStr for Fuzziness {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("AUTO") {
+            return Ok(Self::Auto);
+        }
+
+        let fuzziness = match s.parse() {
+            Ok(distance) => Self::Distance(distance),
+            Err(_) => s
+                .get(..5)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("AUTO:"))
+                .and_then(|_| s[5..].split_once(','))
+                .and_then(|(low, high)| Some(Self::Range(low.parse().ok()?, high.parse().ok()?)))
+                .ok_or_else(|| format!("invalid fuzziness `{s}`"))?,
+        };
+
+        fuzziness
+            .validate()
+            .map(|_| fuzziness)
+            .map_err(|_| format!("invalid fuzziness `{s}`"))
+    }
+}
+
+impl TryFrom<&str> for Fuzziness {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl Serialize for Fuzziness {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -50,6 +91,42 @@ impl Serialize for Fuzziness {
     }
 }
 
+impl<'de> Deserialize<'de> for Fuzziness {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FuzzinessVisitor;
+
+        impl<'de> Visitor<'de> for FuzzinessVisitor {
+            type Value = Fuzziness;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("`AUTO`, `AUTO:[low],[high]` or an edit distance")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                u8::try_from(v)
+                    .map_err(|_| format!("invalid edit distance `{v}`"))
+                    .and_then(Fuzziness::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(FuzzinessVisitor)
+    }
+}
+
 impl From<Range<u8>> for Fuzziness {
     fn from(v: Range<u8>) -> Self {
         Self::Range(v.start, v.end)
@@ -62,9 +139,44 @@ impl From<[u8; 2]> for Fuzziness {
     }
 }
 
-impl From<u8> for Fuzziness {
-    fn from(v: u8) -> Self {
-        Self::Distance(v)
+/// Fails for edit distances greater than 2
+impl TryFrom<u8> for Fuzziness {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > MAX_DISTANCE {
+            Err(format!("invalid edit distance `{value}`"))
+        } else {
+            Ok(Self::Distance(value))
+        }
+    }
+}
+
+impl Validate for Fuzziness {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        match *self {
+            Self::Auto => {}
+            Self::Range(low, high) => {
+                if low > high {
+                    errors.push(
+                        "",
+                        "`AUTO` low distance cannot be greater than high distance",
+                    );
+                }
+            }
+            Self::Distance(distance) => {
+                if distance > MAX_DISTANCE {
+                    errors.push(
+                        "",
+                        format!("edit distance cannot be greater than {MAX_DISTANCE}"),
+                    );
+                }
+            }
+        }
+
+        errors.into_result()
     }
 }
 
@@ -74,12 +186,12 @@ mod tests {
     use crate::util::assert_serialize;
 
     #[test]
-    fn implements_from_u8() {
-        let result = Fuzziness::from(8);
-
-        let expectation = Fuzziness::Distance(8);
-
-        assert_eq!(result, expectation);
+    fn implements_try_from_u8() {
+        assert_eq!(Fuzziness::try_from(2), Ok(Fuzziness::Distance(2)));
+        assert_eq!(
+            Fuzziness::try_from(8),
+            Err("invalid edit distance `8`".to_string())
+        );
     }
 
     #[test]
@@ -91,6 +203,51 @@ mod tests {
         assert_eq!(result, expectation);
     }
 
+    #[test]
+    fn parses_from_str() {
+        assert_eq!("AUTO".parse(), Ok(Fuzziness::Auto));
+        assert_eq!("auto".parse(), Ok(Fuzziness::Auto));
+        assert_eq!("AUTO:3,6".parse(), Ok(Fuzziness::Range(3, 6)));
+        assert_eq!(Fuzziness::try_from("1"), Ok(Fuzziness::Distance(1)));
+        assert_eq!(
+            "AUTO:3".parse::<Fuzziness>(),
+            Err("invalid fuzziness `AUTO:3`".to_string())
+        );
+        assert_eq!(
+            "fuzzy".parse::<Fuzziness>(),
+            Err("invalid fuzziness `fuzzy`".to_string())
+        );
+        assert_eq!(
+            "5".parse::<Fuzziness>(),
+            Err("invalid fuzziness `5`".to_string())
+        );
+        assert_eq!(
+            "AUTO:6,3".parse::<Fuzziness>(),
+            Err("invalid fuzziness `AUTO:6,3`".to_string())
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Fuzziness::Auto.validate(), Ok(()));
+        assert_eq!(Fuzziness::Range(3, 6).validate(), Ok(()));
+        assert_eq!(Fuzziness::Distance(2).validate(), Ok(()));
+        assert_eq!(
+            Fuzziness::Distance(3).validate(),
+            Err(vec![ValidationError::new(
+                "",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+        assert_eq!(
+            Fuzziness::Range(6, 3).validate(),
+            Err(vec![ValidationError::new(
+                "",
+                "`AUTO` low distance cannot be greater than high distance"
+            )])
+        );
+    }
+
     #[test]
     fn serializes() {
         assert_serialize(
@@ -102,4 +259,27 @@ mod tests {
             json!(["AUTO", "AUTO:0,2", 5,]),
         )
     }
+
+    #[test]
+    fn deserializes() {
+        let result: Vec<Fuzziness> =
+            serde_json::from_value(json!(["AUTO", "AUTO:0,2", 1, "2"])).unwrap();
+
+        assert_eq!(
+            result,
+            [
+                Fuzziness::Auto,
+                Fuzziness::Range(0, 2),
+                Fuzziness::Distance(1),
+                Fuzziness::Distance(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_values_on_deserialization() {
+        assert!(serde_json::from_value::<Fuzziness>(json!(5)).is_err());
+        assert!(serde_json::from_value::<Fuzziness>(json!("3")).is_err());
+        assert!(serde_json::from_value::<Fuzziness>(json!("AUTO:6,3")).is_err());
+    }
 }
@@ -2,7 +2,7 @@ use crate::search::*;
 use serde::Serialize;
 
 /// Strategies to verify the correctness of coordinates
-#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ValidationMethod {
     /// accept geo points with invalid latitude or longitude
@@ -16,23 +16,23 @@ pub enum ValidationMethod {
 }
 
 /// Different representations of geo bounding box
-#[derive(Debug, PartialEq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum GeoBoundingBox {
     /// MainDiagonal vertices of geo bounding box
     MainDiagonal {
         /// The coordinates of the upper left vertex
-        top_left: GeoLocation,
+        top_left: GeoPoint,
         /// The coordinates of the lower right vertex
-        bottom_right: GeoLocation,
+        bottom_right: GeoPoint,
     },
 
     /// SubDiagonal vertices of geo bounding box
     SubDiagonal {
         /// The coordinates of the upper right vertex
-        top_right: GeoLocation,
+        top_right: GeoPoint,
         /// The coordinates of the lower left vertex
-        bottom_left: GeoLocation,
+        bottom_left: GeoPoint,
     },
 
     /// Well-Known Text (WKT).
@@ -57,6 +57,34 @@ pub enum GeoBoundingBox {
     },
 }
 
+impl GeoBoundingBox {
+    /// Creates a [`GeoBoundingBox::MainDiagonal`] from its upper left and
+    /// lower right vertices
+    pub fn main_diagonal<T, U>(top_left: T, bottom_right: U) -> Self
+    where
+        T: Into<GeoPoint>,
+        U: Into<GeoPoint>,
+    {
+        Self::MainDiagonal {
+            top_left: top_left.into(),
+            bottom_right: bottom_right.into(),
+        }
+    }
+
+    /// Creates a [`GeoBoundingBox::SubDiagonal`] from its upper right and
+    /// lower left vertices
+    pub fn sub_diagonal<T, U>(top_right: T, bottom_left: U) -> Self
+    where
+        T: Into<GeoPoint>,
+        U: Into<GeoPoint>,
+    {
+        Self::SubDiagonal {
+            top_right: top_right.into(),
+            bottom_left: bottom_left.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,16 +93,30 @@ mod tests {
     #[test]
     fn serialization() {
         assert_serialize(
-            GeoBoundingBox::MainDiagonal {
-                top_left: GeoLocation::new(40.73, -74.1),
-                bottom_right: GeoLocation::new(40.01, -71.12),
-            },
+            GeoBoundingBox::main_diagonal(
+                GeoLocation::new(40.73, -74.1),
+                GeoLocation::new(40.01, -71.12),
+            ),
             json!({
                 "top_left": [-74.1, 40.73],
                 "bottom_right": [-71.12, 40.01]
             }),
         );
 
+        assert_serialize(
+            GeoBoundingBox::sub_diagonal(
+                GeoPoint::geohash("dr5r9ydj2y73"),
+                GeoPoint::Wkt {
+                    lon: -74.1,
+                    lat: 40.01,
+                },
+            ),
+            json!({
+                "top_right": "dr5r9ydj2y73",
+                "bottom_left": "POINT (-74.1 40.01)"
+            }),
+        );
+
         assert_serialize(
             GeoBoundingBox::WellKnownText {
                 wkt: "BBOX (-74.1, -71.12, 40.73, 40.01)".into(),
@@ -0,0 +1,265 @@
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use serde::{Serialize, Serializer};
+use std::convert::TryFrom;
+
+/// The minimum number or percentage of optional clauses that must match for a
+/// document to be returned.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-minimum-should-match.html>
+#[derive(Debug, Clone, PartialEq)]
+pub enum MinimumShouldMatch {
+    /// Indicates a fixed value regardless of the number of optional clauses,
+    /// e.g. `3`
+    Count(i32),
+
+    /// Indicates that this percent of the total number of optional clauses
+    /// are necessary, rounded down, e.g. `75%`
+    Percentage(f32),
+
+    /// Indicates that the total number of optional clauses, minus this
+    /// number, should be mandatory, e.g. `-2`
+    NegativeCount(i32),
+
+    /// Indicates that this percent of the total number of optional clauses
+    /// can be missing, rounded down, e.g. `-25%`
+    NegativePercentage(f32),
+
+    /// Conditional specifications such as `3<90%` or `2<-25% 9<-3`, where
+    /// the value following `<` applies only when the number of optional
+    /// clauses is greater than the integer preceding it
+    CombinedExpression(String),
+}
+
+impl std::fmt::Display for MinimumShouldMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count(count) => count.fmt(f),
+            Self::Percentage(percentage) => write!(f, "{percentage}%"),
+            Self::NegativeCount(count) => write!(f, "-{}", count.abs()),
+            Self::NegativePercentage(percentage) => write!(f, "-{}%", percentage.abs()),
+            Self::CombinedExpression(expression) => expression.fmt(f),
+        }
+    }
+}
+
+impl Serialize for MinimumShouldMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MinimumShouldMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MinimumShouldMatchVisitor;
+
+        impl<'de> Visitor<'de> for MinimumShouldMatchVisitor {
+            type Value = MinimumShouldMatch;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("an integer, a percentage or a combination expression")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                i32::try_from(v)
+                    .map(MinimumShouldMatch::from)
+                    .map_err(|_| E::custom(format!("invalid minimum should match `{v}`")))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                i32::try_from(v)
+                    .map(MinimumShouldMatch::from)
+                    .map_err(|_| E::custom(format!("invalid minimum should match `{v}`")))
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(MinimumShouldMatch::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(MinimumShouldMatchVisitor)
+    }
+}
+
+impl MinimumShouldMatch {
+    /// Creates a percentage of optional clauses, negative values indicate
+    /// the percentage of clauses that can be missing, e.g. `75.0` for `75%`
+    /// and `-25.0` for `-25%`
+    pub fn percentage(percentage: f32) -> Self {
+        if percentage < 0.0 {
+            Self::NegativePercentage(percentage.abs())
+        } else {
+            Self::Percentage(percentage)
+        }
+    }
+}
+
+impl From<i32> for MinimumShouldMatch {
+    fn from(v: i32) -> Self {
+        if v < 0 {
+            Self::NegativeCount(v.saturating_abs())
+        } else {
+            Self::Count(v)
+        }
+    }
+}
+
+/// Treats the value as a percentage, e.g. `75.0` for `75%`
+impl From<f32> for MinimumShouldMatch {
+    fn from(v: f32) -> Self {
+        Self::percentage(v)
+    }
+}
+
+macro_rules! impl_from_integers {
+    ($($ty:ty),+) => {
+        $(
+            /// Saturates counts out of [`i32`] range
+            impl From<$ty> for MinimumShouldMatch {
+                fn from(v: $ty) -> Self {
+                    Self::from((v as i128).clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integers!(i8, i16, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<&str> for MinimumShouldMatch {
+    fn from(v: &str) -> Self {
+        let trimmed = v.trim();
+
+        if let Some(percentage) = trimmed.strip_suffix('%') {
+            if let Ok(percentage) = percentage.parse::<f32>() {
+                return Self::percentage(percentage);
+            }
+        } else if let Ok(count) = trimmed.parse::<i32>() {
+            return Self::from(count);
+        }
+
+        Self::CombinedExpression(v.to_string())
+    }
+}
+
+impl From<String> for MinimumShouldMatch {
+    fn from(v: String) -> Self {
+        Self::from(v.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn implements_from() {
+        assert_eq!(MinimumShouldMatch::from(3), MinimumShouldMatch::Count(3));
+        assert_eq!(
+            MinimumShouldMatch::from(-2),
+            MinimumShouldMatch::NegativeCount(2)
+        );
+        assert_eq!(MinimumShouldMatch::from(3u8), MinimumShouldMatch::Count(3));
+        assert_eq!(MinimumShouldMatch::from(3u32), MinimumShouldMatch::Count(3));
+        assert_eq!(
+            MinimumShouldMatch::from(3usize),
+            MinimumShouldMatch::Count(3)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from(-2i64),
+            MinimumShouldMatch::NegativeCount(2)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from(u64::MAX),
+            MinimumShouldMatch::Count(i32::MAX)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from(i64::MIN),
+            MinimumShouldMatch::NegativeCount(i32::MAX)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from(75.0),
+            MinimumShouldMatch::Percentage(75.0)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from(-25.0),
+            MinimumShouldMatch::NegativePercentage(25.0)
+        );
+        assert_eq!(
+            MinimumShouldMatch::percentage(75.0),
+            MinimumShouldMatch::Percentage(75.0)
+        );
+        assert_eq!(
+            MinimumShouldMatch::percentage(-25.0),
+            MinimumShouldMatch::NegativePercentage(25.0)
+        );
+        assert_eq!(MinimumShouldMatch::from("3"), MinimumShouldMatch::Count(3));
+        assert_eq!(
+            MinimumShouldMatch::from("-2"),
+            MinimumShouldMatch::NegativeCount(2)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from("75%"),
+            MinimumShouldMatch::Percentage(75.0)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from("-25%"),
+            MinimumShouldMatch::NegativePercentage(25.0)
+        );
+        assert_eq!(
+            MinimumShouldMatch::from("3<90%"),
+            MinimumShouldMatch::CombinedExpression("3<90%".to_string())
+        );
+        assert_eq!(
+            MinimumShouldMatch::from("2<-25% 9<-3"),
+            MinimumShouldMatch::CombinedExpression("2<-25% 9<-3".to_string())
+        );
+    }
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            [
+                MinimumShouldMatch::Count(3),
+                MinimumShouldMatch::Percentage(75.0),
+                MinimumShouldMatch::Percentage(12.5),
+                MinimumShouldMatch::NegativeCount(2),
+                MinimumShouldMatch::NegativePercentage(25.0),
+                MinimumShouldMatch::CombinedExpression("2<-25% 9<-3".to_string()),
+            ],
+            json!(["3", "75%", "12.5%", "-2", "-25%", "2<-25% 9<-3"]),
+        )
+    }
+
+    #[test]
+    fn deserializes() {
+        let result: Vec<MinimumShouldMatch> =
+            serde_json::from_value(json!([3, -2, "75%", "-25%", "3<90%"])).unwrap();
+
+        assert_eq!(
+            result,
+            [
+                MinimumShouldMatch::Count(3),
+                MinimumShouldMatch::NegativeCount(2),
+                MinimumShouldMatch::Percentage(75.0),
+                MinimumShouldMatch::NegativePercentage(25.0),
+                MinimumShouldMatch::CombinedExpression("3<90%".to_string()),
+            ]
+        );
+    }
+}
@@ -3,11 +3,11 @@
 // Common parameters
 mod fuzziness;
 mod has_child_query;
-mod inner_hits;
+mod minimum_should_match;
+mod multi_match_type;
 mod negative_boost;
 mod operator;
 mod rewrite;
-mod script_object;
 mod stored_fields;
 mod zero_terms_query;
 
@@ -22,14 +22,14 @@ mod regexp_query;
 mod shape_query;
 mod simple_query_string_query;
 mod terms_set_query;
-mod text_query_type;
 
 // Public re-exports
 pub use self::function_score_query::*;
 pub use self::fuzziness::*;
 pub use self::geo_query::*;
 pub use self::has_child_query::*;
-pub use self::inner_hits::*;
+pub use self::minimum_should_match::*;
+pub use self::multi_match_type::*;
 pub use self::negative_boost::*;
 pub use self::nested_query::*;
 pub use self::operator::*;
@@ -38,10 +38,11 @@ pub use self::pinned_query::*;
 pub use self::range_query::*;
 pub use self::regexp_query::*;
 pub use self::rewrite::*;
-pub use self::script_object::*;
 pub use self::shape_query::*;
 pub use self::simple_query_string_query::*;
 pub use self::stored_fields::*;
 pub use self::terms_set_query::*;
-pub use self::text_query_type::*;
 pub use self::zero_terms_query::*;
+
+// Shared with aggregations, sorting and script fields, kept here for backwards compatibility
+pub use crate::search::params::{InnerHits, Script, ScriptLang, ScriptSource};
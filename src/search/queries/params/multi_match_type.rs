@@ -1,12 +1,13 @@
 /// The way the `multi_match` query is executed internally.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-match-query.html#multi-match-types>
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-pub enum TextQueryType {
+pub enum MultiMatchType {
     /// Finds documents which match any field, but uses the `_score` from the
     /// best field. See
     /// [`best_fields`](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-match-query.html#type-best-fields).
+    #[default]
     BestFields,
 
     /// Finds documents which match any field and combines the `_score` from
@@ -34,3 +35,21 @@ pub enum TextQueryType {
     /// [`bool_prefix`](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-multi-match-query.html#type-bool-prefix).
     BoolPrefix,
 }
+
+impl MultiMatchType {
+    /// Whether the type runs a phrase query on each field, in which case
+    /// `slop` can be used
+    pub fn is_phrase(self) -> bool {
+        matches!(self, Self::Phrase | Self::PhrasePrefix)
+    }
+
+    /// Whether the type matches the last term as a prefix, in which case
+    /// `max_expansions` limits the number of expanded terms
+    pub fn is_prefix(self) -> bool {
+        matches!(self, Self::PhrasePrefix | Self::BoolPrefix)
+    }
+}
+
+/// Former name of [`MultiMatchType`]
+#[deprecated(note = "renamed to `MultiMatchType`")]
+pub type TextQueryType = MultiMatchType;
@@ -1,5 +1,5 @@
 /// Boolean logic used to interpret text in the `query` value
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Operator {
     /// For example, a `query` value of `capital of Hungary` is interpreted as
@@ -1,7 +1,7 @@
 use crate::util::*;
 
 /// Values that can be percolated
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PercolateSource {
     /// A document
@@ -1,5 +1,5 @@
 /// Indicates how the range query matches values for range fields.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum RangeRelation {
     /// Matches documents with a range field value that intersects the query’s range.
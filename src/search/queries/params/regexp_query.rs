@@ -1,4 +1,7 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
+use std::iter::FromIterator;
+use std::ops::BitOr;
 
 /// You can use the flags parameter to enable more optional operators for Lucene’s regular
 /// expression engine.
@@ -13,6 +16,16 @@ pub enum RegexpFlag {
     /// `a~bc   # matches 'adc' and 'aec' but not 'abc'`
     Complement,
 
+    /// Enables the `#` (empty language) operator. The `#` operator doesn't match any string, not
+    /// even an empty string.
+    ///
+    /// If you create regular expressions by programmatically combining values, you can pass `#` to
+    /// specify "no string." This lets you avoid accidentally matching empty strings or other
+    /// unwanted strings. For example:
+    ///
+    /// `#|abc  # matches 'abc' but nothing else, not even an empty string`
+    Empty,
+
     /// Enables the `<>` operators. You can use `<>` to match a numeric range. For example:
     ///
     /// `foo<1-100>      # matches 'foo1', 'foo2' ... 'foo99', 'foo100'`
@@ -32,6 +45,9 @@ pub enum RegexpFlag {
     ///
     /// `@&~(abc.+)  # matches everything except terms beginning with 'abc'`
     Anystring,
+
+    /// Disables all optional operators.
+    None,
 }
 
 impl From<RegexpFlag> for &'static str {
@@ -39,9 +55,11 @@ impl From<RegexpFlag> for &'static str {
         match value {
             RegexpFlag::All => "ALL",
             RegexpFlag::Complement => "COMPLEMENT",
+            RegexpFlag::Empty => "EMPTY",
             RegexpFlag::Interval => "INTERVAL",
             RegexpFlag::Intersection => "INTERSECTION",
             RegexpFlag::Anystring => "ANYSTRING",
+            RegexpFlag::None => "NONE",
         }
     }
 }
@@ -66,3 +84,199 @@ impl Serialize for RegexpFlag {
         <&'static str>::from(*self).serialize(serializer)
     }
 }
+
+impl std::str::FromStr for RegexpFlag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ALL" => Ok(Self::All),
+            "COMPLEMENT" => Ok(Self::Complement),
+            "EMPTY" => Ok(Self::Empty),
+            "INTERVAL" => Ok(Self::Interval),
+            "INTERSECTION" => Ok(Self::Intersection),
+            "ANYSTRING" => Ok(Self::Anystring),
+            "NONE" => Ok(Self::None),
+            _ => Err(format!("unknown flag `{}`", s)),
+        }
+    }
+}
+
+impl RegexpFlag {
+    const VARIANTS: [Self; 7] = [
+        Self::All,
+        Self::Complement,
+        Self::Empty,
+        Self::Interval,
+        Self::Intersection,
+        Self::Anystring,
+        Self::None,
+    ];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl BitOr for RegexpFlag {
+    type Output = RegexpFlags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        RegexpFlags::from(self) | rhs
+    }
+}
+
+/// A set of [`RegexpFlag`]s, serialized as a `|` separated string with every
+/// flag appearing once, in declaration order.
+///
+/// ```
+/// # use elasticsearch_dsl::queries::params::*;
+/// let flags = RegexpFlag::Complement | RegexpFlag::Interval | RegexpFlag::Complement;
+///
+/// assert!(flags.contains(RegexpFlag::Interval));
+/// assert!(!flags.contains(RegexpFlag::Empty));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RegexpFlags(u8);
+
+impl RegexpFlags {
+    /// Creates an empty set of flags
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns `true` if no flags are set
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the flag is set
+    pub const fn contains(self, flag: RegexpFlag) -> bool {
+        self.0 & flag.bit() != 0
+    }
+
+    /// Sets the flag, setting it again has no effect
+    pub fn insert(&mut self, flag: RegexpFlag) {
+        self.0 |= flag.bit();
+    }
+
+    /// Iterates over the set flags in declaration order
+    pub fn iter(self) -> impl Iterator<Item = RegexpFlag> {
+        RegexpFlag::VARIANTS
+            .iter()
+            .copied()
+            .filter(move |flag| self.contains(*flag))
+    }
+}
+
+impl From<RegexpFlag> for RegexpFlags {
+    fn from(value: RegexpFlag) -> Self {
+        Self(value.bit())
+    }
+}
+
+impl<T> BitOr<T> for RegexpFlags
+where
+    T: Into<RegexpFlags>,
+{
+    type Output = Self;
+
+    fn bitor(self, rhs: T) -> Self::Output {
+        Self(self.0 | rhs.into().0)
+    }
+}
+
+impl Extend<RegexpFlag> for RegexpFlags {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = RegexpFlag>,
+    {
+        for flag in iter {
+            self.insert(flag);
+        }
+    }
+}
+
+impl FromIterator<RegexpFlag> for RegexpFlags {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RegexpFlag>,
+    {
+        let mut flags = Self::empty();
+        flags.extend(iter);
+        flags
+    }
+}
+
+impl IntoIterator for RegexpFlags {
+    type Item = RegexpFlag;
+    type IntoIter = std::vec::IntoIter<RegexpFlag>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl std::fmt::Display for RegexpFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.iter()
+            .map(<&'static str>::from)
+            .collect::<Vec<_>>()
+            .join("|")
+            .fmt(f)
+    }
+}
+
+impl Serialize for RegexpFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RegexpFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .split('|')
+            .filter(|flag| !flag.is_empty())
+            .map(|flag| flag.parse::<RegexpFlag>().map_err(Error::custom))
+            .collect()
+    }
+}
+
+impl crate::util::ShouldSkip for RegexpFlags {
+    fn should_skip(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            RegexpFlag::Interval | RegexpFlag::All | RegexpFlag::Interval,
+            json!("ALL|INTERVAL"),
+        );
+
+        assert_serialize(RegexpFlags::empty(), json!(""));
+    }
+
+    #[test]
+    fn deserialization() {
+        assert_eq!(
+            serde_json::from_value::<RegexpFlags>(json!("EMPTY|COMPLEMENT")).unwrap(),
+            RegexpFlag::Complement | RegexpFlag::Empty
+        );
+
+        assert!(serde_json::from_value::<RegexpFlags>(json!("ALL|UNKNOWN")).is_err());
+    }
+}
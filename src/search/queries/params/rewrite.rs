@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
 
 /// Method used to rewrite the query.
@@ -37,6 +38,10 @@ pub enum Rewrite {
     /// setting. If the query exceeds this limit, Elasticsearch returns an error.
     ConstantScoreBoolean,
 
+    /// Legacy name of [`ConstantScore`](Self::ConstantScore), still
+    /// accepted by Elasticsearch for backwards compatibility.
+    ConstantScoreFilter,
+
     /// Calculates a relevance score for each matching document.
     ///
     /// This method changes the original query to a
@@ -107,6 +112,7 @@ impl Serialize for Rewrite {
         match self {
             Self::ConstantScore => "constant_score".serialize(serializer),
             Self::ConstantScoreBoolean => "constant_score_boolean".serialize(serializer),
+            Self::ConstantScoreFilter => "constant_score_filter".serialize(serializer),
             Self::ScoringBoolean => "scoring_boolean".serialize(serializer),
             Self::TopTermsBlendedFrequencies(n) => {
                 format!("top_terms_blended_freqs_{n}").serialize(serializer)
@@ -116,3 +122,85 @@ impl Serialize for Rewrite {
         }
     }
 }
+
+impl<'de> Deserialize<'de> for Rewrite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        let top_terms = |prefix: &str| value.strip_prefix(prefix).and_then(|n| n.parse().ok());
+
+        match value.as_str() {
+            "constant_score" => Ok(Self::ConstantScore),
+            "constant_score_boolean" => Ok(Self::ConstantScoreBoolean),
+            "constant_score_filter" => Ok(Self::ConstantScoreFilter),
+            "scoring_boolean" => Ok(Self::ScoringBoolean),
+            _ => top_terms("top_terms_blended_freqs_")
+                .map(Self::TopTermsBlendedFrequencies)
+                .or_else(|| top_terms("top_terms_boost_").map(Self::TopTermsBoost))
+                .or_else(|| top_terms("top_terms_").map(Self::TopTerms))
+                .ok_or_else(|| D::Error::custom(format!("invalid rewrite method `{value}`"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serializes() {
+        assert_serialize(
+            [
+                Rewrite::ConstantScore,
+                Rewrite::ConstantScoreBoolean,
+                Rewrite::ConstantScoreFilter,
+                Rewrite::ScoringBoolean,
+                Rewrite::TopTerms(10),
+                Rewrite::TopTermsBoost(20),
+                Rewrite::TopTermsBlendedFrequencies(30),
+            ],
+            json!([
+                "constant_score",
+                "constant_score_boolean",
+                "constant_score_filter",
+                "scoring_boolean",
+                "top_terms_10",
+                "top_terms_boost_20",
+                "top_terms_blended_freqs_30",
+            ]),
+        );
+    }
+
+    #[test]
+    fn deserializes() {
+        let result: Vec<Rewrite> = serde_json::from_value(json!([
+            "constant_score",
+            "constant_score_boolean",
+            "constant_score_filter",
+            "scoring_boolean",
+            "top_terms_10",
+            "top_terms_boost_20",
+            "top_terms_blended_freqs_30",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            result,
+            [
+                Rewrite::ConstantScore,
+                Rewrite::ConstantScoreBoolean,
+                Rewrite::ConstantScoreFilter,
+                Rewrite::ScoringBoolean,
+                Rewrite::TopTerms(10),
+                Rewrite::TopTermsBoost(20),
+                Rewrite::TopTermsBlendedFrequencies(30),
+            ]
+        );
+
+        assert!(serde_json::from_value::<Rewrite>(json!("top_terms_")).is_err());
+        assert!(serde_json::from_value::<Rewrite>(json!("constant_score_bitset")).is_err());
+    }
+}
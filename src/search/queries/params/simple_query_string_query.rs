@@ -1,7 +1,8 @@
 use serde::ser::{Serialize, Serializer};
 
-/// You can use the flags parameter to enable more optional operators for Lucene’s regular
-/// expression engine.
+/// Operators that can be enabled for the
+/// [simple query string syntax](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-simple-query-string-query.html#supported-flags)
+/// through the `flags` parameter, serialized as a pipe-delimited string such as `OR|AND|PREFIX`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SimpleQueryStringQueryFlags {
     /// Enables all optional operators.
@@ -90,3 +91,26 @@ impl Serialize for SimpleQueryStringQueryFlags {
         <&'static str>::from(*self).serialize(serializer)
     }
 }
+
+impl std::str::FromStr for SimpleQueryStringQueryFlags {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ALL" => Ok(Self::All),
+            "AND" => Ok(Self::And),
+            "ESCAPE" => Ok(Self::Escape),
+            "FUZZY" => Ok(Self::Fuzzy),
+            "NEAR" => Ok(Self::Near),
+            "NONE" => Ok(Self::None),
+            "NOT" => Ok(Self::Not),
+            "OR" => Ok(Self::Or),
+            "PHRASE" => Ok(Self::Phrase),
+            "PRECEDENCE" => Ok(Self::Precedence),
+            "PREFIX" => Ok(Self::Prefix),
+            "SLOP" => Ok(Self::Slop),
+            "WHITESPACE" => Ok(Self::Whitespace),
+            _ => Err(format!("unknown flag `{}`", s)),
+        }
+    }
+}
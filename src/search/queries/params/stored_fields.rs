@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{types::Set, util::ShouldSkip};
 
@@ -41,6 +41,25 @@ impl Serialize for StoredFields {
     }
 }
 
+impl<'de> Deserialize<'de> for StoredFields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Fields {
+            One(String),
+            Many(Set<String>),
+        }
+
+        match Fields::deserialize(deserializer)? {
+            Fields::One(field) => Ok(Self::from([field])),
+            Fields::Many(fields) => Ok(Self::from(fields)),
+        }
+    }
+}
+
 impl<T> From<T> for StoredFields
 where
     T: IntoIterator,
@@ -1,6 +1,6 @@
 /// Indicates whether no documents are returned if the `analyzer` removes all
 /// tokens, such as when using a `stop` filter.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ZeroTermsQuery {
     /// No documents are returned if the `analyzer` removes all tokens.
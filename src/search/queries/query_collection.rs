@@ -1,8 +1,9 @@
 use super::Query;
 use crate::util::ShouldSkip;
+use crate::{Validate, ValidationError, ValidationErrors};
 
 /// A collection of queries
-#[derive(Default, Clone, PartialEq, Serialize)]
+#[derive(Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QueryCollection(Vec<Query>);
 
 impl std::fmt::Debug for QueryCollection {
@@ -27,6 +28,18 @@ impl ShouldSkip for QueryCollection {
     }
 }
 
+impl Validate for QueryCollection {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (index, query) in self.0.iter().enumerate() {
+            errors.nested(&format!("[{}]", index), query);
+        }
+
+        errors.into_result()
+    }
+}
+
 impl QueryCollection {
     /// Extends query collection
     pub fn extend<T>(&mut self, query: T)
@@ -1,19 +1,20 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Queries documents that contain fields indexed using the `shape` type.
 ///
 /// Requires the [`shape` Mapping](https://www.elastic.co/guide/en/elasticsearch/reference/current/shape.html).
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-shape-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ShapeLookupQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     shape: Shape,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -26,7 +27,7 @@ pub struct ShapeLookupQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Shape {
     indexed_shape: IndexedShape,
 
@@ -34,7 +35,7 @@ struct Shape {
     relation: Option<SpatialRelation>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct IndexedShape {
     id: String,
 
@@ -55,11 +56,11 @@ impl Query {
     /// - `id` - The ID of the document that containing the pre-indexed shape
     pub fn shape_lookup<S, T>(field: S, id: T) -> ShapeLookupQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: ToString,
     {
         ShapeLookupQuery {
-            field: field.to_string(),
+            field: field.into(),
             shape: Shape {
                 indexed_shape: IndexedShape {
                     id: id.to_string(),
@@ -127,7 +128,10 @@ impl ShapeLookupQuery {
 
 impl ShouldSkip for ShapeLookupQuery {}
 
+impl Validate for ShapeLookupQuery {}
+
 serialize_with_root_key_value_pair!("shape": ShapeLookupQuery, field, shape);
+deserialize_with_root_key_value_pair!("shape": ShapeLookupQuery, field, shape);
 
 #[cfg(test)]
 mod tests {
@@ -1,19 +1,20 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Queries documents that contain fields indexed using the `shape` type.
 ///
 /// Requires the [`shape` Mapping](https://www.elastic.co/guide/en/elasticsearch/reference/current/shape.html).
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-shape-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ShapeQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     shape: InlineShape,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -26,7 +27,7 @@ pub struct ShapeQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct InlineShape {
     shape: Shape,
 
@@ -41,11 +42,11 @@ impl Query {
     /// - `shape` - Shape you with to search
     pub fn shape<S, T>(field: S, shape: T) -> ShapeQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: Into<Shape>,
     {
         ShapeQuery {
-            field: field.to_string(),
+            field: field.into(),
             shape: InlineShape {
                 shape: shape.into(),
                 relation: None,
@@ -81,7 +82,10 @@ impl ShapeQuery {
 
 impl ShouldSkip for ShapeQuery {}
 
+impl Validate for ShapeQuery {}
+
 serialize_with_root_key_value_pair!("shape": ShapeQuery, field, shape);
+deserialize_with_root_key_value_pair!("shape": ShapeQuery, field, shape);
 
 #[cfg(test)]
 mod tests {
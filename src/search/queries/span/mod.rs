@@ -31,12 +31,14 @@ pub use self::span_term_query::*;
 pub use self::span_within_query::*;
 
 use crate::util::*;
-use crate::{FuzzyQuery, PrefixQuery, RangeQuery, RegexpQuery, WildcardQuery};
+use crate::{
+    FuzzyQuery, PrefixQuery, RangeQuery, RegexpQuery, Validate, ValidationError, WildcardQuery,
+};
 
 macro_rules! span_query {
     ($($variant:ident($query:ty)),+ $(,)?) => {
         /// A container enum for supported Elasticsearch query types
-        #[derive(Clone, PartialEq, Serialize)]
+        #[derive(Clone, PartialEq, Serialize, Deserialize)]
         #[serde(untagged)]
         #[allow(missing_docs)]
         pub enum SpanQuery {
@@ -68,6 +70,16 @@ macro_rules! span_query {
             }
         }
 
+        impl Validate for SpanQuery {
+            fn validate(&self) -> Result<(), Vec<ValidationError>> {
+                match self {
+                    $(
+                        Self::$variant(q) => q.validate(),
+                    )+
+                }
+            }
+        }
+
         impl std::fmt::Debug for SpanQuery {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
@@ -119,7 +131,7 @@ macro_rules! span_query {
 macro_rules! multi_term_query {
     ($($variant:ident($query:ty)),+ $(,)?) => {
         /// A container enum for supported Elasticsearch query types
-        #[derive(Clone, PartialEq, Serialize)]
+        #[derive(Clone, PartialEq, Serialize, Deserialize)]
         #[serde(untagged)]
         #[allow(missing_docs)]
         pub enum MultiTermQuery {
@@ -151,6 +163,16 @@ macro_rules! multi_term_query {
             }
         }
 
+        impl Validate for MultiTermQuery {
+            fn validate(&self) -> Result<(), Vec<ValidationError>> {
+                match self {
+                    $(
+                        Self::$variant(q) => q.validate(),
+                    )+
+                }
+            }
+        }
+
         impl std::fmt::Debug for MultiTermQuery {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
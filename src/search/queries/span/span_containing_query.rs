@@ -1,6 +1,6 @@
 use super::SpanQuery;
 use crate::util::*;
-use crate::Query;
+use crate::{Query, Validate, ValidationError, ValidationErrors};
 
 /// Returns matches which enclose another span query. The span containing query maps to Lucene
 /// `SpanContainingQuery`. <br/>
@@ -8,8 +8,8 @@ use crate::Query;
 /// contain matches from `little` are returned.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-containing-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanContainingQuery {
     little: Box<SpanQuery>,
     big: Box<SpanQuery>,
@@ -31,7 +31,19 @@ impl Query {
 
 impl ShouldSkip for SpanContainingQuery {}
 
+impl Validate for SpanContainingQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("span_containing.little", &self.little);
+        errors.nested("span_containing.big", &self.big);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_containing": SpanContainingQuery);
+deserialize_with_root!("span_containing": SpanContainingQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::util::*;
-use crate::{Query, SpanQuery};
+use crate::{Query, SpanQuery, Validate, ValidationError, ValidationErrors};
+use std::borrow::Cow;
 
 /// Wrapper to allow span queries to participate in composite single-field span queries by
 /// _lying_ about their search field. The span field masking query maps to Lucene’s
@@ -17,11 +18,11 @@ use crate::{Query, SpanQuery};
 /// norms of the field name supplied. This may lead to unexpected scoring behavior.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-field-masking-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanFieldMaskingQuery {
     query: Box<SpanQuery>,
-    field: String,
+    field: Cow<'static, str>,
 }
 
 impl Query {
@@ -30,18 +31,29 @@ impl Query {
     pub fn span_field_masking<Q, F>(query: Q, field: F) -> SpanFieldMaskingQuery
     where
         Q: Into<SpanQuery>,
-        F: ToString,
+        F: Into<Cow<'static, str>>,
     {
         SpanFieldMaskingQuery {
             query: Box::new(query.into()),
-            field: field.to_string(),
+            field: field.into(),
         }
     }
 }
 
 impl ShouldSkip for SpanFieldMaskingQuery {}
 
+impl Validate for SpanFieldMaskingQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("span_field_masking.query", &self.query);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_field_masking": SpanFieldMaskingQuery);
+deserialize_with_root!("span_field_masking": SpanFieldMaskingQuery);
 
 #[cfg(test)]
 mod tests {
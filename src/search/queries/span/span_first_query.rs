@@ -1,5 +1,5 @@
 use crate::util::*;
-use crate::{Query, SpanQuery};
+use crate::{Query, SpanQuery, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// Matches spans near the beginning of a field. The span first query maps to Lucene
@@ -8,8 +8,8 @@ use serde::Serialize;
 /// position permitted in a match.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-first-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanFirstQuery {
     r#match: Box<SpanQuery>,
     end: u32,
@@ -30,7 +30,18 @@ impl Query {
 
 impl ShouldSkip for SpanFirstQuery {}
 
+impl Validate for SpanFirstQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("span_first.match", &self.r#match);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_first": SpanFirstQuery);
+deserialize_with_root!("span_first": SpanFirstQuery);
 
 #[cfg(test)]
 mod tests {
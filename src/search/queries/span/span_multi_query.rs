@@ -1,5 +1,5 @@
 use crate::util::*;
-use crate::{MultiTermQuery, Query};
+use crate::{MultiTermQuery, Query, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// The span_multi query allows you to wrap a `multi term query` (one of
@@ -9,15 +9,25 @@ use serde::Serialize;
 /// nested.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-multi-term-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanMultiQuery {
     r#match: Box<MultiTermQuery>,
 }
 
 impl ShouldSkip for SpanMultiQuery {}
 
+impl Validate for SpanMultiQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("span_multi.match", &*self.r#match);
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_multi": SpanMultiQuery);
+deserialize_with_root!("span_multi": SpanMultiQuery);
 
 impl Query {
     /// Creates an instance of [`SpanMultiQuery`]
@@ -35,6 +45,7 @@ impl Query {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Fuzziness;
 
     #[test]
     fn serialization() {
@@ -67,5 +78,82 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize_query(
+            Query::span_multi(Query::wildcard("test", "12*4")),
+            json!({
+                "span_multi": {
+                    "match" : {
+                        "wildcard": {
+                            "test": {
+                                "value": "12*4"
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::span_multi(Query::fuzzy("test", "1234").fuzziness(Fuzziness::Auto)),
+            json!({
+                "span_multi": {
+                    "match" : {
+                        "fuzzy": {
+                            "test": {
+                                "value": "1234",
+                                "fuzziness": "AUTO"
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::span_multi(Query::range("test").gte(1).lt(10)),
+            json!({
+                "span_multi": {
+                    "match" : {
+                        "range": {
+                            "test": {
+                                "gte": 1,
+                                "lt": 10
+                            }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserialization() {
+        let query: Query = serde_json::from_value(json!({
+            "span_multi": {
+                "match": {
+                    "regexp": {
+                        "test": {
+                            "value": "12.*"
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(query, Query::span_multi(Query::regexp("test", "12.*")));
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::span_multi(Query::fuzzy("test", "1234").fuzziness(Fuzziness::Distance(3)))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "span_multi.match.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
     }
 }
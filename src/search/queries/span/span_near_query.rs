@@ -1,6 +1,6 @@
 use crate::util::*;
-use crate::Query;
 use crate::SpanQuery;
+use crate::{Query, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// Matches spans which are near one another. One can specify _slop_, the maximum number of
@@ -10,8 +10,8 @@ use serde::Serialize;
 /// the maximum number of intervening unmatched positions permitted.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-near-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanNearQuery {
     clauses: Vec<SpanQuery>,
 
@@ -59,11 +59,25 @@ impl SpanNearQuery {
     }
 }
 
+impl Validate for SpanNearQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (index, clause) in self.clauses.iter().enumerate() {
+            errors.nested(&format!("span_near.clauses[{}]", index), clause);
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_near": SpanNearQuery);
+deserialize_with_root!("span_near": SpanNearQuery);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Fuzziness;
 
     #[test]
     fn serialization() {
@@ -105,4 +119,18 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::span_near([Query::span_multi(
+                Query::fuzzy("test", "abc").fuzziness(Fuzziness::Distance(3))
+            )])
+            .validate(),
+            Err(vec![ValidationError::new(
+                "span_near.clauses[0].span_multi.match.fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use crate::util::*;
-use crate::{Query, SpanQuery};
+use crate::{Query, SpanQuery, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// Removes matches which overlap with another span query or which are within x tokens before
@@ -11,16 +11,16 @@ use serde::Serialize;
 /// must not overlap those returned.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-not-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanNotQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     dist: Option<i32>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     exclude: Vec<SpanQuery>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     include: Vec<SpanQuery>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -79,7 +79,24 @@ impl SpanNotQuery {
 
 impl ShouldSkip for SpanNotQuery {}
 
+impl Validate for SpanNotQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (index, clause) in self.exclude.iter().enumerate() {
+            errors.nested(&format!("span_not.exclude[{}]", index), clause);
+        }
+
+        for (index, clause) in self.include.iter().enumerate() {
+            errors.nested(&format!("span_not.include[{}]", index), clause);
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_not": SpanNotQuery);
+deserialize_with_root!("span_not": SpanNotQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,6 @@
 use super::SpanQuery;
 use crate::util::*;
-use crate::Query;
+use crate::{Query, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// Matches the union of its span clauses. The span or query maps to Lucene `SpanOrQuery`.
@@ -8,8 +8,8 @@ use serde::Serialize;
 /// The `clauses` element is a list of one or more other span type queries.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-or-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanOrQuery {
     clauses: Vec<SpanQuery>,
 }
@@ -29,7 +29,20 @@ impl Query {
     }
 }
 
+impl Validate for SpanOrQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        for (index, clause) in self.clauses.iter().enumerate() {
+            errors.nested(&format!("span_or.clauses[{}]", index), clause);
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_or": SpanOrQuery);
+deserialize_with_root!("span_or": SpanOrQuery);
 
 #[cfg(test)]
 mod tests {
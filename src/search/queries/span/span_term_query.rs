@@ -1,15 +1,16 @@
 use crate::util::*;
-use crate::{Query, Term};
+use crate::{Query, Term, Validate};
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Matches spans containing a term. The span term query maps to Lucene `SpanTermQuery`.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-term-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanTermQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     value: Option<Term>,
 
@@ -28,11 +29,11 @@ impl Query {
     /// To return a document, the term must exactly match the field value, including whitespace and capitalization.
     pub fn span_term<T, U>(field: T, value: U) -> SpanTermQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Serialize,
     {
         SpanTermQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: Term::new(value),
             boost: None,
             _name: None,
@@ -50,7 +51,10 @@ impl ShouldSkip for SpanTermQuery {
     }
 }
 
+impl Validate for SpanTermQuery {}
+
 serialize_with_root_keyed!("span_term": SpanTermQuery);
+deserialize_with_root_keyed!("span_term": SpanTermQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,6 @@
 use super::SpanQuery;
 use crate::util::*;
-use crate::Query;
+use crate::{Query, Validate, ValidationError, ValidationErrors};
 use serde::Serialize;
 
 /// Returns matches which are enclosed inside another span query. The span within query maps to
@@ -10,8 +10,8 @@ use serde::Serialize;
 /// are enclosed within `big` are returned.
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-span-within-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct SpanWithinQuery {
     big: Box<SpanQuery>,
     little: Box<SpanQuery>,
@@ -33,7 +33,19 @@ impl Query {
 
 impl ShouldSkip for SpanWithinQuery {}
 
+impl Validate for SpanWithinQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("span_within.big", &self.big);
+        errors.nested("span_within.little", &self.little);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("span_within": SpanWithinQuery);
+deserialize_with_root!("span_within": SpanWithinQuery);
 
 #[cfg(test)]
 mod tests {
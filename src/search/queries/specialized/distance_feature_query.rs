@@ -1,12 +1,14 @@
 use crate::search::*;
 use crate::util::*;
 use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 #[doc(hidden)]
-pub trait Origin: Debug + PartialEq + Serialize + Clone {
-    type Pivot: Debug + PartialEq + Serialize + Clone;
+pub trait Origin: Debug + PartialEq + Serialize + DeserializeOwned + Clone {
+    type Pivot: Debug + PartialEq + Serialize + DeserializeOwned + Clone;
 }
 
 impl Origin for DateTime<Utc> {
@@ -90,13 +92,13 @@ impl Origin for GeoLocation {
 /// ```
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-distance-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields, bound(deserialize = ""))]
 pub struct DistanceFeatureQuery<O>
 where
     O: Origin,
 {
-    field: String,
+    field: Cow<'static, str>,
 
     origin: O,
 
@@ -149,11 +151,11 @@ impl Query {
         pivot: <O as Origin>::Pivot,
     ) -> DistanceFeatureQuery<O>
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         O: Origin,
     {
         DistanceFeatureQuery {
-            field: field.to_string(),
+            field: field.into(),
             origin,
             pivot,
             boost: None,
@@ -171,8 +173,12 @@ where
 
 impl<O> ShouldSkip for DistanceFeatureQuery<O> where O: Origin {}
 
+impl<O> Validate for DistanceFeatureQuery<O> where O: Origin {}
+
 serialize_with_root!("distance_feature": DistanceFeatureQuery<DateTime<Utc>>);
+deserialize_with_root!("distance_feature": DistanceFeatureQuery<DateTime<Utc>>);
 serialize_with_root!("distance_feature": DistanceFeatureQuery<GeoLocation>);
+deserialize_with_root!("distance_feature": DistanceFeatureQuery<GeoLocation>);
 
 #[cfg(test)]
 mod tests {
@@ -48,8 +48,8 @@ use crate::util::*;
 ///     .name("more_like_this");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-mlt-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct MoreLikeThisQuery {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     fields: Option<Vec<String>>,
@@ -84,7 +84,7 @@ pub struct MoreLikeThisQuery {
     analyzer: Option<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    minimum_should_match: Option<String>,
+    minimum_should_match: Option<MinimumShouldMatch>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     fail_on_unsupported_field: Option<bool>,
@@ -103,7 +103,7 @@ pub struct MoreLikeThisQuery {
 }
 
 /// Types for `like` and `unlike` fields
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Like {
     /// String/text which will be used in `like` field array
@@ -132,7 +132,7 @@ impl From<Document> for Like {
 }
 
 /// One of `like` and `unlike` types which has like document structure
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Document {
     _id: String,
 
@@ -145,7 +145,7 @@ pub struct Document {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     _source: Option<SourceFilter>,
 
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     _stored_fields: StoredFields,
 }
 
@@ -326,9 +326,9 @@ impl MoreLikeThisQuery {
     /// The syntax is the same as the `minimum should match`. (Defaults to "30%").
     pub fn minimum_should_match<T>(mut self, minimum_should_match: T) -> Self
     where
-        T: ToString,
+        T: Into<MinimumShouldMatch>,
     {
-        self.minimum_should_match = Some(minimum_should_match.to_string());
+        self.minimum_should_match = Some(minimum_should_match.into());
         self
     }
 
@@ -364,7 +364,10 @@ impl ShouldSkip for MoreLikeThisQuery {
     }
 }
 
+impl Validate for MoreLikeThisQuery {}
+
 serialize_with_root!("more_like_this": MoreLikeThisQuery);
+deserialize_with_root!("more_like_this": MoreLikeThisQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// In order to percolate a newly indexed document, the [percolate](PercolateLookupQuery) query can
 /// be used. Based on the response from an index request, the `_id` and other meta information can
@@ -14,10 +15,10 @@ use crate::util::*;
 /// Query::percolate_lookup("field", "index_name", "document_id");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-percolate-query.html#_percolating_an_existing_document>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct PercolateLookupQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     index: String,
 
@@ -44,12 +45,12 @@ impl Query {
     /// - `id` - The id of the document to fetch
     pub fn percolate_lookup<S, T, U>(field: S, index: T, id: U) -> PercolateLookupQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: ToString,
         U: ToString,
     {
         PercolateLookupQuery {
-            field: field.to_string(),
+            field: field.into(),
             index: index.to_string(),
             id: id.to_string(),
             routing: None,
@@ -98,7 +99,10 @@ impl PercolateLookupQuery {
 
 impl ShouldSkip for PercolateLookupQuery {}
 
+impl Validate for PercolateLookupQuery {}
+
 serialize_with_root!("percolate": PercolateLookupQuery);
+deserialize_with_root!("percolate": PercolateLookupQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// The `percolate` query can be used to match queries stored in an index. The percolate query
 /// itself contains the document that will be used as query to match with the stored queries.
@@ -22,10 +23,10 @@ use serde::Serialize;
 /// Query::percolate("field", vec![json!({ "message": "search text" }), json!({ "message": "another search text" })]);
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-percolate-query.html>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct PercolateQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     name: Option<String>,
@@ -41,7 +42,7 @@ impl Query {
     /// - `source` - [Source](PercolateSource) to percolate
     pub fn percolate<S, T>(field: S, source: T) -> PercolateQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: Serialize,
     {
         let source = serde_json::to_value(source).unwrap_or_default();
@@ -52,7 +53,7 @@ impl Query {
         };
 
         PercolateQuery {
-            field: field.to_string(),
+            field: field.into(),
             source,
             name: None,
         }
@@ -77,7 +78,10 @@ impl ShouldSkip for PercolateQuery {
     }
 }
 
+impl Validate for PercolateQuery {}
+
 serialize_with_root!("percolate": PercolateQuery);
+deserialize_with_root!("percolate": PercolateQuery);
 
 #[cfg(test)]
 mod tests {
@@ -85,7 +89,7 @@ mod tests {
 
     #[test]
     fn serialization() {
-        #[derive(Serialize)]
+        #[derive(Serialize, Deserialize)]
         struct Source {
             id: i32,
             message: &'static str,
@@ -14,8 +14,8 @@ use crate::util::*;
 ///     .name("matches_everything");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-match-all-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct PinnedQuery {
     #[serde(flatten)]
     values: PinnedQueryValues,
@@ -56,7 +56,10 @@ impl ShouldSkip for PinnedQuery {
     }
 }
 
+impl Validate for PinnedQuery {}
+
 serialize_with_root!("pinned": PinnedQuery);
+deserialize_with_root!("pinned": PinnedQuery);
 
 #[cfg(test)]
 mod tests {
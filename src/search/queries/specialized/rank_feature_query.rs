@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Boosts the relevance score of documents based on the numeric value of a `rank_feature` or
 /// `rank_features` field.
@@ -28,10 +29,10 @@ use serde::Serialize;
 /// Query::rank_feature("test").linear();
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rank-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RankFeatureQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -66,10 +67,10 @@ pub struct RankFeatureQuery {
 /// Query::rank_feature("test").linear();
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rank-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RankFeatureSaturationQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     saturation: Saturation,
 
@@ -106,10 +107,10 @@ pub struct RankFeatureSaturationQuery {
 /// Query::rank_feature("test").linear();
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rank-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RankFeatureLogarithmQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     log: Logarithm,
 
@@ -146,10 +147,10 @@ pub struct RankFeatureLogarithmQuery {
 /// Query::rank_feature("test").linear();
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rank-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RankFeatureSigmoidQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     sigmoid: Sigmoid,
 
@@ -186,10 +187,10 @@ pub struct RankFeatureSigmoidQuery {
 /// Query::rank_feature("test").linear();
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-rank-feature-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RankFeatureLinearQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     linear: Linear,
 
@@ -200,24 +201,24 @@ pub struct RankFeatureLinearQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Saturation {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     pivot: Option<f64>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Logarithm {
     scaling_factor: f64,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Sigmoid {
     pivot: f64,
     exponent: f64,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct Linear {}
 
 impl Query {
@@ -226,10 +227,10 @@ impl Query {
     /// - `field` - `rank_feature` or `rank_features` field used to boost relevance scores
     pub fn rank_feature<T>(field: T) -> RankFeatureQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
     {
         RankFeatureQuery {
-            field: field.to_string(),
+            field: field.into(),
             boost: None,
             _name: None,
         }
@@ -336,11 +337,26 @@ impl ShouldSkip for RankFeatureLogarithmQuery {}
 impl ShouldSkip for RankFeatureSigmoidQuery {}
 impl ShouldSkip for RankFeatureLinearQuery {}
 
+impl Validate for RankFeatureQuery {}
+
 serialize_with_root!("rank_feature": RankFeatureQuery);
+deserialize_with_root!("rank_feature": RankFeatureQuery);
+impl Validate for RankFeatureSaturationQuery {}
+
 serialize_with_root!("rank_feature": RankFeatureSaturationQuery);
+deserialize_with_root!("rank_feature": RankFeatureSaturationQuery);
+impl Validate for RankFeatureLogarithmQuery {}
+
 serialize_with_root!("rank_feature": RankFeatureLogarithmQuery);
+deserialize_with_root!("rank_feature": RankFeatureLogarithmQuery);
+impl Validate for RankFeatureSigmoidQuery {}
+
 serialize_with_root!("rank_feature": RankFeatureSigmoidQuery);
+deserialize_with_root!("rank_feature": RankFeatureSigmoidQuery);
+impl Validate for RankFeatureLinearQuery {}
+
 serialize_with_root!("rank_feature": RankFeatureLinearQuery);
+deserialize_with_root!("rank_feature": RankFeatureLinearQuery);
 
 #[cfg(test)]
 mod tests {
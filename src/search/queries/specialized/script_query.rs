@@ -14,8 +14,8 @@ use crate::util::*;
 /// Query::script(Script::source("return doc['amount'].value < 10;"));
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-script-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ScriptQuery {
     script: Script,
 
@@ -46,7 +46,10 @@ impl ScriptQuery {
 
 impl ShouldSkip for ScriptQuery {}
 
+impl Validate for ScriptQuery {}
+
 serialize_with_root!("script": ScriptQuery);
+deserialize_with_root!("script": ScriptQuery);
 
 #[cfg(test)]
 mod tests {
@@ -17,8 +17,8 @@ use crate::util::*;
 /// );
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-script-score-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ScriptScoreQuery {
     query: Box<Query>,
 
@@ -60,7 +60,18 @@ impl ScriptScoreQuery {
 
 impl ShouldSkip for ScriptScoreQuery {}
 
+impl Validate for ScriptScoreQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("script_score.query", &self.query);
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root!("script_score": ScriptScoreQuery);
+deserialize_with_root!("script_score": ScriptScoreQuery);
 
 #[cfg(test)]
 mod tests {
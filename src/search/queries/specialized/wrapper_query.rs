@@ -16,8 +16,8 @@ use crate::util::*;
 /// Query::wrapper("eyJ0ZXJtIiA6IHsgInVzZXIuaWQiIDogImtpbWNoeSIgfX0=");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-wrapper-query.html>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct WrapperQuery {
     query: String,
 }
@@ -36,7 +36,10 @@ impl Query {
 
 impl ShouldSkip for WrapperQuery {}
 
+impl Validate for WrapperQuery {}
+
 serialize_with_root!("wrapper": WrapperQuery);
+deserialize_with_root!("wrapper": WrapperQuery);
 
 #[cfg(test)]
 mod tests {
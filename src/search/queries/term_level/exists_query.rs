@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Returns documents that contain an indexed value for a field.
 ///
@@ -18,10 +19,10 @@ use crate::util::*;
 /// Query::exists("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-exists-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ExistsQuery {
-    field: String,
+    field: Cow<'static, str>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     boost: Option<f32>,
@@ -41,10 +42,10 @@ impl Query {
     ///   - A custom [`null-value`](https://www.elastic.co/guide/en/elasticsearch/reference/current/null-value.html), defined in field mapping
     pub fn exists<T>(field: T) -> ExistsQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
     {
         ExistsQuery {
-            field: field.to_string(),
+            field: field.into(),
             boost: None,
             _name: None,
         }
@@ -57,7 +58,10 @@ impl ExistsQuery {
 
 impl ShouldSkip for ExistsQuery {}
 
+impl Validate for ExistsQuery {}
+
 serialize_with_root!("exists": ExistsQuery);
+deserialize_with_root!("exists": ExistsQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain terms similar to the search term, as measured by a
 /// [Levenshtein edit distance](https://en.wikipedia.org/wiki/Levenshtein_distance).
@@ -31,11 +32,11 @@ use serde::Serialize;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-fuzzy-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct FuzzyQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     value: Option<Term>,
 
@@ -43,10 +44,10 @@ pub struct FuzzyQuery {
     fuzziness: Option<Fuzziness>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    max_expansions: Option<u8>,
+    max_expansions: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    prefix_length: Option<u8>,
+    prefix_length: Option<u32>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     transpositions: Option<bool>,
@@ -68,11 +69,11 @@ impl Query {
     /// - `value` - Fuzzy you wish to find in the provided field.
     pub fn fuzzy<T, U>(field: T, value: U) -> FuzzyQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Serialize,
     {
         FuzzyQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: Term::new(value),
             fuzziness: None,
             max_expansions: None,
@@ -101,14 +102,14 @@ impl FuzzyQuery {
 
     /// Maximum number of terms to which the query will expand.
     /// Defaults to `50`.
-    pub fn max_expansions(mut self, max_expansions: u8) -> Self {
+    pub fn max_expansions(mut self, max_expansions: u32) -> Self {
         self.max_expansions = Some(max_expansions);
         self
     }
 
     /// Number of beginning characters left unchanged for fuzzy matching.
     /// Defaults to `0`.
-    pub fn prefix_length(mut self, prefix_length: u8) -> Self {
+    pub fn prefix_length(mut self, prefix_length: u32) -> Self {
         self.prefix_length = Some(prefix_length);
         self
     }
@@ -136,7 +137,17 @@ impl ShouldSkip for FuzzyQuery {
     }
 }
 
+impl Validate for FuzzyQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested(&format!("fuzzy.{}.fuzziness", self.field), &self.fuzziness);
+        errors.into_result()
+    }
+}
+
 serialize_with_root_keyed!("fuzzy": FuzzyQuery);
+deserialize_with_root_keyed!("fuzzy": FuzzyQuery);
 
 #[cfg(test)]
 mod tests {
@@ -185,4 +196,22 @@ mod tests {
             json!({ "bool": {} }),
         )
     }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            Query::fuzzy("test", "abc").fuzziness(3..6).validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Query::fuzzy("test", "abc")
+                .fuzziness(Fuzziness::Distance(3))
+                .validate(),
+            Err(vec![ValidationError::new(
+                "fuzzy.test.fuzziness",
+                "edit distance cannot be greater than 2"
+            )])
+        );
+    }
 }
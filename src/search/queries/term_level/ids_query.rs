@@ -14,10 +14,10 @@ use crate::Set;
 /// Query::ids(vec!["2"]);
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-ids-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct IdsQuery {
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     values: Set<String>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -37,15 +37,40 @@ impl Query {
         I: IntoIterator,
         I::Item: ToString,
     {
-        IdsQuery {
-            values: values.into_iter().map(|value| value.to_string()).collect(),
+        IdsQuery::new(values.into_iter().map(|value| value.to_string()))
+    }
+}
+
+impl IdsQuery {
+    /// Creates an instance of [`IdsQuery`], unlike [`Query::ids`] only
+    /// accepts values that are already strings
+    ///
+    /// - `values` - An array of
+    /// [document IDs](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-id-field.html).
+    pub fn new<I>(values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            values: values.into_iter().map(Into::into).collect(),
             boost: None,
             _name: None,
         }
     }
-}
 
-impl IdsQuery {
+    /// Adds a document ID to the query, duplicate IDs are only sent once
+    // Named after the builder it is, not `std::ops::Add`, which would make
+    // `query + "id"` read like string concatenation
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let _ = self.values.insert(value.into());
+        self
+    }
+
     add_boost_and_name!();
 }
 
@@ -55,7 +80,10 @@ impl ShouldSkip for IdsQuery {
     }
 }
 
+impl Validate for IdsQuery {}
+
 serialize_with_root!("ids": IdsQuery);
+deserialize_with_root!("ids": IdsQuery);
 
 #[cfg(test)]
 mod tests {
@@ -83,4 +111,32 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn adds_value() {
+        assert_serialize_query(
+            Query::ids(["1"]).add("3").add(String::from("2")).add("1"),
+            json!({
+                "ids": {
+                    "values": ["1", "2", "3"]
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn creates_from_strings() {
+        assert_eq!(IdsQuery::new(["1", "2"]), Query::ids([1, 2]),);
+    }
+
+    #[test]
+    fn should_skip_when_there_are_no_values() {
+        assert!(Query::ids(Vec::<String>::new()).should_skip());
+        assert!(Query::ids(Vec::<String>::new()).add("1").should_keep());
+
+        assert_serialize_query(
+            Query::bool().filter(Query::ids(Vec::<String>::new())),
+            json!({ "bool": {} }),
+        );
+    }
 }
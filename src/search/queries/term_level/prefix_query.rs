@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain a specific prefix in a provided field.
 ///
@@ -21,11 +22,11 @@ use serde::Serialize;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-prefix-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct PrefixQuery {
-    #[serde(skip_serializing)]
-    field: String,
+    #[serde(skip)]
+    field: Cow<'static, str>,
 
     value: Option<Term>,
 
@@ -50,11 +51,11 @@ impl Query {
     /// To return a document, the term must exactly match the field value, including whitespace and capitalization.
     pub fn prefix<T, U>(field: T, value: U) -> PrefixQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Serialize,
     {
         PrefixQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: Term::new(value),
             rewrite: None,
             case_insensitive: None,
@@ -89,7 +90,10 @@ impl ShouldSkip for PrefixQuery {
     }
 }
 
+impl Validate for PrefixQuery {}
+
 serialize_with_root_keyed!("prefix": PrefixQuery);
+deserialize_with_root_keyed!("prefix": PrefixQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain terms within a provided range.
 ///
@@ -16,11 +17,11 @@ use serde::Serialize;
 ///     .name("range_query");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-range-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RangeQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     gt: Option<Term>,
@@ -56,10 +57,10 @@ impl Query {
     /// - `field` - Field you wish to search.
     pub fn range<T>(field: T) -> RangeQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
     {
         RangeQuery {
-            field: field.to_string(),
+            field: field.into(),
             gt: Default::default(),
             gte: Default::default(),
             lt: Default::default(),
@@ -165,7 +166,25 @@ impl ShouldSkip for RangeQuery {
     }
 }
 
+impl Validate for RangeQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+        let path = format!("range.{}", self.field);
+
+        if self.gt.is_some() && self.gte.is_some() {
+            errors.push(&path, "`gt` and `gte` cannot be set at the same time");
+        }
+
+        if self.lt.is_some() && self.lte.is_some() {
+            errors.push(&path, "`lt` and `lte` cannot be set at the same time");
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root_keyed!("range": RangeQuery);
+deserialize_with_root_keyed!("range": RangeQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain terms matching a
 /// [regular expression](https://en.wikipedia.org/wiki/Regular_expression).
@@ -17,19 +18,16 @@ use serde::Serialize;
 /// Query::regexp("test", "username");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-regexp-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct RegexpQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     value: String,
 
-    #[serde(
-        skip_serializing_if = "ShouldSkip::should_skip",
-        serialize_with = "join_with_pipe"
-    )]
-    flags: Vec<RegexpFlag>,
+    #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
+    flags: RegexpFlags,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     case_insensitive: Option<bool>,
@@ -58,14 +56,15 @@ impl Query {
     /// By default, regular expressions are limited to 1,000 characters. You can change this limit
     /// using the
     /// [`index.max_regex_length`](https://www.elastic.co/guide/en/elasticsearch/reference/current/index-modules.html#index-max-regex-length) setting.
-    pub fn regexp<S>(field: S, value: S) -> RegexpQuery
+    pub fn regexp<T, U>(field: T, value: U) -> RegexpQuery
     where
-        S: ToString,
+        T: Into<Cow<'static, str>>,
+        U: ToString,
     {
         RegexpQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: value.to_string(),
-            flags: vec![],
+            flags: RegexpFlags::empty(),
             case_insensitive: None,
             max_determinized_states: None,
             rewrite: None,
@@ -79,11 +78,15 @@ impl RegexpQuery {
     /// Enables optional operators for the regular expression. For valid values and more
     /// information, see
     /// [Regular expression syntax](https://www.elastic.co/guide/en/elasticsearch/reference/current/regexp-syntax.html#regexp-optional-operators).
+    ///
+    /// Accepts either a list of [`RegexpFlag`]s or [`RegexpFlags`] combined
+    /// with `|`, flags are added to the ones set previously and each flag is
+    /// only sent once.
     pub fn flags<I>(mut self, flags: I) -> Self
     where
         I: IntoIterator<Item = RegexpFlag>,
     {
-        self.flags.extend(flags.into_iter());
+        self.flags.extend(flags);
         self
     }
 
@@ -126,7 +129,10 @@ impl ShouldSkip for RegexpQuery {
     }
 }
 
+impl Validate for RegexpQuery {}
+
 serialize_with_root_keyed!("regexp": RegexpQuery);
+deserialize_with_root_keyed!("regexp": RegexpQuery);
 
 #[cfg(test)]
 mod tests {
@@ -168,4 +174,48 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn serializes_combined_flags() {
+        assert_serialize_query(
+            Query::regexp("user.id", "k.*y")
+                .flags([RegexpFlag::All, RegexpFlag::Complement])
+                .flags(RegexpFlag::Empty | RegexpFlag::Complement)
+                .case_insensitive(true)
+                .max_determinized_states(10000),
+            json!({
+                "regexp": {
+                    "user.id": {
+                        "value": "k.*y",
+                        "flags": "ALL|COMPLEMENT|EMPTY",
+                        "case_insensitive": true,
+                        "max_determinized_states": 10000
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserializes_combined_flags() {
+        let query: Query = serde_json::from_value(json!({
+            "regexp": {
+                "user.id": {
+                    "value": "k.*y",
+                    "flags": "ALL|COMPLEMENT|EMPTY",
+                    "case_insensitive": true
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            query,
+            Query::from(
+                Query::regexp("user.id", "k.*y")
+                    .flags([RegexpFlag::All, RegexpFlag::Complement, RegexpFlag::Empty])
+                    .case_insensitive(true)
+            )
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain an **exact** term in a provided field.
 ///
@@ -23,11 +24,11 @@ use serde::Serialize;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-term-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct TermQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     value: Option<Term>,
 
@@ -46,11 +47,11 @@ impl Query {
     /// To return a document, the term must exactly match the field value, including whitespace and capitalization.
     pub fn term<T, U>(field: T, value: U) -> TermQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Serialize,
     {
         TermQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: Term::new(value),
             boost: None,
             _name: None,
@@ -68,7 +69,10 @@ impl ShouldSkip for TermQuery {
     }
 }
 
+impl Validate for TermQuery {}
+
 serialize_with_root_keyed!("term": TermQuery);
+deserialize_with_root_keyed!("term": TermQuery);
 
 #[cfg(test)]
 mod tests {
@@ -105,4 +109,33 @@ mod tests {
             json!({ "bool": {} }),
         )
     }
+
+    #[test]
+    fn serializes_false_values() {
+        assert!(Query::term("is_active", false).should_keep());
+        assert!(Query::term("is_active", Some(false)).should_keep());
+        assert!(Query::term("is_active", None::<bool>).should_skip());
+
+        assert_serialize_query(
+            Query::term("is_active", false),
+            json!({
+                "term": {
+                    "is_active": {
+                        "value": false
+                    }
+                }
+            }),
+        );
+
+        assert_serialize_query(
+            Query::bool().filter(Query::term("is_active", Some(false))),
+            json!({
+                "bool": {
+                    "filter": [
+                        { "term": { "is_active": { "value": false } } }
+                    ]
+                }
+            }),
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Terms lookup fetches the field values of an existing document.
 /// Elasticsearch then uses those values as search terms. This can be
@@ -26,13 +27,13 @@ use crate::util::*;
 ///     .name("lookup");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct TermsLookupQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     terms_lookup: TermsLookup,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -42,7 +43,7 @@ pub struct TermsLookupQuery {
     _name: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct TermsLookup {
     index: String,
     id: String,
@@ -64,13 +65,13 @@ impl Query {
     /// using dot notation syntax.
     pub fn terms_lookup<S, T, U, V>(field: S, index: T, id: U, path: V) -> TermsLookupQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: ToString,
         U: ToString,
         V: ToString,
     {
         TermsLookupQuery {
-            field: field.to_string(),
+            field: field.into(),
             terms_lookup: TermsLookup {
                 index: index.to_string(),
                 id: id.to_string(),
@@ -103,7 +104,10 @@ impl TermsLookupQuery {
 
 impl ShouldSkip for TermsLookupQuery {}
 
+impl Validate for TermsLookupQuery {}
+
 serialize_with_root_key_value_pair!("terms": TermsLookupQuery, field, terms_lookup);
+deserialize_with_root_key_value_pair!("terms": TermsLookupQuery, field, terms_lookup);
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,6 @@
 use crate::search::*;
 use crate::util::*;
+use std::borrow::Cow;
 
 /// Returns documents that contain one or more **exact** terms in a provided field.
 /// The terms query is the same as the term query, except you can search for multiple values.
@@ -21,13 +22,13 @@ use crate::util::*;
 ///     .name("test");
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct TermsQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     terms: Terms,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -51,11 +52,11 @@ impl Query {
     /// use the terms lookup parameters.
     pub fn terms<S, I>(field: S, terms: I) -> TermsQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         I: Into<Terms>,
     {
         TermsQuery {
-            field: field.to_string(),
+            field: field.into(),
             terms: terms.into(),
             boost: None,
             _name: None,
@@ -64,16 +65,39 @@ impl Query {
 }
 
 impl TermsQuery {
+    /// Creates an instance of [`TermsQuery`] from any collection of values,
+    /// same as [`Query::terms`]
+    pub fn from_iter<S, I>(field: S, values: I) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        I: IntoIterator,
+        I::Item: serde::Serialize,
+    {
+        Query::terms(field, values)
+    }
+
     add_boost_and_name!();
 }
 
+impl<T> From<(String, Vec<T>)> for TermsQuery
+where
+    T: serde::Serialize,
+{
+    fn from((field, values): (String, Vec<T>)) -> Self {
+        Self::from_iter(field, values)
+    }
+}
+
 impl ShouldSkip for TermsQuery {
     fn should_skip(&self) -> bool {
         self.terms.should_skip()
     }
 }
 
+impl Validate for TermsQuery {}
+
 serialize_with_root_key_value_pair!("terms": TermsQuery, field, terms);
+deserialize_with_root_key_value_pair!("terms": TermsQuery, field, terms);
 
 #[cfg(test)]
 mod tests {
@@ -98,6 +122,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serializes_typed_collections() {
+        assert_serialize_query(
+            Query::terms("ids", vec![1i64, -2, 3]),
+            json!({ "terms": { "ids": [1, -2, 3] } }),
+        );
+
+        assert_serialize_query(
+            Query::terms("status", vec!["active", "pending"]),
+            json!({ "terms": { "status": ["active", "pending"] } }),
+        );
+
+        assert_serialize_query(
+            Query::terms("status", ["active", ""].iter().map(|x| x.to_uppercase())),
+            json!({ "terms": { "status": ["ACTIVE"] } }),
+        );
+
+        assert_serialize_query(
+            Query::bool().filter(Query::terms("ids", Vec::<i64>::new())),
+            json!({ "bool": {} }),
+        );
+    }
+
+    #[test]
+    fn creates_from_iterators_and_tuples() {
+        assert_eq!(
+            TermsQuery::from_iter("ids", vec![1i64, 2, 3]),
+            Query::terms("ids", [1, 2, 3])
+        );
+
+        assert_eq!(
+            TermsQuery::from(("status".to_string(), vec!["active", "pending"])),
+            Query::terms("status", ["active", "pending"])
+        );
+
+        assert!(TermsQuery::from(("ids".to_string(), Vec::<i64>::new())).should_skip());
+    }
+
     #[test]
     fn should_skip_when_there_are_no_values() {
         let values: Vec<i32> = Vec::new();
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain an **exact** terms_set in a provided field.
 ///
@@ -27,11 +28,11 @@ use serde::Serialize;
 /// );
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-terms-set-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct TermsSetQuery {
     #[serde(skip)]
-    field: String,
+    field: Cow<'static, str>,
 
     terms: Terms,
 
@@ -53,12 +54,12 @@ impl Query {
     /// To return a document, the terms_set must exactly match the field value, including whitespace and capitalization.
     pub fn terms_set<S, T, U>(field: S, terms: T, minimum_should_match: U) -> TermsSetQuery
     where
-        S: ToString,
+        S: Into<Cow<'static, str>>,
         T: Into<Terms>,
         U: Into<TermsSetMinimumShouldMatch>,
     {
         TermsSetQuery {
-            field: field.to_string(),
+            field: field.into(),
             terms: terms.into(),
             minimum_should_match: minimum_should_match.into(),
             boost: None,
@@ -77,7 +78,23 @@ impl ShouldSkip for TermsSetQuery {
     }
 }
 
+impl Validate for TermsSetQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        if self.terms.should_skip() {
+            errors.push(
+                format!("terms_set.{}", self.field),
+                "at least one term is required",
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
 serialize_with_root_keyed!("terms_set": TermsSetQuery);
+deserialize_with_root_keyed!("terms_set": TermsSetQuery);
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::search::*;
 use crate::util::*;
 use serde::Serialize;
+use std::borrow::Cow;
 
 /// Returns documents that contain terms matching a wildcard pattern.
 ///
@@ -16,11 +17,11 @@ use serde::Serialize;
 /// Query::wildcard("test", 123);
 /// ```
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-wildcard-query.html>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct WildcardQuery {
-    #[serde(skip_serializing)]
-    field: String,
+    #[serde(skip)]
+    field: Cow<'static, str>,
 
     value: Option<Term>,
 
@@ -45,11 +46,11 @@ impl Query {
     /// To return a document, the wildcard must exactly match the field value, including whitespace and capitalization.
     pub fn wildcard<T, U>(field: T, value: U) -> WildcardQuery
     where
-        T: ToString,
+        T: Into<Cow<'static, str>>,
         U: Serialize,
     {
         WildcardQuery {
-            field: field.to_string(),
+            field: field.into(),
             value: Term::new(value),
             rewrite: None,
             case_insensitive: None,
@@ -84,7 +85,10 @@ impl ShouldSkip for WildcardQuery {
     }
 }
 
+impl Validate for WildcardQuery {}
+
 serialize_with_root_keyed!("wildcard": WildcardQuery);
+deserialize_with_root_keyed!("wildcard": WildcardQuery);
 
 #[cfg(test)]
 mod tests {
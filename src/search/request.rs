@@ -18,6 +18,24 @@ pub struct Search {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     min_score: Option<f32>,
 
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    terminate_after: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    timeout: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    version: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    seq_no_primary_term: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    profile: Option<bool>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     _source: Option<SourceFilter>,
 
@@ -33,6 +51,12 @@ pub struct Search {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     query: Option<Query>,
 
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        serialize_with = "serialize_knn"
+    )]
+    knn: Vec<Knn>,
+
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     sort: SortCollection,
 
@@ -65,6 +89,21 @@ pub struct Search {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     search_after: Terms,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slice: Option<Slice>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pit: Option<PointInTime>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    collapse: Option<Collapse>,
+
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        serialize_with = "serialize_key_value_pairs"
+    )]
+    ext: Vec<KeyValuePair<String, serde_json::Value>>,
 }
 
 impl Search {
@@ -96,13 +135,34 @@ impl Search {
     /// across more than one indices. This is very handy when hits coming from
     /// one index matter more than hits coming from another index (think social
     /// graph where each user has an index).
-    pub fn indices_boost<T, U>(mut self, field: T, boost: U) -> Self
+    ///
+    /// Entries are kept in the given order, which matters when an index
+    /// matches several of them through aliases or index patterns.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-multiple-indices.html#index-boost>
+    pub fn indices_boost<I, T, U>(mut self, indices_boost: I) -> Self
+    where
+        I: IntoIterator<Item = (T, U)>,
+        T: Into<String>,
+        U: Into<f32>,
+    {
+        self.indices_boost.extend(
+            indices_boost
+                .into_iter()
+                .map(|(index, boost)| KeyValuePair::new(index.into(), boost.into())),
+        );
+        self
+    }
+
+    /// Appends a single index boost entry, see
+    /// [`indices_boost`](Search::indices_boost)
+    pub fn indices_boost_entry<T, U>(mut self, index: T, boost: U) -> Self
     where
         T: ToString,
         U: num_traits::AsPrimitive<f32>,
     {
         self.indices_boost
-            .push(KeyValuePair::new(field.to_string(), boost.as_()));
+            .push(KeyValuePair::new(index.to_string(), boost.as_()));
         self
     }
 
@@ -119,6 +179,57 @@ impl Search {
         self
     }
 
+    /// Maximum number of documents to collect for each shard. If a query
+    /// reaches this limit, Elasticsearch terminates the query early.
+    /// Elasticsearch collects documents before sorting.
+    pub fn terminate_after(mut self, terminate_after: u64) -> Self {
+        self.terminate_after = Some(terminate_after);
+        self
+    }
+
+    /// Period of time to wait for a response from each shard. If no response
+    /// is received before the timeout expires, the request fails and returns
+    /// an error.
+    ///
+    /// Accepts [`Time`], [`std::time::Duration`] or a raw time unit string
+    /// such as `2s`.
+    pub fn timeout<T>(mut self, timeout: T) -> Self
+    where
+        T: Into<Time>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// If `true`, returns document version as part of a hit
+    pub fn version(mut self, version: bool) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// If `true`, returns sequence number and primary term of the last
+    /// modification of each hit
+    pub fn seq_no_primary_term(mut self, seq_no_primary_term: bool) -> Self {
+        self.seq_no_primary_term = Some(seq_no_primary_term);
+        self
+    }
+
+    /// If `true`, returns detailed information about score computation as
+    /// part of a hit
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.explain = Some(explain);
+        self
+    }
+
+    /// If `true`, returns detailed timing information about the execution of
+    /// individual components in the search request
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-profile.html>
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
     /// Indicates which source fields are returned for matching documents
     pub fn source<S>(mut self, source: S) -> Self
     where
@@ -163,13 +274,28 @@ impl Search {
         self
     }
 
+    /// Runs an approximate k-nearest neighbor search, which can be repeated to
+    /// search against several vector fields. When combined with a
+    /// [query](Self::query) the scores of both are summed.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/knn-search.html>
+    pub fn knn<T>(mut self, knn: T) -> Self
+    where
+        T: IntoIterator<Item = Knn>,
+    {
+        self.knn.extend(knn);
+        self
+    }
+
     /// When you use the `post_filter` parameter to filter search results, the search hits are filtered after the
     /// aggregations are calculated. A post filter has no impact on the aggregation results.
+    ///
+    /// Skippable queries, e.g. empty `terms` query, leave the post filter unset.
     pub fn post_filter<Q>(mut self, post_filter: Q) -> Self
     where
         Q: Into<Query>,
     {
-        self.post_filter = Some(post_filter.into());
+        self.post_filter = Some(post_filter.into()).filter(ShouldSkip::should_keep);
         self
     }
 
@@ -221,7 +347,10 @@ impl Search {
         self
     }
 
-    /// A collection of stored fields
+    /// A collection of stored fields, `_none_` (or [`StoredFields::None`])
+    /// disables the stored fields and metadata fields entirely
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-fields.html#stored-fields>
     pub fn stored_fields<T>(mut self, stored_fields: T) -> Self
     where
         T: Into<StoredFields>,
@@ -250,5 +379,234 @@ impl Search {
         self
     }
 
+    /// Searches against a point in time, preserving the index state across paginated requests.
+    ///
+    /// When `pit` is set the request must not target an index, as the point in time already
+    /// carries the indices it was opened against, i.e. the request should be sent to `/_search`
+    /// rather than `/<index>/_search`.
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#search-after>
+    pub fn pit<T>(mut self, pit: T) -> Self
+    where
+        T: Into<PointInTime>,
+    {
+        self.pit = Some(pit.into());
+        self
+    }
+
+    /// Splits a scroll or point in time search into independent slices
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/paginate-search-results.html#slice-scroll>
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Collapses search results based on field values
+    pub fn collapse<C>(mut self, collapse: C) -> Self
+    where
+        C: Into<Collapse>,
+    {
+        self.collapse = Some(collapse.into());
+        self
+    }
+
+    /// Adds an entry to the `ext` section, used by plugins that extend the
+    /// search body (e.g. learning to rank feature logging). Entries keep their
+    /// insertion order and setting the same key again replaces its value.
+    pub fn ext<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: Into<serde_json::Value>,
+    {
+        let key = key.to_string();
+        let value = value.into();
+
+        match self.ext.iter_mut().find(|entry| entry.key == key) {
+            Some(entry) => entry.value = value,
+            None => self.ext.push(KeyValuePair::new(key, value)),
+        }
+
+        self
+    }
+
     add_aggregate!();
 }
+
+impl Validate for Search {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("query", &self.query);
+        errors.nested("post_filter", &self.post_filter);
+
+        for (index, knn) in self.knn.iter().enumerate() {
+            errors.nested(&format!("knn[{}]", index), knn);
+        }
+
+        errors.nested("slice", &self.slice);
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_post_filter() {
+        let empty: [&str; 0] = [];
+
+        assert_serialize(
+            Search::new().post_filter(Query::terms("color", empty)),
+            json!({}),
+        );
+
+        assert_serialize(
+            Search::new()
+                .query(Query::term("brand", "gucci"))
+                .aggregate("models", Aggregation::terms("model"))
+                .post_filter(Query::term("color", "red")),
+            json!({
+                "query": { "term": { "brand": { "value": "gucci" } } },
+                "aggs": { "models": { "terms": { "field": "model" } } },
+                "post_filter": { "term": { "color": { "value": "red" } } }
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_indices_boost() {
+        assert_serialize(
+            Search::new().indices_boost(Vec::<(String, f32)>::new()),
+            json!({}),
+        );
+
+        assert_serialize(
+            Search::new()
+                .indices_boost([("my-alias", 1.4), ("my-index*", 1.3)])
+                .indices_boost_entry("my-index-000001", 2),
+            json!({
+                "indices_boost": [
+                    { "my-alias": 1.4 },
+                    { "my-index*": 1.3 },
+                    { "my-index-000001": 2.0 }
+                ]
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_execution_parameters() {
+        assert_serialize(Search::new(), json!({}));
+
+        assert_serialize(
+            Search::new()
+                .min_score(0.5)
+                .terminate_after(100)
+                .timeout(std::time::Duration::from_secs(2))
+                .version(true)
+                .seq_no_primary_term(true)
+                .explain(false)
+                .profile(true),
+            json!({
+                "min_score": 0.5,
+                "terminate_after": 100,
+                "timeout": "2s",
+                "version": true,
+                "seq_no_primary_term": true,
+                "explain": false,
+                "profile": true
+            }),
+        );
+
+        assert_serialize(
+            Search::new().timeout("500ms"),
+            json!({ "timeout": "500ms" }),
+        );
+    }
+
+    #[test]
+    fn serializes_stored_fields() {
+        assert_serialize(
+            Search::new()
+                .stored_fields(["user", "postDate"])
+                .source(false),
+            json!({
+                "_source": false,
+                "stored_fields": ["postDate", "user"]
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .stored_fields(["_none_"])
+                .source(SourceFilter::includes(["obj.*"])),
+            json!({
+                "_source": { "includes": ["obj.*"] },
+                "stored_fields": "_none_"
+            }),
+        );
+
+        assert_serialize(
+            Search::new()
+                .stored_fields(StoredFields::None)
+                .explain(true),
+            json!({
+                "explain": true,
+                "stored_fields": "_none_"
+            }),
+        );
+    }
+
+    #[test]
+    fn serializes_ext() {
+        assert_serialize(Search::new(), json!({}));
+
+        assert_serialize(
+            Search::new().ext("ltr_log", json!({})),
+            json!({ "ext": { "ltr_log": {} } }),
+        );
+
+        let search = Search::new()
+            .query(Query::term("text", "rambo"))
+            .ext(
+                "ltr_log",
+                json!({
+                    "log_specs": {
+                        "name": "log_entry1",
+                        "named_query": "logged_featureset",
+                        "missing_as_zero": true
+                    }
+                }),
+            )
+            .ext("my_plugin", json!([1, 2]))
+            .ext("another_plugin", true)
+            .ext("my_plugin", "replaced");
+
+        assert_serialize(
+            &search,
+            json!({
+                "query": { "term": { "text": { "value": "rambo" } } },
+                "ext": {
+                    "ltr_log": {
+                        "log_specs": {
+                            "name": "log_entry1",
+                            "named_query": "logged_featureset",
+                            "missing_as_zero": true
+                        }
+                    },
+                    "my_plugin": "replaced",
+                    "another_plugin": true
+                }
+            }),
+        );
+
+        let json = serde_json::to_string(&search).unwrap();
+
+        assert!(json.ends_with(
+            r#""ext":{"ltr_log":{"log_specs":{"missing_as_zero":true,"name":"log_entry1","named_query":"logged_featureset"}},"my_plugin":"replaced","another_plugin":true}}"#
+        ));
+    }
+}
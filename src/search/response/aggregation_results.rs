@@ -0,0 +1,208 @@
+use super::{
+    Bucket, BucketAggregationResult, PercentilesAggregationResult, StatsAggregationResult,
+    ValueAggregationResult,
+};
+use crate::{util::ShouldSkip, Map};
+use serde::de::{DeserializeOwned, Error};
+use serde_json::Value;
+
+/// Aggregation results keyed by the aggregation name
+///
+/// Results are kept as raw JSON and parsed on demand, the caller asserting the
+/// expected shape through one of the typed accessors, i.e.
+/// [`get_terms`](AggregationResults::get_terms) or
+/// [`get_value`](AggregationResults::get_value). Names are matched with or
+/// without the type prefix Elasticsearch adds when `typed_keys` is enabled,
+/// e.g. `sterms#genres`.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-aggregations.html>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct AggregationResults(Map<String, Value>);
+
+impl AggregationResults {
+    /// Raw result of the aggregation
+    pub fn raw(&self, name: &str) -> Option<&Value> {
+        self.0.get(name).or_else(|| {
+            self.0
+                .iter()
+                .find(|(key, _)| matches!(key.split_once('#'), Some((_, key)) if key == name))
+                .map(|(_, value)| value)
+        })
+    }
+
+    /// Iterates over aggregation names and their raw results
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+
+    /// Parses the result of the aggregation into a concrete type, fails when
+    /// the aggregation is missing or has a different shape
+    pub fn get<T>(&self, name: &str) -> Result<T, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        match self.raw(name) {
+            Some(value) => T::deserialize(value).map_err(serde_json::Error::custom),
+            None => Err(serde_json::Error::custom(format!(
+                "missing aggregation `{name}`"
+            ))),
+        }
+    }
+
+    /// Parses the result of a single bucket aggregation, such as
+    /// [`filter`](crate::FilterAggregation) or [`global`](crate::GlobalAggregation)
+    pub fn get_bucket(&self, name: &str) -> Result<Bucket, serde_json::Error> {
+        self.get(name)
+    }
+
+    /// Parses the result of a multi bucket aggregation
+    pub fn get_buckets(&self, name: &str) -> Result<BucketAggregationResult, serde_json::Error> {
+        self.get(name)
+    }
+
+    /// Parses the result of a [`terms`](crate::TermsAggregation) aggregation
+    pub fn get_terms(&self, name: &str) -> Result<BucketAggregationResult, serde_json::Error> {
+        self.get_buckets(name)
+    }
+
+    /// Parses the result of a [`histogram`](crate::HistogramAggregation) aggregation
+    pub fn get_histogram(&self, name: &str) -> Result<BucketAggregationResult, serde_json::Error> {
+        self.get_buckets(name)
+    }
+
+    /// Parses the result of a [`date_histogram`](crate::DateHistogramAggregation)
+    /// aggregation
+    pub fn get_date_histogram(
+        &self,
+        name: &str,
+    ) -> Result<BucketAggregationResult, serde_json::Error> {
+        self.get_buckets(name)
+    }
+
+    /// Parses the result of a [`composite`](crate::CompositeAggregation)
+    /// aggregation, see [`BucketAggregationResult::after_key`]
+    pub fn get_composite(&self, name: &str) -> Result<BucketAggregationResult, serde_json::Error> {
+        self.get_buckets(name)
+    }
+
+    /// Parses the result of a single value metrics aggregation, such as
+    /// [`avg`](crate::AvgAggregation) or [`cardinality`](crate::CardinalityAggregation)
+    pub fn get_value(&self, name: &str) -> Result<ValueAggregationResult, serde_json::Error> {
+        self.get(name)
+    }
+
+    /// Parses the result of a [`stats`](crate::StatsAggregation) or
+    /// [`extended_stats`](crate::ExtendedStatsAggregation) aggregation
+    pub fn get_stats(&self, name: &str) -> Result<StatsAggregationResult, serde_json::Error> {
+        self.get(name)
+    }
+
+    /// Parses the result of a [`percentiles`](crate::PercentilesAggregation) or
+    /// [`percentile_ranks`](crate::PercentileRanksAggregation) aggregation
+    pub fn get_percentiles(
+        &self,
+        name: &str,
+    ) -> Result<PercentilesAggregationResult, serde_json::Error> {
+        self.get(name)
+    }
+}
+
+impl ShouldSkip for AggregationResults {
+    fn should_skip(&self) -> bool {
+        self.0.should_skip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_terms_with_sub_aggregations() {
+        let json = json!({
+          "genres": {
+            "doc_count_error_upper_bound": 0,
+            "sum_other_doc_count": 0,
+            "buckets": [
+              {
+                "key": "electronic",
+                "doc_count": 6,
+                "avg_price": { "value": 12.5 },
+                "by_month": {
+                  "buckets": [
+                    {
+                      "key_as_string": "2015/01/01 00:00:00",
+                      "key": 1420070400000u64,
+                      "doc_count": 3
+                    }
+                  ]
+                }
+              },
+              {
+                "key": "rock",
+                "doc_count": 3,
+                "avg_price": { "value": null }
+              }
+            ]
+          }
+        });
+
+        let subject: AggregationResults = serde_json::from_value(json).unwrap();
+        let genres = subject.get_terms("genres").unwrap();
+
+        assert_eq!(genres.doc_count_error_upper_bound, Some(0));
+        assert_eq!(genres.sum_other_doc_count, Some(0));
+        assert_eq!(genres.buckets.len(), 2);
+        assert_eq!(genres.buckets[0].key, json!("electronic"));
+        assert_eq!(genres.buckets[0].doc_count, 6);
+        assert_eq!(
+            genres.buckets[0]
+                .aggregations
+                .get_value("avg_price")
+                .unwrap()
+                .value,
+            Some(12.5)
+        );
+        assert_eq!(
+            genres.buckets[1]
+                .aggregations
+                .get_value("avg_price")
+                .unwrap()
+                .value,
+            None
+        );
+
+        let by_month = genres.buckets[0]
+            .aggregations
+            .get_date_histogram("by_month")
+            .unwrap();
+
+        assert_eq!(by_month.buckets[0].key, json!(1420070400000u64));
+        assert_eq!(
+            by_month.buckets[0].key_as_string.as_deref(),
+            Some("2015/01/01 00:00:00")
+        );
+
+        assert!(subject.get_terms("missing").is_err());
+        assert!(subject.get_stats("genres").is_err());
+    }
+
+    #[test]
+    fn matches_typed_keys() {
+        let json = json!({
+          "sterms#genres": { "buckets": [] },
+          "avg#avg_price": { "value": 10.0 }
+        });
+
+        let subject: AggregationResults = serde_json::from_value(json).unwrap();
+
+        assert!(subject.get_terms("genres").unwrap().buckets.is_empty());
+        assert_eq!(subject.get_value("avg_price").unwrap().value, Some(10.0));
+        assert_eq!(
+            subject.get_value("avg#avg_price").unwrap().value,
+            Some(10.0)
+        );
+        assert!(subject.raw("avg").is_none());
+    }
+}
@@ -0,0 +1,26 @@
+use super::AggregationResults;
+use crate::util::ShouldSkip;
+use serde_json::Value;
+
+/// A bucket of a multi bucket aggregation, or the result of a single bucket
+/// aggregation, such as [`filter`](crate::FilterAggregation)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bucket {
+    /// Bucket key, e.g. a term, a number, a date in milliseconds since epoch
+    /// or an object for [composite](crate::CompositeAggregation) buckets.
+    /// [`Value::Null`] for single bucket aggregations
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    pub key: Value,
+
+    /// Formatted bucket key
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub key_as_string: Option<String>,
+
+    /// Number of documents in the bucket
+    pub doc_count: u64,
+
+    /// Sub-aggregations, along with any bucket property that is not typed
+    /// above, e.g. range `from` and `to`
+    #[serde(flatten)]
+    pub aggregations: AggregationResults,
+}
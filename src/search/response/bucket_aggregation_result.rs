@@ -0,0 +1,134 @@
+use super::Bucket;
+use crate::{util::ShouldSkip, Map};
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::Value;
+
+/// Result of a multi bucket aggregation
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BucketAggregationResult {
+    /// Aggregation buckets, in the order returned by Elasticsearch. Keyed
+    /// buckets, e.g. `keyed` ranges or named filters, get their name as
+    /// [`Bucket::key`]
+    #[serde(default, deserialize_with = "deserialize_buckets")]
+    pub buckets: Vec<Bucket>,
+
+    /// Upper bound of the error on the document counts of
+    /// [`terms`](crate::TermsAggregation) buckets
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub doc_count_error_upper_bound: Option<i64>,
+
+    /// Number of documents not part of any returned
+    /// [`terms`](crate::TermsAggregation) bucket
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub sum_other_doc_count: Option<u64>,
+
+    /// Key of the last [composite](crate::CompositeAggregation) bucket, pass
+    /// it to [`CompositeAggregation::after`](crate::CompositeAggregation::after)
+    /// to request the next page. [`None`] once every bucket was returned
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub after_key: Option<Map<String, Value>>,
+}
+
+fn deserialize_buckets<'de, D>(deserializer: D) -> Result<Vec<Bucket>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct BucketsVisitor;
+
+    impl<'de> Visitor<'de> for BucketsVisitor {
+        type Value = Vec<Bucket>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("an array or a map of buckets")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut buckets = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+            while let Some(bucket) = seq.next_element()? {
+                buckets.push(bucket);
+            }
+
+            Ok(buckets)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut buckets = Vec::with_capacity(map.size_hint().unwrap_or_default());
+
+            while let Some((key, mut bucket)) = map.next_entry::<String, Bucket>()? {
+                if bucket.key.is_null() {
+                    bucket.key = Value::String(key);
+                }
+
+                buckets.push(bucket);
+            }
+
+            Ok(buckets)
+        }
+    }
+
+    deserializer.deserialize_any(BucketsVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_keyed_buckets() {
+        let json = json!({
+          "buckets": {
+            "errors": { "doc_count": 1 },
+            "warnings": { "doc_count": 2 },
+            "*-100.0": { "to": 100.0, "doc_count": 2 }
+          }
+        });
+
+        let subject: BucketAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            subject
+                .buckets
+                .iter()
+                .map(|bucket| (bucket.key.clone(), bucket.doc_count))
+                .collect::<Vec<_>>(),
+            [
+                (json!("*-100.0"), 2),
+                (json!("errors"), 1),
+                (json!("warnings"), 2)
+            ]
+        );
+        assert_eq!(
+            subject.buckets[0].aggregations.raw("to"),
+            Some(&json!(100.0))
+        );
+    }
+
+    #[test]
+    fn deserializes_composite_buckets() {
+        let json = json!({
+          "after_key": { "date": 1494288000000u64, "product": "mad max" },
+          "buckets": [
+            {
+              "key": { "date": 1494201600000u64, "product": "rocky" },
+              "doc_count": 1
+            },
+            {
+              "key": { "date": 1494288000000u64, "product": "mad max" },
+              "doc_count": 2
+            }
+          ]
+        });
+
+        let subject: BucketAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.buckets[1].key["product"], json!("mad max"));
+        assert_eq!(subject.after_key.unwrap()["date"], json!(1494288000000u64));
+    }
+}
@@ -1,3 +1,4 @@
+use super::ShardFailure;
 use crate::{util::ShouldSkip, Map};
 use serde_json::Value;
 
@@ -10,6 +11,18 @@ pub struct ErrorCause {
     /// Error cause reason
     pub reason: Option<String>,
 
+    /// Name of the index the error relates to
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub index: Option<String>,
+
+    /// UUID of the index the error relates to
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub index_uuid: Option<String>,
+
+    /// Per shard failures of a search phase
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub failed_shards: Vec<ShardFailure>,
+
     /// Root error cause
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
     pub root_cause: Vec<ErrorCause>,
@@ -29,3 +42,13 @@ pub struct ErrorCause {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
     pub additional_details: Map<String, Value>,
 }
+
+impl std::fmt::Display for ErrorCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.ty, &self.reason) {
+            (Some(ty), Some(reason)) => write!(f, "{ty}: {reason}"),
+            (Some(message), None) | (None, Some(message)) => message.fmt(f),
+            (None, None) => "unknown error".fmt(f),
+        }
+    }
+}
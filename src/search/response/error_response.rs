@@ -0,0 +1,191 @@
+use super::ErrorCause;
+
+/// Error response returned by Elasticsearch for failed requests
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/common-options.html#common-options-error-options>
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ErrorResponse {
+    /// Error details
+    pub error: ErrorCause,
+
+    /// HTTP status code of the response
+    pub status: u16,
+}
+
+impl std::fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.status, self.error)
+    }
+}
+
+impl std::error::Error for ErrorResponse {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SearchResponse;
+
+    #[test]
+    fn deserializes_parsing_exception() {
+        let json = json!({
+          "error": {
+            "root_cause": [
+              {
+                "type": "parsing_exception",
+                "reason": "unknown query [mach]",
+                "line": 1,
+                "col": 19
+              }
+            ],
+            "type": "parsing_exception",
+            "reason": "unknown query [mach]",
+            "line": 1,
+            "col": 19,
+            "caused_by": {
+              "type": "named_object_not_found_exception",
+              "reason": "[1:19] unknown field [mach]"
+            }
+          },
+          "status": 400
+        });
+
+        let subject: ErrorResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.status, 400);
+        assert_eq!(subject.error.ty.as_deref(), Some("parsing_exception"));
+        assert_eq!(subject.error.root_cause.len(), 1);
+        assert_eq!(subject.error.additional_details["line"], json!(1));
+        assert_eq!(
+            subject.error.caused_by.as_ref().unwrap().ty.as_deref(),
+            Some("named_object_not_found_exception")
+        );
+        assert_eq!(
+            subject.to_string(),
+            "[400] parsing_exception: unknown query [mach]"
+        );
+    }
+
+    #[test]
+    fn deserializes_index_not_found_exception() {
+        let json = json!({
+          "error": {
+            "root_cause": [
+              {
+                "type": "index_not_found_exception",
+                "reason": "no such index [missing-index]",
+                "resource.type": "index_or_alias",
+                "resource.id": "missing-index",
+                "index_uuid": "_na_",
+                "index": "missing-index"
+              }
+            ],
+            "type": "index_not_found_exception",
+            "reason": "no such index [missing-index]",
+            "resource.type": "index_or_alias",
+            "resource.id": "missing-index",
+            "index_uuid": "_na_",
+            "index": "missing-index"
+          },
+          "status": 404
+        });
+
+        let subject: ErrorResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.status, 404);
+        assert_eq!(subject.error.index.as_deref(), Some("missing-index"));
+        assert_eq!(subject.error.index_uuid.as_deref(), Some("_na_"));
+        assert_eq!(
+            subject.error.additional_details["resource.type"],
+            json!("index_or_alias")
+        );
+    }
+
+    #[test]
+    fn deserializes_search_phase_execution_exception() {
+        let json = json!({
+          "error": {
+            "root_cause": [
+              {
+                "type": "query_shard_exception",
+                "reason": "failed to create query: For input string: \"abc\"",
+                "index_uuid": "kdvZRRD3Q4e7b7D9pZrr0w",
+                "index": "my-index"
+              }
+            ],
+            "type": "search_phase_execution_exception",
+            "reason": "all shards failed",
+            "phase": "query",
+            "grouped": true,
+            "failed_shards": [
+              {
+                "shard": 0,
+                "index": "my-index",
+                "node": "ZqmDvTdGQsy-GnPbc6n2YQ",
+                "reason": {
+                  "type": "query_shard_exception",
+                  "reason": "failed to create query: For input string: \"abc\"",
+                  "index_uuid": "kdvZRRD3Q4e7b7D9pZrr0w",
+                  "index": "my-index",
+                  "caused_by": {
+                    "type": "number_format_exception",
+                    "reason": "For input string: \"abc\""
+                  }
+                }
+              }
+            ]
+          },
+          "status": 400
+        });
+
+        let subject: ErrorResponse = serde_json::from_value(json).unwrap();
+        let failed_shard = &subject.error.failed_shards[0];
+
+        assert_eq!(subject.error.additional_details["phase"], json!("query"));
+        assert_eq!(failed_shard.shard, Some(0));
+        assert_eq!(
+            failed_shard
+                .reason
+                .as_ref()
+                .and_then(|reason| reason.caused_by.as_ref())
+                .and_then(|cause| cause.ty.as_deref()),
+            Some("number_format_exception")
+        );
+    }
+
+    #[test]
+    fn deserializes_partial_shard_failures() {
+        let json = json!({
+          "took": 12,
+          "timed_out": false,
+          "_shards": {
+            "total": 2,
+            "successful": 1,
+            "skipped": 0,
+            "failed": 1,
+            "failures": [
+              {
+                "shard": 1,
+                "index": "my-index",
+                "node": "ZqmDvTdGQsy-GnPbc6n2YQ",
+                "reason": {
+                  "type": "script_exception",
+                  "reason": "runtime error",
+                  "script_stack": ["doc['missing'].value", "    ^---- HERE"],
+                  "lang": "painless"
+                }
+              }
+            ]
+          },
+          "hits": { "total": { "value": 0, "relation": "eq" }, "max_score": null, "hits": [] }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let failure = &subject.shards.failures[0];
+        let reason = failure.reason.as_ref().unwrap();
+
+        assert_eq!(subject.shards.failed, 1);
+        assert_eq!(failure.index.as_deref(), Some("my-index"));
+        assert_eq!(reason.to_string(), "script_exception: runtime error");
+        assert_eq!(reason.additional_details["lang"], json!("painless"));
+    }
+}
@@ -4,8 +4,13 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 /// Represents a single matched document
+///
+/// By default the document source is kept as raw [`Source`] and can be parsed
+/// on demand with [`Hit::source`]. Alternatively, the source type can be
+/// specified upfront, e.g. `Hit<MyDocument>`, or `Hit<Option<MyDocument>>`
+/// when some hits might not return the `_source`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Hit {
+pub struct Hit<T = Source> {
     /// Search explanation
     #[serde(
         skip_serializing_if = "ShouldSkip::should_skip",
@@ -34,17 +39,37 @@ pub struct Hit {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_score")]
     pub score: Option<f32>,
 
+    /// Document version, returned when the request enabled
+    /// [`version`](crate::Search::version)
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_version")]
+    pub version: Option<u64>,
+
+    /// Sequence number of the last operation on the document, returned when
+    /// the request enabled
+    /// [`seq_no_primary_term`](crate::Search::seq_no_primary_term)
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_seq_no")]
+    pub seq_no: Option<u64>,
+
+    /// Primary term of the last operation on the document, returned when the
+    /// request enabled
+    /// [`seq_no_primary_term`](crate::Search::seq_no_primary_term)
+    #[serde(
+        skip_serializing_if = "ShouldSkip::should_skip",
+        rename = "_primary_term"
+    )]
+    pub primary_term: Option<u64>,
+
     /// Nested document identity
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_nested")]
     pub nested: Option<NestedIdentity>,
 
     /// Document source
     #[serde(
-        skip_serializing_if = "ShouldSkip::should_skip",
-        rename = "_source",
-        default
+        skip_serializing_if = "skip_default_source",
+        bound(serialize = "T: serde::Serialize + 'static"),
+        rename = "_source"
     )]
-    pub source: Source,
+    pub source: T,
 
     /// Highlighted matches
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
@@ -76,3 +101,38 @@ impl Hit {
         self.source.parse()
     }
 }
+
+/// Skips the [default](Source::default) source of hits that didn't return
+/// one, so that they're serialized back as they were received
+fn skip_default_source<T: 'static>(source: &T) -> bool {
+    let source: &dyn std::any::Any = source;
+
+    source
+        .downcast_ref::<Source>()
+        .map_or(false, ShouldSkip::should_skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_missing_source() {
+        let value = json!({ "_index": "test", "_id": "1", "fields": { "id": [1] } });
+
+        let hit: Hit = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(hit.source, Source::default());
+        assert_eq!(serde_json::to_value(&hit).unwrap(), value);
+    }
+
+    #[test]
+    fn keeps_typed_source() {
+        let value = json!({ "_index": "test", "_id": "1", "_source": null });
+
+        let hit: Hit<Option<Value>> = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(hit.source, None);
+        assert_eq!(serde_json::to_value(&hit).unwrap(), value);
+    }
+}
@@ -1,9 +1,9 @@
-use super::{Hit, TotalHits};
+use super::{Hit, Source, TotalHits};
 use crate::util::ShouldSkip;
 
 /// Matched hits
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
-pub struct HitsMetadata {
+pub struct HitsMetadata<T = Source> {
     /// Total number of matched documents
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     pub total: Option<TotalHits>,
@@ -14,6 +14,9 @@ pub struct HitsMetadata {
     pub max_score: Option<f32>,
 
     /// Matched hits
-    #[serde(default = "Vec::new")]
-    pub hits: Vec<Hit>,
+    #[serde(
+        default = "Vec::new",
+        bound(serialize = "T: serde::Serialize + 'static")
+    )]
+    pub hits: Vec<Hit<T>>,
 }
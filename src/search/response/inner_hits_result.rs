@@ -6,3 +6,82 @@ pub struct InnerHitsResult {
     /// The actual inner hits
     pub hits: HitsMetadata,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::SearchResponse;
+
+    #[test]
+    fn deserializes_multi_level_nested_inner_hits() {
+        let json = json!({
+          "took": 1,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 1, "relation": "eq" },
+            "max_score": 0.5753642,
+            "hits": [
+              {
+                "_index": "test",
+                "_id": "1",
+                "_score": 0.5753642,
+                "_source": {
+                  "comments": [
+                    { "message": "hey", "votes": [{ "value": 1, "voter": "kimchy" }] },
+                    { "message": "hi", "votes": [{ "value": 1, "voter": "kimchy" }, { "value": -1, "voter": "other" }] }
+                  ]
+                },
+                "inner_hits": {
+                  "comments.votes": {
+                    "hits": {
+                      "total": { "value": 1, "relation": "eq" },
+                      "max_score": 0.5753642,
+                      "hits": [
+                        {
+                          "_index": "test",
+                          "_id": "1",
+                          "_nested": {
+                            "field": "comments",
+                            "offset": 1,
+                            "_nested": {
+                              "field": "votes",
+                              "offset": 1
+                            }
+                          },
+                          "_score": 0.5753642,
+                          "_source": { "value": -1, "voter": "other" },
+                          "highlight": { "comments.votes.voter": ["<em>other</em>"] }
+                        }
+                      ]
+                    }
+                  }
+                }
+              }
+            ]
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let inner_hits = &subject.hits.hits[0].inner_hits["comments.votes"].hits;
+        let inner_hit = &inner_hits.hits[0];
+        let nested = inner_hit.nested.as_ref().unwrap();
+
+        assert_eq!(inner_hits.total.as_ref().unwrap().value, 1);
+        assert_eq!(inner_hits.max_score, Some(0.5753642));
+        assert_eq!(
+            nested
+                .iter()
+                .map(|identity| (identity.field.as_str(), identity.offset))
+                .collect::<Vec<_>>(),
+            [("comments", 1), ("votes", 1)]
+        );
+        assert_eq!(
+            inner_hit.source::<serde_json::Value>().unwrap(),
+            json!({ "value": -1, "voter": "other" })
+        );
+        assert_eq!(
+            inner_hit.highlight["comments.votes.voter"],
+            ["<em>other</em>"]
+        );
+    }
+}
@@ -1,31 +1,47 @@
+mod aggregation_results;
+mod bucket;
+mod bucket_aggregation_result;
 mod cluster_statistics;
 mod error_cause;
+mod error_response;
 mod explanation;
 mod hit;
 mod hits_metadata;
 mod inner_hits_result;
 mod nested_identity;
+mod percentiles_aggregation_result;
+mod profile;
 mod search_response;
 mod shard_failure;
 mod shard_statistics;
 mod source;
+mod stats_aggregation_result;
 mod suggest;
 mod suggest_option;
 mod total_hits;
 mod total_hits_relation;
+mod value_aggregation_result;
 
+pub use self::aggregation_results::*;
+pub use self::bucket::*;
+pub use self::bucket_aggregation_result::*;
 pub use self::cluster_statistics::*;
 pub use self::error_cause::*;
+pub use self::error_response::*;
 pub use self::explanation::*;
 pub use self::hit::*;
 pub use self::hits_metadata::*;
 pub use self::inner_hits_result::*;
 pub use self::nested_identity::*;
+pub use self::percentiles_aggregation_result::*;
+pub use self::profile::*;
 pub use self::search_response::*;
 pub use self::shard_failure::*;
 pub use self::shard_statistics::*;
 pub use self::source::*;
+pub use self::stats_aggregation_result::*;
 pub use self::suggest::*;
 pub use self::suggest_option::*;
 pub use self::total_hits::*;
 pub use self::total_hits_relation::*;
+pub use self::value_aggregation_result::*;
@@ -13,3 +13,12 @@ pub struct NestedIdentity {
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", rename = "_nested")]
     pub nested: Option<Box<NestedIdentity>>,
 }
+
+impl NestedIdentity {
+    /// Iterates over this and the nested document identities, from the
+    /// outermost to the innermost one, i.e. the path to address the matched
+    /// object within the `_source` of the top level document
+    pub fn iter(&self) -> impl Iterator<Item = &NestedIdentity> {
+        std::iter::successors(Some(self), |identity| identity.nested.as_deref())
+    }
+}
@@ -0,0 +1,200 @@
+use crate::util::ShouldSkip;
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+
+/// Result of a [`percentiles`](crate::PercentilesAggregation) or
+/// [`percentile_ranks`](crate::PercentileRanksAggregation) aggregation, in
+/// either the `keyed` (default) or the array form
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PercentilesAggregationResult {
+    /// Calculated percentiles, sorted by key
+    #[serde(deserialize_with = "deserialize_percentiles")]
+    pub values: Vec<Percentile>,
+}
+
+/// A single calculated percentile
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Percentile {
+    /// Requested percent, or value for
+    /// [`percentile_ranks`](crate::PercentileRanksAggregation)
+    pub key: f64,
+
+    /// Calculated value, [`None`] when no value was aggregated
+    pub value: Option<f64>,
+
+    /// Formatted calculated value
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub value_as_string: Option<String>,
+}
+
+impl PercentilesAggregationResult {
+    /// Calculated value for the given percent
+    pub fn get(&self, key: f64) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|percentile| percentile.key == key)
+            .and_then(|percentile| percentile.value)
+    }
+}
+
+fn deserialize_percentiles<'de, D>(deserializer: D) -> Result<Vec<Percentile>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct PercentilesVisitor;
+
+    impl<'de> Visitor<'de> for PercentilesVisitor {
+        type Value = Vec<Percentile>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("an array or a map of percentiles")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+            while let Some(value) = seq.next_element()? {
+                values.push(value);
+            }
+
+            sort_percentiles(&mut values);
+
+            Ok(values)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            use serde::de::Error;
+
+            let mut values = Vec::<(String, Percentile)>::new();
+            let mut formatted = Vec::<(String, String)>::new();
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.strip_suffix("_as_string") {
+                    Some(key) => formatted.push((key.to_string(), map.next_value()?)),
+                    None => {
+                        let percent = key.parse().map_err(|_| {
+                            A::Error::custom(format!("invalid percentile key `{key}`"))
+                        })?;
+
+                        values.push((
+                            key,
+                            Percentile {
+                                key: percent,
+                                value: map.next_value()?,
+                                value_as_string: None,
+                            },
+                        ));
+                    }
+                }
+            }
+
+            for (key, value_as_string) in formatted {
+                if let Some((_, percentile)) = values.iter_mut().find(|(k, _)| *k == key) {
+                    percentile.value_as_string = Some(value_as_string);
+                }
+            }
+
+            let mut values: Vec<Percentile> = values
+                .into_iter()
+                .map(|(_, percentile)| percentile)
+                .collect();
+
+            sort_percentiles(&mut values);
+
+            Ok(values)
+        }
+    }
+
+    deserializer.deserialize_any(PercentilesVisitor)
+}
+
+/// Keyed percentiles may come in any order, e.g. `100.0` before `5.0` once
+/// parsed into a map, hence sorting by numeric key
+fn sort_percentiles(values: &mut [Percentile]) {
+    values.sort_by(|left, right| left.key.total_cmp(&right.key));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_keyed_percentiles() {
+        let json = json!({
+          "values": {
+            "1.0": 5.0,
+            "1.0_as_string": "5.0ms",
+            "99.0": 987.0,
+            "99.0_as_string": "987.0ms",
+            "50.0": null
+          }
+        });
+
+        let subject: PercentilesAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            subject.values,
+            [
+                Percentile {
+                    key: 1.0,
+                    value: Some(5.0),
+                    value_as_string: Some("5.0ms".to_string()),
+                },
+                Percentile {
+                    key: 50.0,
+                    value: None,
+                    value_as_string: None,
+                },
+                Percentile {
+                    key: 99.0,
+                    value: Some(987.0),
+                    value_as_string: Some("987.0ms".to_string()),
+                },
+            ]
+        );
+        assert_eq!(subject.get(99.0), Some(987.0));
+        assert_eq!(subject.get(50.0), None);
+    }
+
+    #[test]
+    fn sorts_keyed_percentiles_numerically() {
+        let json = json!({
+          "values": {
+            "100.0": 1000.0,
+            "5.0": 50.0,
+            "25.0": 250.0
+          }
+        });
+
+        let subject: PercentilesAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            subject
+                .values
+                .iter()
+                .map(|percentile| percentile.key)
+                .collect::<Vec<_>>(),
+            [5.0, 25.0, 100.0]
+        );
+    }
+
+    #[test]
+    fn deserializes_array_percentiles() {
+        let json = json!({
+          "values": [
+            { "key": 1.0, "value": 5.0 },
+            { "key": 5.0, "value": 25.0, "value_as_string": "25.0" }
+          ]
+        });
+
+        let subject: PercentilesAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.get(1.0), Some(5.0));
+        assert_eq!(subject.values[1].value_as_string.as_deref(), Some("25.0"));
+    }
+}
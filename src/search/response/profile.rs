@@ -0,0 +1,379 @@
+use crate::{util::ShouldSkip, Map};
+use serde_json::Value;
+
+/// Detailed timing information about the execution of individual components
+/// in a search request, returned when [profiling](crate::Search::profile) is
+/// enabled
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-profile.html>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Profile {
+    /// Profiled shards
+    #[serde(default)]
+    pub shards: Vec<ShardProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+/// Profile of a single shard that participated in the search
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShardProfile {
+    /// Shard identifier, i.e. `[nodeID][indexName][shardID]`
+    pub id: String,
+
+    /// Profiled searches, one for each search executed against the shard
+    #[serde(default)]
+    pub searches: Vec<SearchProfile>,
+
+    /// Profiled aggregations
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub aggregations: Vec<AggregationProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile,
+    /// e.g. `fetch` or `dfs` sections
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+impl ShardProfile {
+    /// Sum of the time spent in top level queries of every search, in nanoseconds
+    pub fn query_time_in_nanos(&self) -> u64 {
+        self.searches
+            .iter()
+            .flat_map(|search| &search.query)
+            .map(|query| query.time_in_nanos)
+            .sum()
+    }
+}
+
+/// Profile of a search executed against a shard
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchProfile {
+    /// Query tree, as rewritten by Lucene
+    #[serde(default)]
+    pub query: Vec<QueryProfile>,
+
+    /// Total time spent rewriting queries, in nanoseconds
+    #[serde(default)]
+    pub rewrite_time: u64,
+
+    /// Collectors tree
+    #[serde(default)]
+    pub collector: Vec<CollectorProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+/// Timing of a single Lucene query and of the queries it is made of
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueryProfile {
+    /// Lucene class name of the query, e.g. `TermQuery` or `BooleanQuery`
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Lucene explanation of the query
+    pub description: String,
+
+    /// Total time spent executing the query, including its children, in
+    /// nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Low-level Lucene timings (e.g. `create_weight`, `next_doc`) and counts
+    #[serde(default)]
+    pub breakdown: Map<String, u64>,
+
+    /// Queries this one is made of
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<QueryProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+impl QueryProfile {
+    /// Iterates over this query and all of its descendants, depth first
+    pub fn iter(&self) -> impl Iterator<Item = &QueryProfile> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let query = stack.pop()?;
+            stack.extend(query.children.iter().rev());
+            Some(query)
+        })
+    }
+
+    /// Time spent in this query alone, excluding its children, in nanoseconds
+    pub fn self_time_in_nanos(&self) -> u64 {
+        let children = self
+            .children
+            .iter()
+            .map(|child| child.time_in_nanos)
+            .sum::<u64>();
+
+        self.time_in_nanos.saturating_sub(children)
+    }
+}
+
+/// Timing of a Lucene collector, which coordinates the traversal, scoring and
+/// collection of matching documents
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CollectorProfile {
+    /// Lucene class name of the collector
+    pub name: String,
+
+    /// Collector classification, e.g. `search_top_hits` or `aggregation`
+    pub reason: String,
+
+    /// Total time spent in the collector, in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Wrapped collectors
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<CollectorProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+/// Timing of an aggregation and of its sub-aggregations
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AggregationProfile {
+    /// Class name of the aggregator
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Aggregation name from the request
+    pub description: String,
+
+    /// Total time spent executing the aggregation, including its children,
+    /// in nanoseconds
+    pub time_in_nanos: u64,
+
+    /// Low-level timings (e.g. `initialize`, `collect`) and counts
+    #[serde(default)]
+    pub breakdown: Map<String, u64>,
+
+    /// Aggregation specific debug information
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub debug: Option<Value>,
+
+    /// Sub-aggregations
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub children: Vec<AggregationProfile>,
+
+    /// Additional fields that are not part of the strongly typed profile
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = r#"{
+          "shards": [
+            {
+              "id": "[q2aE02wS1R8qQFnYu6vDVQ][my-index-000001][0]",
+              "node_id": "q2aE02wS1R8qQFnYu6vDVQ",
+              "shard_id": 0,
+              "index": "my-index-000001",
+              "cluster": "(local)",
+              "searches": [
+                {
+                  "query": [
+                    {
+                      "type": "BooleanQuery",
+                      "description": "message:get message:search",
+                      "time_in_nanos": 11972972,
+                      "breakdown": {
+                        "set_min_competitive_score_count": 19,
+                        "match_count": 5,
+                        "shallow_advance_count": 0,
+                        "set_min_competitive_score": 47880,
+                        "next_doc": 39022,
+                        "match": 4456,
+                        "next_doc_count": 5,
+                        "score_count": 5,
+                        "compute_max_score_count": 0,
+                        "compute_max_score": 0,
+                        "advance": 84525,
+                        "advance_count": 1,
+                        "score": 37779,
+                        "build_scorer_count": 38,
+                        "create_weight": 4694895,
+                        "shallow_advance": 0,
+                        "create_weight_count": 1,
+                        "build_scorer": 7112295,
+                        "count_weight": 0,
+                        "count_weight_count": 0
+                      },
+                      "children": [
+                        {
+                          "type": "TermQuery",
+                          "description": "message:get",
+                          "time_in_nanos": 3801935,
+                          "breakdown": {
+                            "set_min_competitive_score_count": 0,
+                            "match_count": 0,
+                            "shallow_advance_count": 3,
+                            "set_min_competitive_score": 0,
+                            "next_doc": 0,
+                            "match": 0,
+                            "next_doc_count": 0,
+                            "score_count": 5,
+                            "compute_max_score_count": 3,
+                            "compute_max_score": 32487,
+                            "advance": 5749,
+                            "advance_count": 6,
+                            "score": 16219,
+                            "build_scorer_count": 58,
+                            "create_weight": 2382719,
+                            "shallow_advance": 9754,
+                            "create_weight_count": 1,
+                            "build_scorer": 1355007,
+                            "count_weight": 0,
+                            "count_weight_count": 0
+                          }
+                        },
+                        {
+                          "type": "TermQuery",
+                          "description": "message:search",
+                          "time_in_nanos": 205654,
+                          "breakdown": {
+                            "set_min_competitive_score_count": 0,
+                            "match_count": 0,
+                            "shallow_advance_count": 3,
+                            "set_min_competitive_score": 0,
+                            "next_doc": 0,
+                            "match": 0,
+                            "next_doc_count": 0,
+                            "score_count": 5,
+                            "compute_max_score_count": 3,
+                            "compute_max_score": 6678,
+                            "advance": 12733,
+                            "advance_count": 6,
+                            "score": 6627,
+                            "build_scorer_count": 58,
+                            "create_weight": 130839,
+                            "shallow_advance": 2512,
+                            "create_weight_count": 1,
+                            "build_scorer": 46153,
+                            "count_weight": 0,
+                            "count_weight_count": 0
+                          }
+                        }
+                      ]
+                    }
+                  ],
+                  "rewrite_time": 451233,
+                  "collector": [
+                    {
+                      "name": "QueryPhaseCollector",
+                      "reason": "search_query_phase",
+                      "time_in_nanos": 775274,
+                      "children": [
+                        {
+                          "name": "SimpleTopScoreDocCollector",
+                          "reason": "search_top_hits",
+                          "time_in_nanos": 775274
+                        }
+                      ]
+                    }
+                  ]
+                }
+              ],
+              "aggregations": [
+                {
+                  "type": "NumericTermsAggregator",
+                  "description": "my_scoped_agg",
+                  "time_in_nanos": 79294,
+                  "breakdown": {
+                    "reduce": 0,
+                    "build_aggregation": 30885,
+                    "build_aggregation_count": 1,
+                    "initialize": 2623,
+                    "initialize_count": 1,
+                    "reduce_count": 0,
+                    "collect": 45786,
+                    "collect_count": 4,
+                    "build_leaf_collector": 18211,
+                    "build_leaf_collector_count": 1,
+                    "post_collection": 1906,
+                    "post_collection_count": 1
+                  },
+                  "debug": {
+                    "total_buckets": 1,
+                    "result_strategy": "long_terms",
+                    "built_buckets": 1
+                  }
+                }
+              ],
+              "fetch": {
+                "type": "fetch",
+                "description": "",
+                "time_in_nanos": 660555,
+                "breakdown": {
+                  "next_reader": 7292,
+                  "next_reader_count": 1,
+                  "load_stored_fields": 299325,
+                  "load_stored_fields_count": 5
+                }
+              }
+            }
+          ]
+        }
+        "#;
+
+        let profile: Profile = serde_json::from_str(json).unwrap();
+        let shard = &profile.shards[0];
+        let search = &shard.searches[0];
+        let query = &search.query[0];
+
+        assert_eq!(shard.id, "[q2aE02wS1R8qQFnYu6vDVQ][my-index-000001][0]");
+        assert_eq!(shard.additional_details["shard_id"], json!(0));
+        assert!(shard.additional_details.contains_key("fetch"));
+        assert_eq!(shard.query_time_in_nanos(), 11972972);
+        assert_eq!(search.rewrite_time, 451233);
+
+        assert_eq!(query.ty, "BooleanQuery");
+        assert_eq!(query.breakdown["create_weight"], 4694895);
+        assert_eq!(
+            query
+                .iter()
+                .map(|query| query.description.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "message:get message:search",
+                "message:get",
+                "message:search"
+            ]
+        );
+        assert_eq!(
+            query
+                .iter()
+                .map(QueryProfile::self_time_in_nanos)
+                .sum::<u64>(),
+            query.time_in_nanos
+        );
+
+        assert_eq!(search.collector[0].children[0].reason, "search_top_hits");
+
+        assert_eq!(shard.aggregations[0].description, "my_scoped_agg");
+        assert_eq!(
+            shard.aggregations[0].debug,
+            Some(json!({
+                "total_buckets": 1,
+                "result_strategy": "long_terms",
+                "built_buckets": 1
+            }))
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use super::{ClusterStatistics, HitsMetadata, ShardStatistics, Suggest};
+use super::{
+    AggregationResults, ClusterStatistics, HitsMetadata, Profile, ShardStatistics, Source, Suggest,
+};
 use crate::{util::ShouldSkip, Map};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 /// Search response
+///
+/// Document sources are kept as raw [`Source`] unless a concrete document type
+/// is given, i.e. `SearchResponse<MyDocument>`.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
-pub struct SearchResponse {
+pub struct SearchResponse<T = Source> {
     /// The time that it took Elasticsearch to process the query
     pub took: u32,
 
@@ -47,15 +52,20 @@ pub struct SearchResponse {
     pub shards: ShardStatistics,
 
     /// Search hits
-    pub hits: HitsMetadata,
+    #[serde(bound(serialize = "T: serde::Serialize + 'static"))]
+    pub hits: HitsMetadata<T>,
 
     /// Search aggregations
-    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
-    pub aggregations: Option<Value>,
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
+    pub aggregations: AggregationResults,
 
     /// Suggest response
     #[serde(skip_serializing_if = "ShouldSkip::should_skip", default)]
     pub suggest: Map<String, Vec<Suggest>>,
+
+    /// Profiling results, present when the request enabled profiling
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub profile: Option<Profile>,
 }
 
 impl SearchResponse {
@@ -68,12 +78,49 @@ impl SearchResponse {
     }
 }
 
+impl<T> SearchResponse<T> {
+    /// Key of the last bucket of a [composite](crate::CompositeAggregation)
+    /// aggregation, pass it to
+    /// [`CompositeAggregation::after`](crate::CompositeAggregation::after) to
+    /// request the next page. Returns [None] once every bucket was returned.
+    pub fn after_key(&self, aggregation_name: &str) -> Option<&serde_json::Map<String, Value>> {
+        self.aggregations
+            .raw(aggregation_name)?
+            .get("after_key")?
+            .as_object()
+    }
+
+    /// Suggestion entries of the suggester, matched with or without the type
+    /// prefix Elasticsearch adds when `typed_keys` is enabled, e.g.
+    /// `term#my-suggester`. Empty when the suggester is missing
+    pub fn suggestions(&self, suggester_name: &str) -> &[Suggest] {
+        self.suggest
+            .get(suggester_name)
+            .or_else(|| {
+                self.suggest
+                    .iter()
+                    .find(|(key, _)| {
+                        matches!(key.split_once('#'), Some((_, key)) if key == suggester_name)
+                    })
+                    .map(|(_, suggest)| suggest)
+            })
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the typed `_source` of each hit
+    pub fn sources(&self) -> impl Iterator<Item = &T> {
+        self.hits.hits.iter().map(|hit| &hit.source)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::assert_serialize_aggregation;
     use crate::{
-        CompletionSuggestOption, Hit, PhraseSuggestOption, Source, SuggestOption,
-        TermSuggestOption, TotalHits, TotalHitsRelation,
+        Aggregation, CompletionSuggestOption, CompositeSource, Hit, PhraseSuggestOption, Source,
+        SuggestOption, TermSuggestOption, TotalHits, TotalHitsRelation,
     };
 
     #[test]
@@ -186,6 +233,9 @@ mod tests {
                     index: "_index".into(),
                     id: "123".into(),
                     score: Some(1.0),
+                    version: None,
+                    seq_no: None,
+                    primary_term: None,
                     source: Source::from_string("null".to_string()).unwrap(),
                     highlight: Default::default(),
                     inner_hits: Default::default(),
@@ -194,7 +244,7 @@ mod tests {
                     fields: Default::default(),
                 }],
             },
-            aggregations: None,
+            aggregations: Default::default(),
             terminated_early: None,
             scroll_id: None,
             fields: Default::default(),
@@ -261,9 +311,13 @@ mod tests {
                     }],
                 ),
             ]),
+            profile: None,
         };
 
         assert_eq!(actual, expected);
+        assert_eq!(actual.suggestions("my-first-suggester").len(), 3);
+        assert_eq!(actual.suggestions("song-suggest").len(), 1);
+        assert!(actual.suggestions("missing").is_empty());
     }
 
     #[test]
@@ -317,4 +371,306 @@ mod tests {
 
         assert_eq!(subject, expectation);
     }
+
+    #[test]
+    fn deserializes_typed_documents() {
+        let json = json!({
+          "took": 5,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 2, "relation": "eq" },
+            "max_score": null,
+            "hits": [
+              {
+                "_index": "my-index",
+                "_id": "1",
+                "_score": null,
+                "_source": { "id": 1, "title": "first" },
+                "sort": [1]
+              },
+              {
+                "_index": "my-index",
+                "_id": "2",
+                "_score": null,
+                "sort": [2]
+              }
+            ]
+          }
+        });
+
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        struct Document {
+            id: i32,
+            title: String,
+        }
+
+        let subject: SearchResponse<Option<Document>> =
+            serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(subject.hits.max_score, None);
+        assert_eq!(subject.hits.hits[0].id, "1");
+        assert_eq!(subject.hits.hits[0].sort, vec![json!(1)]);
+        assert_eq!(
+            subject.sources().cloned().collect::<Vec<_>>(),
+            vec![
+                Some(Document {
+                    id: 1,
+                    title: "first".to_string()
+                }),
+                None
+            ]
+        );
+
+        let subject: SearchResponse = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(subject.hits.hits[1].source, Source::default());
+
+        assert!(serde_json::from_value::<SearchResponse<Document>>(json).is_err());
+    }
+
+    #[test]
+    fn deserializes_es7_response() {
+        let json = json!({
+          "took": 3,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 1, "relation": "eq" },
+            "max_score": 0.2876821,
+            "hits": [
+              {
+                "_index": "my-index-000001",
+                "_type": "_doc",
+                "_id": "0",
+                "_version": 1,
+                "_seq_no": 0,
+                "_primary_term": 1,
+                "_score": 0.2876821,
+                "_source": {
+                  "user": { "id": "kimchy" },
+                  "message": "GET /search HTTP/1.1 200 1070000"
+                },
+                "highlight": {
+                  "message": ["GET /<em>search</em> HTTP/1.1 200 1070000"]
+                },
+                "matched_queries": ["message_query"]
+              }
+            ]
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let hit = &subject.hits.hits[0];
+
+        assert_eq!(subject.took, 3);
+        assert_eq!(subject.shards.total, 1);
+        assert_eq!(
+            subject.hits.total,
+            Some(TotalHits {
+                value: 1,
+                relation: TotalHitsRelation::Equal,
+            })
+        );
+        assert_eq!(subject.hits.max_score, Some(0.2876821));
+        assert_eq!(hit.version, Some(1));
+        assert_eq!(hit.seq_no, Some(0));
+        assert_eq!(hit.primary_term, Some(1));
+        assert_eq!(
+            hit.highlight["message"],
+            ["GET /<em>search</em> HTTP/1.1 200 1070000"]
+        );
+        assert_eq!(hit.matched_queries, ["message_query"]);
+        assert_eq!(
+            hit.source::<Value>().unwrap()["user"]["id"],
+            json!("kimchy")
+        );
+    }
+
+    #[test]
+    fn deserializes_es8_stored_fields_response() {
+        let json = json!({
+          "took": 2,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": {
+            "total": { "value": 10000, "relation": "gte" },
+            "max_score": null,
+            "hits": [
+              {
+                "_index": "my-index-000001",
+                "_id": "oX9uXXoB0da05OCR3adK",
+                "_score": null,
+                "fields": {
+                  "@timestamp": ["2099-11-15T14:12:12.000Z"],
+                  "user.id": ["kimchy"]
+                },
+                "sort": [4098435132000u64]
+              }
+            ]
+          }
+        });
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Document {
+            message: String,
+        }
+
+        let subject: SearchResponse<Option<Document>> =
+            serde_json::from_value(json.clone()).unwrap();
+        let hit = &subject.hits.hits[0];
+
+        assert_eq!(subject.hits.max_score, None);
+        assert_eq!(hit.score, None);
+        assert_eq!(hit.source, None);
+        assert_eq!(hit.version, None);
+        assert_eq!(hit.fields["user.id"], json!(["kimchy"]));
+        assert_eq!(hit.sort, [json!(4098435132000u64)]);
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.hits.hits[0].source, Source::default());
+        assert_eq!(subject.documents::<Option<Document>>().unwrap(), [None]);
+    }
+
+    #[test]
+    fn extracts_composite_after_key() {
+        let json = json!({
+          "took": 1,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": { "total": { "value": 0, "relation": "eq" }, "max_score": null, "hits": [] },
+          "aggregations": {
+            "my_buckets": {
+              "after_key": { "date": 1494288000000i64, "product": "mad max" },
+              "buckets": []
+            },
+            "other": { "value": 1.0 }
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let after_key = subject.after_key("my_buckets").unwrap();
+
+        assert_eq!(after_key["product"], json!("mad max"));
+        assert_eq!(subject.after_key("other"), None);
+        assert_eq!(subject.after_key("missing"), None);
+
+        assert_serialize_aggregation(
+            Aggregation::composite()
+                .source(CompositeSource::terms("product", "product"))
+                .after(after_key.clone()),
+            json!({
+                "composite": {
+                    "sources": [{ "product": { "terms": { "field": "product" } } }],
+                    "after": { "date": 1494288000000i64, "product": "mad max" }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn deserializes_aggregations() {
+        let json = json!({
+          "took": 4,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": { "total": { "value": 7, "relation": "eq" }, "max_score": null, "hits": [] },
+          "aggregations": {
+            "grades_stats": {
+              "count": 2,
+              "min": 50.0,
+              "max": 100.0,
+              "avg": 75.0,
+              "sum": 150.0
+            },
+            "genres": {
+              "doc_count_error_upper_bound": 0,
+              "sum_other_doc_count": 0,
+              "buckets": [
+                { "key": "electronic", "doc_count": 6, "max_price": { "value": 200.0 } },
+                { "key": "rock", "doc_count": 1, "max_price": { "value": 10.0 } }
+              ]
+            }
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let stats = subject.aggregations.get_stats("grades_stats").unwrap();
+        let genres = subject.aggregations.get_terms("genres").unwrap();
+
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.avg, Some(75.0));
+        assert_eq!(
+            genres
+                .buckets
+                .iter()
+                .map(|bucket| bucket.aggregations.get_value("max_price").unwrap().value)
+                .collect::<Vec<_>>(),
+            [Some(200.0), Some(10.0)]
+        );
+    }
+
+    #[test]
+    fn deserializes_profile() {
+        let json = json!({
+          "took": 1,
+          "timed_out": false,
+          "_shards": { "total": 1, "successful": 1, "skipped": 0, "failed": 0 },
+          "hits": { "total": { "value": 0, "relation": "eq" }, "max_score": null, "hits": [] },
+          "profile": {
+            "shards": [
+              {
+                "id": "[q2aE02wS1R8qQFnYu6vDVQ][my-index-000001][0]",
+                "searches": [
+                  {
+                    "query": [
+                      {
+                        "type": "TermQuery",
+                        "description": "message:search",
+                        "time_in_nanos": 10,
+                        "breakdown": { "score": 4 }
+                      }
+                    ],
+                    "rewrite_time": 5,
+                    "collector": [
+                      {
+                        "name": "SimpleTopScoreDocCollector",
+                        "reason": "search_top_hits",
+                        "time_in_nanos": 3
+                      }
+                    ]
+                  }
+                ],
+                "aggregations": [
+                  {
+                    "type": "GlobalAggregator",
+                    "description": "my_global",
+                    "time_in_nanos": 20,
+                    "breakdown": { "collect": 12 },
+                    "children": [
+                      {
+                        "type": "NumericTermsAggregator",
+                        "description": "my_terms",
+                        "time_in_nanos": 8,
+                        "breakdown": { "collect": 6 }
+                      }
+                    ]
+                  }
+                ]
+              }
+            ]
+          }
+        });
+
+        let subject: SearchResponse = serde_json::from_value(json).unwrap();
+        let shard = &subject.profile.unwrap().shards[0];
+
+        assert_eq!(shard.searches[0].query[0].ty, "TermQuery");
+        assert_eq!(shard.searches[0].rewrite_time, 5);
+        assert_eq!(shard.searches[0].collector[0].reason, "search_top_hits");
+        assert_eq!(shard.aggregations[0].ty, "GlobalAggregator");
+        assert_eq!(shard.aggregations[0].breakdown["collect"], 12);
+        assert_eq!(shard.aggregations[0].children[0].description, "my_terms");
+    }
 }
@@ -1,11 +1,24 @@
 use crate::util::ShouldSkip;
-use serde::de::DeserializeOwned;
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
 use serde_json::{value::RawValue, Value};
 
 /// Document source with delayed deserialization
-#[derive(Clone, Default, Serialize, Deserialize)]
+///
+/// Missing or `null` sources (e.g. `stored_fields`-only searches) deserialize
+/// into the [default](Source::default) source.
+#[derive(Clone, Default, Serialize)]
 pub struct Source(Box<RawValue>);
 
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Box<RawValue>>::deserialize(deserializer)
+            .map(|value| value.map(Self).unwrap_or_default())
+    }
+}
+
 impl std::fmt::Debug for Source {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
@@ -0,0 +1,76 @@
+use crate::{util::ShouldSkip, Map};
+use serde_json::Value;
+
+/// Result of a [`stats`](crate::StatsAggregation) or
+/// [`extended_stats`](crate::ExtendedStatsAggregation) aggregation
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatsAggregationResult {
+    /// Number of aggregated values
+    pub count: u64,
+
+    /// Lowest value, [`None`] when no value was aggregated
+    pub min: Option<f64>,
+
+    /// Highest value, [`None`] when no value was aggregated
+    pub max: Option<f64>,
+
+    /// Average value, [`None`] when no value was aggregated
+    pub avg: Option<f64>,
+
+    /// Sum of the values
+    pub sum: f64,
+
+    /// Formatted lowest value
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub min_as_string: Option<String>,
+
+    /// Formatted highest value
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub max_as_string: Option<String>,
+
+    /// Formatted average value
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub avg_as_string: Option<String>,
+
+    /// Formatted sum of the values
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub sum_as_string: Option<String>,
+
+    /// Additional statistics, e.g. `variance` or `std_deviation_bounds` of
+    /// [`extended_stats`](crate::ExtendedStatsAggregation)
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip", default, flatten)]
+    pub additional_details: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_successfully() {
+        let json = json!({
+          "count": 2,
+          "min": 50.0,
+          "max": 100.0,
+          "avg": 75.0,
+          "sum": 150.0,
+          "sum_of_squares": 12500.0,
+          "std_deviation": 25.0
+        });
+
+        let subject: StatsAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject.count, 2);
+        assert_eq!(subject.min, Some(50.0));
+        assert_eq!(subject.max, Some(100.0));
+        assert_eq!(subject.avg, Some(75.0));
+        assert_eq!(subject.sum, 150.0);
+        assert_eq!(subject.additional_details["std_deviation"], json!(25.0));
+
+        let json = json!({ "count": 0, "min": null, "max": null, "avg": null, "sum": 0.0 });
+
+        let subject: StatsAggregationResult = serde_json::from_value(json).unwrap();
+
+        assert_eq!(subject, StatsAggregationResult::default());
+    }
+}
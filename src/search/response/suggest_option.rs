@@ -102,3 +102,68 @@ pub struct PhraseSuggestOption {
     #[serde(default, skip_serializing_if = "ShouldSkip::should_skip")]
     pub highlighted: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_phrase_option() {
+        let json = json!({
+          "text": "nobel prize",
+          "highlighted": "<em>nobel</em> prize",
+          "score": 0.48614594,
+          "collate_match": true
+        });
+
+        let subject: SuggestOption = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            subject,
+            SuggestOption::Phrase(PhraseSuggestOption {
+                text: "nobel prize".to_string(),
+                score: 0.48614594,
+                collate_match: Some(true),
+                highlighted: Some("<em>nobel</em> prize".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn deserializes_completion_option() {
+        let json = json!({
+          "text": "timmy's",
+          "_index": "place",
+          "_id": "1",
+          "_score": 1.0,
+          "_source": { "suggest": ["timmy's", "starbucks", "dunkin donuts"] },
+          "contexts": { "place_type": ["cafe"] }
+        });
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Place {
+            suggest: Vec<String>,
+        }
+
+        let subject: SuggestOption = serde_json::from_value(json).unwrap();
+
+        let option = match subject {
+            SuggestOption::Completion(option) => option,
+            other => panic!("expected completion option, got {:?}", other),
+        };
+
+        assert_eq!(option.id, "1");
+        assert_eq!(option.score, 1.0);
+        assert_eq!(option.contexts["place_type"], ["cafe"]);
+        assert_eq!(
+            option.parse::<Place>().unwrap(),
+            Place {
+                suggest: vec![
+                    "timmy's".to_string(),
+                    "starbucks".to_string(),
+                    "dunkin donuts".to_string()
+                ]
+            }
+        );
+    }
+}
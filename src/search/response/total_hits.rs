@@ -19,3 +19,36 @@ impl TotalHits {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_exact_total() {
+        let total: TotalHits =
+            serde_json::from_value(json!({ "value": 42, "relation": "eq" })).unwrap();
+
+        assert_eq!(
+            total,
+            TotalHits {
+                value: 42,
+                relation: TotalHitsRelation::Equal,
+            }
+        );
+    }
+
+    #[test]
+    fn deserializes_lower_bound_total() {
+        let total: TotalHits =
+            serde_json::from_value(json!({ "value": 10000, "relation": "gte" })).unwrap();
+
+        assert_eq!(
+            total,
+            TotalHits {
+                value: 10000,
+                relation: TotalHitsRelation::GreaterThanOrEqualTo,
+            }
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use crate::util::ShouldSkip;
+
+/// Result of a single value metrics aggregation, such as
+/// [`avg`](crate::AvgAggregation) or [`cardinality`](crate::CardinalityAggregation)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ValueAggregationResult {
+    /// Aggregated value, [`None`] when no document had a value to aggregate
+    pub value: Option<f64>,
+
+    /// Formatted aggregated value
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pub value_as_string: Option<String>,
+}
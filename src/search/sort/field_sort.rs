@@ -1,13 +1,13 @@
-use super::{SortMode, SortOrder};
+use super::{NestedSort, Sort, SortMode, SortNumericType, SortOrder};
 use crate::util::ShouldSkip;
 use crate::Term;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Sorts search hits by other field values
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#sort-search-results>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct FieldSort {
     #[serde(skip)]
     field: String,
@@ -26,6 +26,22 @@ pub struct FieldSort {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     missing: Option<Term>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    numeric_type: Option<SortNumericType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    nested: Option<NestedSort>,
+}
+
+impl Sort {
+    /// Creates an instance of [`FieldSort`]
+    pub fn field<T>(field: T) -> FieldSort
+    where
+        T: ToString,
+    {
+        FieldSort::new(field)
+    }
 }
 
 impl FieldSort {
@@ -41,6 +57,8 @@ impl FieldSort {
             unmapped_type: None,
             format: None,
             missing: None,
+            numeric_type: None,
+            nested: None,
         }
     }
 
@@ -108,6 +126,23 @@ impl FieldSort {
         self.missing = Term::new(missing);
         self
     }
+
+    /// Casts numeric values from one type to another, useful for cross-index search if the
+    /// sort field is mapped differently on some indices
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sorting_numeric_fields>
+    pub fn numeric_type(mut self, numeric_type: SortNumericType) -> Self {
+        self.numeric_type = Some(numeric_type);
+        self
+    }
+
+    /// Sorts by fields inside of nested objects
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#nested-sorting>
+    pub fn nested(mut self, nested: NestedSort) -> Self {
+        self.nested = Some(nested);
+        self
+    }
 }
 
 impl IntoIterator for FieldSort {
@@ -121,17 +156,23 @@ impl IntoIterator for FieldSort {
 }
 
 serialize_keyed!(FieldSort: field);
+deserialize_keyed!(FieldSort: field);
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::assert_serialize;
-    use crate::SortSpecialField;
+    use crate::{Query, SortMissing, SortSpecialField};
 
     #[test]
     fn serialization() {
         assert_serialize(FieldSort::new("test"), json!({"test": {}}));
 
+        assert_serialize(
+            Sort::field("test").order(SortOrder::Desc),
+            json!({"test": { "order": "desc" }}),
+        );
+
         assert_serialize(
             FieldSort::new(SortSpecialField::Score),
             json!({"_score": {}}),
@@ -162,5 +203,43 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Sort::field("offer.price")
+                .order(SortOrder::Asc)
+                .mode(SortMode::Avg)
+                .missing(SortMissing::First)
+                .numeric_type(SortNumericType::Double)
+                .nested(
+                    NestedSort::new("offer")
+                        .filter(Query::term("offer.color", "blue"))
+                        .max_children(10)
+                        .nested(
+                            NestedSort::new("offer.variant")
+                                .filter(Query::term("offer.variant.size", "xl")),
+                        ),
+                ),
+            json!({
+                "offer.price": {
+                    "order": "asc",
+                    "mode": "avg",
+                    "missing": "_first",
+                    "numeric_type": "double",
+                    "nested": {
+                        "path": "offer",
+                        "filter": {
+                            "term": { "offer.color": { "value": "blue" } }
+                        },
+                        "max_children": 10,
+                        "nested": {
+                            "path": "offer.variant",
+                            "filter": {
+                                "term": { "offer.variant.size": { "value": "xl" } }
+                            }
+                        }
+                    }
+                }
+            }),
+        );
     }
 }
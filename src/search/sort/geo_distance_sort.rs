@@ -1,18 +1,18 @@
-use super::{SortMode, SortOrder};
+use super::{NestedSort, Sort, SortMode, SortOrder};
 use crate::util::{KeyValuePair, ShouldSkip};
 use crate::{DistanceUnit, GeoDistanceType, GeoLocation};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Sorts search hits by other field values
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#sort-search-results>
-#[derive(Debug, Clone, PartialEq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct GeoDistanceSort {
     #[serde(skip)]
     field: String,
 
-    #[serde(skip)]
+    #[serde(skip_serializing)]
     points: Vec<GeoLocation>,
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
@@ -29,6 +29,21 @@ pub struct GeoDistanceSort {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     ignore_unmapped: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    nested: Option<NestedSort>,
+}
+
+impl Sort {
+    /// Creates an instance of [`GeoDistanceSort`]
+    pub fn geo_distance<T, U>(field: T, points: U) -> GeoDistanceSort
+    where
+        T: ToString,
+        U: IntoIterator,
+        U::Item: Into<GeoLocation>,
+    {
+        GeoDistanceSort::new(field, points)
+    }
 }
 
 impl GeoDistanceSort {
@@ -47,6 +62,7 @@ impl GeoDistanceSort {
             mode: None,
             distance_type: None,
             ignore_unmapped: None,
+            nested: None,
         }
     }
 
@@ -111,6 +127,14 @@ impl GeoDistanceSort {
         self.ignore_unmapped = Some(ignore_unmapped);
         self
     }
+
+    /// Sorts by distance to geo points stored inside of nested objects
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#nested-sorting>
+    pub fn nested(mut self, nested: NestedSort) -> Self {
+        self.nested = Some(nested);
+        self
+    }
 }
 
 impl IntoIterator for GeoDistanceSort {
@@ -124,16 +148,18 @@ impl IntoIterator for GeoDistanceSort {
 }
 
 serialize_with_root_key_value_pair!("_geo_distance": GeoDistanceSort, field, points);
+deserialize_with_root_key_value_pair!("_geo_distance": GeoDistanceSort, field, points);
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::assert_serialize;
+    use crate::Query;
 
     #[test]
     fn serialization() {
         assert_serialize(
-            GeoDistanceSort::new("test", GeoLocation::new(1.2, 3.3)),
+            Sort::geo_distance("test", GeoLocation::new(1.2, 3.3)),
             json!({
                 "_geo_distance": {
                     "test": [ [3.3, 1.2] ]
@@ -169,5 +195,41 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Sort::geo_distance(
+                "pin.location",
+                [GeoLocation::new(40.0, -70.0), GeoLocation::new(42.0, -71.0)],
+            )
+            .order(SortOrder::Asc)
+            .unit(DistanceUnit::Kilometers)
+            .mode(SortMode::Min)
+            .distance_type(GeoDistanceType::Plane),
+            json!({
+                "_geo_distance": {
+                    "pin.location": [ [-70.0, 40.0], [-71.0, 42.0] ],
+                    "order": "asc",
+                    "unit": "km",
+                    "mode": "min",
+                    "distance_type": "plane"
+                }
+            }),
+        );
+
+        assert_serialize(
+            Sort::geo_distance("offer.location", GeoLocation::new(40.0, -70.0))
+                .nested(NestedSort::new("offer").filter(Query::term("offer.available", true))),
+            json!({
+                "_geo_distance": {
+                    "offer.location": [ [-70.0, 40.0] ],
+                    "nested": {
+                        "path": "offer",
+                        "filter": {
+                            "term": { "offer.available": { "value": true } }
+                        }
+                    }
+                }
+            }),
+        );
     }
 }
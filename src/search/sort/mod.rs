@@ -6,20 +6,26 @@
 
 mod field_sort;
 mod geo_distance_sort;
+mod nested_sort;
+mod score_sort;
 mod script_sort;
 mod sort_;
 mod sort_collection;
 mod sort_missing;
 mod sort_mode;
+mod sort_numeric_type;
 mod sort_order;
 mod sort_special_field;
 
 pub use self::field_sort::*;
 pub use self::geo_distance_sort::*;
+pub use self::nested_sort::*;
+pub use self::score_sort::*;
 pub use self::script_sort::*;
 pub use self::sort_::*;
 pub use self::sort_collection::*;
 pub use self::sort_missing::*;
 pub use self::sort_mode::*;
+pub use self::sort_numeric_type::*;
 pub use self::sort_order::*;
 pub use self::sort_special_field::*;
@@ -0,0 +1,94 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Sorting by fields inside of nested objects
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#nested-sorting>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NestedSort {
+    path: String,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    filter: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_children: Option<u32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    nested: Option<Box<NestedSort>>,
+}
+
+impl NestedSort {
+    /// Creates an instance of [NestedSort]
+    ///
+    /// - `path` - A nested object to sort on
+    pub fn new<T>(path: T) -> Self
+    where
+        T: ToString,
+    {
+        Self {
+            path: path.to_string(),
+            filter: None,
+            max_children: None,
+            nested: None,
+        }
+    }
+
+    /// A filter that the inner objects inside the nested path should match with in order for its
+    /// field values to be taken into account by sorting. Common case is to repeat the query / filter
+    /// inside the nested filter or query. By default no filter is active.
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Option<Query>>,
+    {
+        self.filter = filter.into();
+        self
+    }
+
+    /// The maximum number of children to consider per root document when picking the sort value.
+    /// Defaults to unlimited.
+    pub fn max_children(mut self, max_children: u32) -> Self {
+        self.max_children = Some(max_children);
+        self
+    }
+
+    /// Same as top-level `nested` but applies to another nested path within the current nested
+    /// object.
+    pub fn nested(mut self, nested: NestedSort) -> Self {
+        self.nested = Some(Box::new(nested));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(NestedSort::new("offer"), json!({ "path": "offer" }));
+
+        assert_serialize(
+            NestedSort::new("parent")
+                .filter(Query::range("parent.age").gte(21))
+                .max_children(10)
+                .nested(
+                    NestedSort::new("parent.child")
+                        .filter(Query::term("parent.child.name", "matt")),
+                ),
+            json!({
+                "path": "parent",
+                "filter": {
+                    "range": { "parent.age": { "gte": 21 } }
+                },
+                "max_children": 10,
+                "nested": {
+                    "path": "parent.child",
+                    "filter": {
+                        "term": { "parent.child.name": { "value": "matt" } }
+                    }
+                }
+            }),
+        );
+    }
+}
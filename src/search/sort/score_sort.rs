@@ -0,0 +1,106 @@
+use super::{Sort, SortOrder, SortSpecialField};
+use crate::util::KeyValuePair;
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+/// Sorts search hits by their relevance score
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#sort-search-results>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreSort {
+    order: Option<SortOrder>,
+}
+
+impl Sort {
+    /// Creates an instance of [`ScoreSort`]
+    pub fn score() -> ScoreSort {
+        ScoreSort::new()
+    }
+}
+
+impl ScoreSort {
+    /// Creates an instance of [ScoreSort]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an instance of [ScoreSort] by ascending order
+    pub fn ascending() -> Self {
+        Self::new().order(SortOrder::Asc)
+    }
+
+    /// Creates an instance of [ScoreSort] by descending order
+    pub fn descending() -> Self {
+        Self::new().order(SortOrder::Desc)
+    }
+
+    /// Explicit order
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sort_order>
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+impl Serialize for ScoreSort {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.order {
+            Some(order) => KeyValuePair::new(SortSpecialField::Score, order).serialize(serializer),
+            None => SortSpecialField::Score.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ScoreSort {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Field(SortSpecialField),
+            Pair(KeyValuePair<SortSpecialField, SortOrder>),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Field(SortSpecialField::Score) => Ok(Self::new()),
+            Repr::Pair(pair) if pair.key == SortSpecialField::Score => {
+                Ok(Self::new().order(pair.value))
+            }
+            _ => Err(D::Error::custom("expected `_score` sort")),
+        }
+    }
+}
+
+impl IntoIterator for ScoreSort {
+    type Item = Self;
+
+    type IntoIter = std::option::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Some(self).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::assert_serialize;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(Sort::score(), json!("_score"));
+
+        assert_serialize(ScoreSort::descending(), json!({ "_score": "desc" }));
+
+        assert_serialize(
+            Sort::score().order(SortOrder::Asc),
+            json!({ "_score": "asc" }),
+        );
+    }
+}
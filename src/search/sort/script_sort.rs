@@ -1,13 +1,13 @@
-use super::SortOrder;
+use super::{NestedSort, Sort, SortMode, SortOrder};
 use crate::util::ShouldSkip;
 use crate::{Script, ScriptSortType};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Sorts search hits by script result
 ///
 /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#script-based-sorting>
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(remote = "Self")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", deny_unknown_fields)]
 pub struct ScriptSort {
     script: Script,
 
@@ -16,6 +16,22 @@ pub struct ScriptSort {
 
     #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
     r#type: Option<ScriptSortType>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    mode: Option<SortMode>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    nested: Option<NestedSort>,
+}
+
+impl Sort {
+    /// Creates an instance of [`ScriptSort`]
+    ///
+    /// - `script` - Script to compute the sort value with
+    /// - `type` - How to treat the script result
+    pub fn script(script: Script, r#type: ScriptSortType) -> ScriptSort {
+        ScriptSort::new(script).r#type(r#type)
+    }
 }
 
 impl ScriptSort {
@@ -25,6 +41,8 @@ impl ScriptSort {
             script,
             order: None,
             r#type: None,
+            mode: None,
+            nested: None,
         }
     }
 
@@ -51,6 +69,22 @@ impl ScriptSort {
         self.r#type = Some(r#type);
         self
     }
+
+    /// Sort mode for multi-valued script results
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sort_mode_option>
+    pub fn mode(mut self, mode: SortMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sorts by script values computed against nested objects
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#nested-sorting>
+    pub fn nested(mut self, nested: NestedSort) -> Self {
+        self.nested = Some(nested);
+        self
+    }
 }
 
 impl IntoIterator for ScriptSort {
@@ -64,11 +98,13 @@ impl IntoIterator for ScriptSort {
 }
 
 serialize_with_root!("_script": ScriptSort);
+deserialize_with_root!("_script": ScriptSort);
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::util::assert_serialize;
+    use crate::{FieldSort, Query, Search};
 
     #[test]
     fn serialization() {
@@ -90,5 +126,81 @@ mod tests {
                 }
             }),
         );
+
+        assert_serialize(
+            Sort::script(
+                Script::source("doc['numberOfCommits'].value * params.factor").param("factor", 1.1),
+                ScriptSortType::Number,
+            )
+            .order(SortOrder::Desc)
+            .mode(SortMode::Avg),
+            json!({
+                "_script": {
+                    "order": "desc",
+                    "type": "number",
+                    "mode": "avg",
+                    "script": {
+                        "source": "doc['numberOfCommits'].value * params.factor",
+                        "params": {
+                            "factor": 1.1
+                        }
+                    }
+                }
+            }),
+        );
+
+        assert_serialize(
+            Sort::script(
+                Script::source("doc['offer.name'].value.toLowerCase()"),
+                ScriptSortType::String,
+            )
+            .nested(NestedSort::new("offer").filter(Query::term("offer.color", "blue"))),
+            json!({
+                "_script": {
+                    "type": "string",
+                    "script": {
+                        "source": "doc['offer.name'].value.toLowerCase()"
+                    },
+                    "nested": {
+                        "path": "offer",
+                        "filter": {
+                            "term": { "offer.color": { "value": "blue" } }
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn serialization_in_sort_collection() {
+        assert_serialize(
+            Search::new().sort([
+                Sort::from(FieldSort::descending("post_date")),
+                Sort::from(
+                    Sort::script(
+                        Script::source("doc['field_name'].value * params.factor")
+                            .param("factor", 1.1),
+                        ScriptSortType::Number,
+                    )
+                    .order(SortOrder::Asc),
+                ),
+            ]),
+            json!({
+                "sort": [
+                    { "post_date": { "order": "desc" } },
+                    {
+                        "_script": {
+                            "type": "number",
+                            "order": "asc",
+                            "script": {
+                                "source": "doc['field_name'].value * params.factor",
+                                "params": { "factor": 1.1 }
+                            }
+                        }
+                    }
+                ]
+            }),
+        );
     }
 }
@@ -1,8 +1,8 @@
-use super::{FieldSort, GeoDistanceSort, ScriptSort, SortSpecialField};
+use super::{FieldSort, GeoDistanceSort, ScoreSort, ScriptSort, SortSpecialField};
 use std::borrow::Cow;
 
 /// Sorting criterion
-#[derive(Clone, PartialEq, Serialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Sort {
     /// Special sort field,
@@ -14,6 +14,9 @@ pub enum Sort {
     /// Sorts by field name with finer control
     FieldSort(FieldSort),
 
+    /// Sorts by relevance score
+    ScoreSort(ScoreSort),
+
     /// Sorts by a geo distance
     GeoDistanceSort(GeoDistanceSort),
 
@@ -27,6 +30,7 @@ impl std::fmt::Debug for Sort {
             Self::SpecialField(sort) => sort.fmt(f),
             Self::Field(sort) => sort.fmt(f),
             Self::FieldSort(sort) => sort.fmt(f),
+            Self::ScoreSort(sort) => sort.fmt(f),
             Self::GeoDistanceSort(sort) => sort.fmt(f),
             Self::ScriptSort(sort) => sort.fmt(f),
         }
@@ -63,6 +67,12 @@ impl From<FieldSort> for Sort {
     }
 }
 
+impl From<ScoreSort> for Sort {
+    fn from(value: ScoreSort) -> Self {
+        Self::ScoreSort(value)
+    }
+}
+
 impl From<GeoDistanceSort> for Sort {
     fn from(value: GeoDistanceSort) -> Self {
         Self::GeoDistanceSort(value)
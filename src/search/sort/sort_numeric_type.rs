@@ -0,0 +1,20 @@
+/// For numeric fields it is also possible to cast the values from one type to another using this
+/// option. This can be useful for cross-index search if the sort field is mapped differently on
+/// some indices.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/sort-search-results.html#_sorting_numeric_fields>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortNumericType {
+    /// Casts values to `long`
+    Long,
+
+    /// Casts values to `double`
+    Double,
+
+    /// Casts values to `date`
+    Date,
+
+    /// Casts values to `date_nanos`
+    DateNanos,
+}
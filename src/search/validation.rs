@@ -0,0 +1,194 @@
+//! Client side validation of search requests.
+//!
+//! Some query configurations are accepted by the builders but rejected by Elasticsearch,
+//! [`Validate`] allows catching the common ones before the request is sent.
+
+/// Checks whether a search request or query is correctly configured
+pub trait Validate {
+    /// Returns all the problems found within the instance, if any
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        Ok(())
+    }
+}
+
+/// A problem found while validating a search request or query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    path: String,
+    message: String,
+}
+
+impl ValidationError {
+    /// Creates an instance of [ValidationError]
+    ///
+    /// - `path` - Location of the problem within the request, e.g. `query.bool.must[0].range.age`
+    /// - `message` - Human readable description of the problem
+    pub fn new<T, U>(path: T, message: U) -> Self
+    where
+        T: ToString,
+        U: ToString,
+    {
+        Self {
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Location of the problem within the request
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Human readable description of the problem
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn prefixed(mut self, prefix: &str) -> Self {
+        self.path = if self.path.is_empty() {
+            prefix.to_string()
+        } else if self.path.starts_with('[') {
+            format!("{}{}", prefix, self.path)
+        } else {
+            format!("{}.{}", prefix, self.path)
+        };
+        self
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl<T> Validate for Option<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Validate for Box<T>
+where
+    T: Validate + ?Sized,
+{
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        (**self).validate()
+    }
+}
+
+/// Accumulates validation errors of an instance and of its nested values
+#[derive(Debug, Default)]
+pub(crate) struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error found at `path`
+    pub(crate) fn push<T, U>(&mut self, path: T, message: U)
+    where
+        T: ToString,
+        U: ToString,
+    {
+        self.0.push(ValidationError::new(path, message));
+    }
+
+    /// Validates a nested value, prefixing its errors with `path`
+    pub(crate) fn nested<T>(&mut self, path: &str, value: &T)
+    where
+        T: Validate + ?Sized,
+    {
+        if let Err(errors) = value.validate() {
+            self.0
+                .extend(errors.into_iter().map(|error| error.prefixed(path)));
+        }
+    }
+
+    pub(crate) fn into_result(self) -> Result<(), Vec<ValidationError>> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Query, Search};
+
+    #[test]
+    fn accepts_valid_search() {
+        let search = Search::new().query(
+            Query::bool()
+                .must(Query::term("user.id", "kimchy"))
+                .should(Query::range("age").gte(10).lt(20))
+                .minimum_should_match("1"),
+        );
+
+        assert_eq!(search.validate(), Ok(()));
+    }
+
+    #[test]
+    fn reports_nested_paths() {
+        let search = Search::new()
+            .query(
+                Query::bool()
+                    .must(Query::term("user.id", "kimchy"))
+                    .must(Query::range("age").gt(10).gte(10)),
+            )
+            .post_filter(Query::range("age").lt(20).lte(20));
+
+        let errors = search.validate().unwrap_err();
+
+        assert_eq!(
+            errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "query.bool.must[1].range.age: `gt` and `gte` cannot be set at the same time",
+                "post_filter.range.age: `lt` and `lte` cannot be set at the same time",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_leaf_errors() {
+        let empty: [i32; 0] = [];
+
+        let search = Search::new().query(Query::bool().minimum_should_match("1"));
+
+        assert_eq!(
+            search.validate(),
+            Err(vec![ValidationError::new(
+                "query.bool",
+                "`minimum_should_match` is set without any clauses"
+            )])
+        );
+
+        assert_eq!(
+            Query::from(Query::function_score()).validate(),
+            Err(vec![ValidationError::new(
+                "function_score",
+                "at least one function or a query is required"
+            )])
+        );
+
+        assert_eq!(
+            Query::from(Query::terms_set("tags", empty, "required_matches")).validate(),
+            Err(vec![ValidationError::new(
+                "terms_set.tags",
+                "at least one term is required"
+            )])
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Runs a search with a
+//! [search template](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template.html),
+//! a stored search that can be run with different variables.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template-api.html>
+
+mod request;
+
+pub use self::request::*;
@@ -0,0 +1,194 @@
+use crate::util::*;
+use serde_json::Value;
+
+/// Runs a search with a Mustache template filled in by Elasticsearch.
+///
+/// Either a stored template or an inline template source is used:
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # use serde_json::json;
+/// # let request =
+/// SearchTemplateRequest::stored("my-search-template", json!({ "query_string": "hello world" }));
+/// # let request =
+/// SearchTemplateRequest::inline(
+///     r#"{ "query": { "match": { "message": "{{query_string}}" } } }"#,
+///     json!({ "query_string": "hello world" }),
+/// );
+/// ```
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/search-template-api.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchTemplateRequest {
+    #[serde(flatten)]
+    template: TemplateSource,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    params: Option<Value>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    explain: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    profile: Option<bool>,
+}
+
+/// Renders a search template into a search request body without running it,
+/// useful to debug templates.
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/render-search-template-api.html>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenderSearchTemplateRequest {
+    #[serde(flatten)]
+    template: TemplateSource,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    params: Option<Value>,
+}
+
+/// Either a stored template or an inline template source, the two are
+/// mutually exclusive
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum TemplateSource {
+    Id(String),
+    Source(String),
+}
+
+impl SearchTemplateRequest {
+    /// Creates an instance of [`SearchTemplateRequest`] with an inline
+    /// template
+    ///
+    /// - `source` - Mustache template of the search request body
+    /// - `params` - key value pairs used to replace the template variables
+    pub fn inline<T>(source: T, params: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::new(TemplateSource::Source(source.to_string()), params)
+    }
+
+    /// Creates an instance of [`SearchTemplateRequest`] with a stored
+    /// template
+    ///
+    /// - `id` - ID of the stored search template
+    /// - `params` - key value pairs used to replace the template variables
+    pub fn stored<T>(id: T, params: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::new(TemplateSource::Id(id.to_string()), params)
+    }
+
+    fn new(template: TemplateSource, params: Value) -> Self {
+        Self {
+            template,
+            params: Some(params).filter(|params| !params.is_null()),
+            explain: None,
+            profile: None,
+        }
+    }
+
+    /// Returns detailed information about score computation as part of each
+    /// hit
+    pub fn explain(mut self, explain: bool) -> Self {
+        self.explain = Some(explain);
+        self
+    }
+
+    /// Profiles the query execution
+    pub fn profile(mut self, profile: bool) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+}
+
+impl RenderSearchTemplateRequest {
+    /// Creates an instance of [`RenderSearchTemplateRequest`] with an inline
+    /// template
+    ///
+    /// - `source` - Mustache template of the search request body
+    /// - `params` - key value pairs used to replace the template variables
+    pub fn inline<T>(source: T, params: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::new(TemplateSource::Source(source.to_string()), params)
+    }
+
+    /// Creates an instance of [`RenderSearchTemplateRequest`] with a stored
+    /// template
+    ///
+    /// - `id` - ID of the stored search template
+    /// - `params` - key value pairs used to replace the template variables
+    pub fn stored<T>(id: T, params: Value) -> Self
+    where
+        T: ToString,
+    {
+        Self::new(TemplateSource::Id(id.to_string()), params)
+    }
+
+    fn new(template: TemplateSource, params: Value) -> Self {
+        Self {
+            template,
+            params: Some(params).filter(|params| !params.is_null()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(
+            SearchTemplateRequest::stored(
+                "my-search-template",
+                json!({ "query_string": "hello world", "from": 0, "size": 10 }),
+            ),
+            json!({
+                "id": "my-search-template",
+                "params": { "query_string": "hello world", "from": 0, "size": 10 }
+            }),
+        );
+
+        assert_serialize(
+            SearchTemplateRequest::inline(
+                r#"{ "query": { "match": { "message": "{{query_string}}" } } }"#,
+                json!({ "query_string": "hello world" }),
+            )
+            .explain(true)
+            .profile(true),
+            json!({
+                "source": r#"{ "query": { "match": { "message": "{{query_string}}" } } }"#,
+                "params": { "query_string": "hello world" },
+                "explain": true,
+                "profile": true
+            }),
+        );
+
+        assert_serialize(
+            SearchTemplateRequest::stored("my-search-template", Value::Null),
+            json!({ "id": "my-search-template" }),
+        );
+    }
+
+    #[test]
+    fn serializes_render_request() {
+        assert_serialize(
+            RenderSearchTemplateRequest::stored(
+                "my-search-template",
+                json!({ "query_string": "hello world" }),
+            ),
+            json!({
+                "id": "my-search-template",
+                "params": { "query_string": "hello world" }
+            }),
+        );
+
+        assert_serialize(
+            RenderSearchTemplateRequest::inline(r#"{ "query": { "match_all": {} } }"#, Value::Null),
+            json!({ "source": r#"{ "query": { "match_all": {} } }"# }),
+        );
+    }
+}
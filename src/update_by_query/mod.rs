@@ -0,0 +1,12 @@
+//! Updates documents that match the specified query. If no query is
+//! specified, performs an update on every document in the data stream or
+//! index without modifying the source, which is useful for picking up
+//! mapping changes.
+//!
+//! <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html>
+
+mod params;
+mod request;
+
+pub use self::params::*;
+pub use self::request::*;
@@ -0,0 +1,169 @@
+use crate::util::*;
+use crate::Time;
+use serde::{Serialize, Serializer};
+
+/// URL parameters of an [`UpdateByQuery`](crate::UpdateByQuery) request,
+/// serializes into a flat structure that can be passed to the query string
+/// of any HTTP client
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html#docs-update-by-query-api-query-params>
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct UpdateByQueryParams {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    pipeline: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    refresh: Option<bool>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    requests_per_second: Option<f32>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    scroll_size: Option<u64>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slices: Option<Slices>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    timeout: Option<Time>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    wait_for_completion: Option<bool>,
+}
+
+/// Number of slices a by-query request is automatically divided into
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html#docs-update-by-query-automatic-slice>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slices {
+    /// Lets Elasticsearch choose the number of slices, one slice per shard
+    Auto,
+
+    /// Fixed number of slices
+    Count(u32),
+}
+
+impl Serialize for Slices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Auto => serializer.serialize_str("auto"),
+            Self::Count(count) => serializer.serialize_u32(*count),
+        }
+    }
+}
+
+impl From<u32> for Slices {
+    fn from(count: u32) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl UpdateByQueryParams {
+    /// Creates an instance of [`UpdateByQueryParams`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ID of the ingest pipeline used to preprocess updated documents
+    pub fn pipeline<T>(mut self, pipeline: T) -> Self
+    where
+        T: ToString,
+    {
+        self.pipeline = Some(pipeline.to_string());
+        self
+    }
+
+    /// Refreshes all shards involved in the request once it completes
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    /// Throttles the request to the given number of sub-requests per second,
+    /// `-1` disables throttling
+    pub fn requests_per_second(mut self, requests_per_second: f32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// Custom value used to route operations to a specific shard
+    pub fn routing<T>(mut self, routing: T) -> Self
+    where
+        T: ToString,
+    {
+        self.routing = Some(routing.to_string());
+        self
+    }
+
+    /// Size of the scroll request that powers the operation, defaults to
+    /// `1000`
+    pub fn scroll_size(mut self, scroll_size: u64) -> Self {
+        self.scroll_size = Some(scroll_size);
+        self
+    }
+
+    /// Number of slices the task is automatically divided into
+    pub fn slices<T>(mut self, slices: T) -> Self
+    where
+        T: Into<Slices>,
+    {
+        self.slices = Some(slices.into());
+        self
+    }
+
+    /// Period each update request waits for active shards
+    pub fn timeout<T>(mut self, timeout: T) -> Self
+    where
+        T: Into<Time>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Blocks until the operation is complete, when `false` a task is
+    /// started and its ID returned instead
+    pub fn wait_for_completion(mut self, wait_for_completion: bool) -> Self {
+        self.wait_for_completion = Some(wait_for_completion);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(UpdateByQueryParams::new(), json!({}));
+
+        assert_serialize(
+            UpdateByQueryParams::new()
+                .pipeline("set-foo")
+                .refresh(true)
+                .requests_per_second(500.0)
+                .routing("user1")
+                .scroll_size(100)
+                .slices(Slices::Auto)
+                .timeout(Time::Minutes(1))
+                .wait_for_completion(false),
+            json!({
+                "pipeline": "set-foo",
+                "refresh": true,
+                "requests_per_second": 500.0,
+                "routing": "user1",
+                "scroll_size": 100,
+                "slices": "auto",
+                "timeout": "1m",
+                "wait_for_completion": false
+            }),
+        );
+
+        assert_serialize(UpdateByQueryParams::new().slices(5), json!({ "slices": 5 }));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::search::*;
+use crate::util::*;
+
+/// Updates documents that match the specified query without fetching them
+/// to the client.
+/// ```
+/// # use elasticsearch_dsl::*;
+/// # let request =
+/// UpdateByQuery::new()
+///     .query(Query::term("user.id", "kimchy"))
+///     .script(Script::source("ctx._source.count++").lang("painless"))
+///     .conflicts(ConflictsPolicy::Proceed);
+/// ```
+///
+/// URL parameters are set with [`UpdateByQueryParams`].
+///
+/// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html>
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct UpdateByQuery {
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    query: Option<Query>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    conflicts: Option<ConflictsPolicy>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    slice: Option<Slice>,
+
+    #[serde(skip_serializing_if = "ShouldSkip::should_skip")]
+    max_docs: Option<u64>,
+}
+
+/// What to do when a document was changed between the time the snapshot was
+/// taken and the time it is processed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictsPolicy {
+    /// Aborts the request at the first version conflict, this is the default
+    Abort,
+
+    /// Counts version conflicts and carries on
+    Proceed,
+}
+
+impl UpdateByQuery {
+    /// Creates an instance of [`UpdateByQuery`], which updates every document
+    /// unless a [query](Self::query) is given
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the update to documents matching the query
+    pub fn query<Q>(mut self, query: Q) -> Self
+    where
+        Q: Into<Query>,
+    {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Script used to update the document source or metadata
+    pub fn script(mut self, script: Script) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    /// What to do if update by query hits version conflicts
+    pub fn conflicts(mut self, conflicts: ConflictsPolicy) -> Self {
+        self.conflicts = Some(conflicts);
+        self
+    }
+
+    /// Manually slices the request, run one request per slice to
+    /// parallelize the update
+    ///
+    /// <https://www.elastic.co/guide/en/elasticsearch/reference/current/docs-update-by-query.html#docs-update-by-query-manual-slice>
+    pub fn slice(mut self, slice: Slice) -> Self {
+        self.slice = Some(slice);
+        self
+    }
+
+    /// Maximum number of documents to process, defaults to all documents
+    pub fn max_docs(mut self, max_docs: u64) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+}
+
+impl Validate for UpdateByQuery {
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = ValidationErrors::new();
+
+        errors.nested("query", &self.query);
+        errors.nested("slice", &self.slice);
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization() {
+        assert_serialize(UpdateByQuery::new(), json!({}));
+
+        assert_serialize(
+            UpdateByQuery::new()
+                .script(Script::source("ctx._source.count++"))
+                .conflicts(ConflictsPolicy::Proceed),
+            json!({
+                "script": { "source": "ctx._source.count++" },
+                "conflicts": "proceed"
+            }),
+        );
+
+        assert_serialize(
+            UpdateByQuery::new()
+                .query(Query::term("user.id", "kimchy"))
+                .script(Script::source("ctx._source.count++").lang("painless"))
+                .conflicts(ConflictsPolicy::Abort)
+                .slice(Slice::new(0, 2))
+                .max_docs(1000),
+            json!({
+                "query": { "term": { "user.id": { "value": "kimchy" } } },
+                "script": { "source": "ctx._source.count++", "lang": "painless" },
+                "conflicts": "abort",
+                "slice": { "id": 0, "max": 2 },
+                "max_docs": 1000
+            }),
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            UpdateByQuery::new().slice(Slice::new(2, 2)).validate(),
+            Err(vec![ValidationError::new(
+                "slice",
+                "`id` must be lower than `max`"
+            )])
+        );
+    }
+}
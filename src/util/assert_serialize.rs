@@ -13,17 +13,36 @@ where
     );
 }
 
-/// Tests if a query is serialized to correct JSON [`Value`]
+/// Tests if a query is serialized to correct JSON [`Value`] and that the
+/// same JSON, as well as its string form, can be parsed back into an
+/// equivalent query
 #[cfg(test)]
 pub(crate) fn assert_serialize_query<T>(subject: T, expectation: serde_json::Value)
 where
     T: Into<crate::Query> + Clone,
 {
+    let query: crate::Query = subject.clone().into();
+
+    // Raw JSON queries may hold anything, including typed queries
+    if !matches!(query, crate::Query::Json(_)) {
+        let deserialized: crate::Query = serde_json::from_value(expectation.clone()).unwrap();
+        let parsed: crate::Query = query.to_string().parse().unwrap();
+
+        assert_eq!(deserialized, query);
+        assert_eq!(parsed.to_string(), query.to_string());
+        assert_serialize(deserialized, expectation.clone());
+    }
+
     let subject = crate::Search::new()
         .query(subject.clone())
         .post_filter(subject);
 
-    let expectation = json!({ "query": expectation, "post_filter": expectation });
+    // Skippable queries are dropped from post filter
+    let expectation = if crate::util::ShouldSkip::should_skip(&query) {
+        json!({ "query": expectation })
+    } else {
+        json!({ "query": expectation, "post_filter": expectation })
+    };
 
     assert_serialize(subject, expectation)
 }
@@ -1,3 +1,4 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use serde::ser::{Serialize, Serializer};
 
 pub(crate) fn join_with_pipe<S, T>(value: &[T], serializer: S) -> Result<S::Ok, S::Error>
@@ -13,6 +14,21 @@ where
         .serialize(serializer)
 }
 
+pub(crate) fn split_by_pipe<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = String::deserialize(deserializer)?;
+
+    value
+        .split('|')
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(Error::custom))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,4 +47,20 @@ mod tests {
             json!({ "value": "1|2|3" }),
         )
     }
+
+    #[test]
+    fn tests_deserialization() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct SplitByPipe {
+            #[serde(deserialize_with = "split_by_pipe")]
+            value: Vec<i32>,
+        }
+
+        assert_eq!(
+            serde_json::from_value::<SplitByPipe>(json!({ "value": "1|2|3" })).unwrap(),
+            SplitByPipe {
+                value: vec![1, 2, 3]
+            }
+        );
+    }
 }
@@ -1,4 +1,6 @@
+use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
 use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::marker::PhantomData;
 
 #[derive(Clone, PartialEq, Eq)]
 pub(crate) struct KeyValuePair<K, V> {
@@ -40,6 +42,61 @@ where
     }
 }
 
+impl<'de, K, V> Deserialize<'de> for KeyValuePair<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyValuePairVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for KeyValuePairVisitor<K, V>
+        where
+            K: Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = KeyValuePair<K, V>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a map with a single key")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let (key, value) = map
+                    .next_entry()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(A::Error::invalid_length(2, &self));
+                }
+
+                Ok(KeyValuePair::new(key, value))
+            }
+        }
+
+        deserializer.deserialize_map(KeyValuePairVisitor(PhantomData))
+    }
+}
+
+/// Serializes key value pairs as a single map, preserving their order
+pub(crate) fn serialize_key_value_pairs<K, V, S>(
+    pairs: &[KeyValuePair<K, V>],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    serializer.collect_map(pairs.iter().map(|pair| (&pair.key, &pair.value)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +106,59 @@ mod tests {
     fn serializes_as_key_value_pair() {
         assert_serialize(KeyValuePair::new("key", "value"), json!({ "key": "value" }));
     }
+
+    #[test]
+    fn serializes_value_fields_in_declaration_order() {
+        #[derive(Serialize)]
+        struct Value {
+            query: &'static str,
+            operator: &'static str,
+            boost: f32,
+        }
+
+        let subject = KeyValuePair::new(
+            "field",
+            Value {
+                query: "search text",
+                operator: "AND",
+                boost: 2.0,
+            },
+        );
+
+        assert_eq!(
+            serde_json::to_string(&subject).unwrap(),
+            r#"{"field":{"query":"search text","operator":"AND","boost":2.0}}"#
+        );
+    }
+
+    #[test]
+    fn serializes_pairs_as_single_map() {
+        #[derive(Serialize)]
+        struct Subject {
+            #[serde(serialize_with = "serialize_key_value_pairs")]
+            pairs: Vec<KeyValuePair<&'static str, u64>>,
+        }
+
+        let subject = Subject {
+            pairs: vec![KeyValuePair::new("b", 1), KeyValuePair::new("a", 2)],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&subject).unwrap(),
+            r#"{"pairs":{"b":1,"a":2}}"#
+        );
+    }
+
+    #[test]
+    fn deserializes_from_single_key() {
+        let subject: KeyValuePair<String, u64> =
+            serde_json::from_value(json!({ "key": 1 })).unwrap();
+
+        assert_eq!(subject, KeyValuePair::new("key".to_string(), 1));
+
+        assert!(serde_json::from_value::<KeyValuePair<String, u64>>(json!({})).is_err());
+        assert!(
+            serde_json::from_value::<KeyValuePair<String, u64>>(json!({ "a": 1, "b": 2 })).is_err()
+        );
+    }
 }
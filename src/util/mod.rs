@@ -4,9 +4,11 @@ mod assert_serialize;
 mod join_with_pipe;
 mod key_value_pair;
 mod should_skip;
+mod struct_fields;
 
 #[cfg(test)]
 pub(crate) use self::assert_serialize::*;
 pub(crate) use self::join_with_pipe::*;
 pub(crate) use self::key_value_pair::*;
 pub(crate) use self::should_skip::*;
+pub(crate) use self::struct_fields::*;
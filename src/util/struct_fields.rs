@@ -0,0 +1,100 @@
+use serde::de::{self, Deserializer, Visitor};
+
+/// Returns the field names a `deserialize` function expects when reading a
+/// struct, which allows telling apart known fields from a dynamic key that
+/// shares the same JSON object (e.g. `{ "distance": "200km", "pin.location": [..] }`)
+pub(crate) fn struct_fields<T, F>(deserialize: F) -> &'static [&'static str]
+where
+    F: FnOnce(StructFieldsDeserializer<'_>) -> Result<T, StructFieldsError>,
+{
+    let mut fields = None;
+
+    let _ = deserialize(StructFieldsDeserializer {
+        fields: &mut fields,
+    });
+
+    fields.unwrap_or_default()
+}
+
+/// Deserializer that records struct field names and fails immediately after
+pub(crate) struct StructFieldsDeserializer<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+/// Error returned by [`StructFieldsDeserializer`]
+#[derive(Debug)]
+pub(crate) struct StructFieldsError;
+
+impl std::fmt::Display for StructFieldsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("struct fields introspection")
+    }
+}
+
+impl std::error::Error for StructFieldsError {}
+
+impl de::Error for StructFieldsError {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: std::fmt::Display,
+    {
+        Self
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for StructFieldsDeserializer<'a> {
+    type Error = StructFieldsError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(StructFieldsError)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        *self.fields = Some(fields);
+
+        Err(StructFieldsError)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[test]
+    fn returns_struct_fields() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Subject {
+            first: String,
+
+            #[serde(rename = "_second")]
+            second: Option<u64>,
+
+            #[serde(skip)]
+            third: String,
+        }
+
+        assert_eq!(
+            struct_fields(|fields| Subject::deserialize(fields)),
+            &["first", "_second"]
+        );
+    }
+}